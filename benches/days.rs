@@ -0,0 +1,275 @@
+//! Benchmarks for each day's parse and solve phases, using the real
+//! inputs in `src/inputs/`. Days without an input, and parts that
+//! haven't been solved, are skipped.
+//!
+//! `cargo bench -- day_16` runs just one day.
+//!
+//! The `day_01_part_1_scan` group compares day 1's scalar digit scan
+//! with the one that checks eight bytes at a time, and `day_01_scan`
+//! compares its two ways of finding the digits and digit words. The
+//! `day_02_part_2_parsers` group compares day 2's `pest`, `nom`, and
+//! `chumsky` parsers, each followed by part 2, and `day_03_parsers`
+//! compares day 3's `pest` grammar with its grid scanner.
+//! `day_04_parsers` compares day 4's `pest` and `nom` parsers, and
+//! `day_05_parsers` compares day 5's `pest` and `chumsky` parsers.
+//!
+//! With the `arena` feature, the `arena` group also compares the regular
+//! parsers for days 3 and 5 with their `parse_in()` versions, which
+//! stage what they parse in a `bumpalo` arena.
+
+use std::hint::black_box;
+
+use advent_of_code_2023::{
+    days::{
+        day_01::Day01, day_02::Day02, day_03::Day03, day_04::Day04, day_05::Day05, day_06::Day06,
+        day_07::Day07, day_08::Day08, day_09::Day09, day_10::Day10, day_11::Day11, day_12::Day12,
+        day_13::Day13, day_14::Day14, day_15::Day15, day_16::Day16, day_17::Day17, day_18::Day18,
+        day_19::Day19, day_20::Day20, day_21::Day21, day_22::Day22, day_23::Day23, day_24::Day24,
+        day_25::Day25,
+    },
+    parsing::input_path,
+    solver::{Part, Solver},
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench_day<S: Solver>(c: &mut Criterion) {
+    let Ok(input) = std::fs::read_to_string(input_path(S::DAY)) else {
+        eprintln!("Skipping day {}: no input", S::DAY);
+        return;
+    };
+    let Ok(parsed) = S::parse(&input) else {
+        eprintln!("Skipping day {}: the input doesn't parse", S::DAY);
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("day_{:02}", S::DAY));
+    // Some days take seconds per iteration, so the default 100 samples
+    // would take far too long.
+    group.sample_size(10);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    for part in Part::BOTH {
+        if S::solve_part(&parsed, part).is_ok() {
+            group.bench_function(format!("part_{part}"), |b| {
+                b.iter(|| S::solve_part(black_box(&parsed), part));
+            });
+        }
+    }
+    group.finish();
+}
+
+/// Add up the calibration values of every line of `input` that
+/// `find_digits` finds digits in.
+fn calibration_sum(input: &str, find_digits: impl Fn(&str) -> Option<(u32, u32)>) -> u32 {
+    input
+        .lines()
+        .filter_map(find_digits)
+        .map(|(first, last)| 10 * first + last)
+        .sum()
+}
+
+/// Time scanning every line of day 1's input for its part 1 digits, a
+/// character at a time against eight bytes at a time.
+fn bench_day_01_part_1_scan(c: &mut Criterion) {
+    use advent_of_code_2023::days::day_01;
+
+    let Ok(input) = std::fs::read_to_string(input_path(1)) else {
+        eprintln!("Skipping the day 1 part 1 scan: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_01_part_1_scan");
+    group.bench_function("scalar", |b| {
+        b.iter(|| calibration_sum(black_box(&input), day_01::digits));
+    });
+    group.bench_function("swar", |b| {
+        b.iter(|| calibration_sum(black_box(&input), day_01::digits_swar));
+    });
+    group.finish();
+}
+
+/// Time scanning every line of day 1's input for its part 2 digits,
+/// checking for each word at every position against a single
+/// aho-corasick pass.
+fn bench_day_01_scan(c: &mut Criterion) {
+    use advent_of_code_2023::days::day_01;
+
+    let Ok(input) = std::fs::read_to_string(input_path(1)) else {
+        eprintln!("Skipping the day 1 scan: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_01_scan");
+    group.bench_function("windows", |b| {
+        b.iter(|| calibration_sum(black_box(&input), day_01::digits_and_words_by_windows));
+    });
+    group.bench_function("aho_corasick", |b| {
+        b.iter(|| calibration_sum(black_box(&input), day_01::digits_and_words));
+    });
+    group.finish();
+}
+
+/// Time parsing day 2's input and adding up the powers for part 2,
+/// with the `pest` grammar against the `nom` and `chumsky` combinators.
+fn bench_day_02_parsers(c: &mut Criterion) {
+    use advent_of_code_2023::days::day_02;
+
+    let Ok(input) = std::fs::read_to_string(input_path(2)) else {
+        eprintln!("Skipping the day 2 parsers: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_02_part_2_parsers");
+    group.bench_function("pest", |b| {
+        b.iter(|| {
+            day_02::parse_games(black_box(&input)).map(|games| day_02::sum_of_game_powers(&games))
+        });
+    });
+    group.bench_function("nom", |b| {
+        b.iter(|| {
+            day_02::parse_games_nom(black_box(&input))
+                .map(|games| day_02::sum_of_game_powers(&games))
+        });
+    });
+    group.bench_function("chumsky", |b| {
+        b.iter(|| {
+            day_02::parse_games_chumsky(black_box(&input))
+                .map(|games| day_02::sum_of_game_powers(&games))
+        });
+    });
+    group.finish();
+}
+
+/// Time parsing day 3's schematic with the `pest` grammar against
+/// walking the grid directly.
+fn bench_day_03_parsers(c: &mut Criterion) {
+    use advent_of_code_2023::days::day_03;
+
+    let Ok(input) = std::fs::read_to_string(input_path(3)) else {
+        eprintln!("Skipping the day 3 parsers: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_03_parsers");
+    group.bench_function("pest", |b| {
+        b.iter(|| day_03::parse_schematic_pest(black_box(&input)));
+    });
+    group.bench_function("scanner", |b| {
+        b.iter(|| day_03::parse_schematic(black_box(&input)));
+    });
+    group.finish();
+}
+
+/// Time parsing day 4's scratchcards with the `pest` grammar against
+/// the `nom` combinators.
+fn bench_day_04_parsers(c: &mut Criterion) {
+    use std::str::FromStr;
+
+    use advent_of_code_2023::days::day_04;
+
+    let Ok(input) = std::fs::read_to_string(input_path(4)) else {
+        eprintln!("Skipping the day 4 parsers: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_04_parsers");
+    group.bench_function("pest", |b| {
+        b.iter(|| day_04::ScratchCards::from_str(black_box(&input)));
+    });
+    group.bench_function("nom", |b| {
+        b.iter(|| day_04::parse_scratch_cards_nom(black_box(&input)));
+    });
+    group.finish();
+}
+
+/// Time parsing day 5's almanac with the `pest` grammar against the
+/// `chumsky` combinators.
+fn bench_day_05_parsers(c: &mut Criterion) {
+    use std::str::FromStr;
+
+    use advent_of_code_2023::days::day_05;
+
+    let Ok(input) = std::fs::read_to_string(input_path(5)) else {
+        eprintln!("Skipping the day 5 parsers: no input");
+        return;
+    };
+    let mut group = c.benchmark_group("day_05_parsers");
+    group.bench_function("pest", |b| {
+        b.iter(|| day_05::Almanac::from_str(black_box(&input)));
+    });
+    group.bench_function("chumsky", |b| {
+        b.iter(|| day_05::parse_almanac_chumsky(black_box(&input)));
+    });
+    group.finish();
+}
+
+/// Time `S::parse()` against `parse_in()` on the same input, reusing
+/// one arena that's reset between iterations, which is how the arena
+/// would be used when parsing lots of inputs.
+#[cfg(feature = "arena")]
+fn bench_arena<S: Solver>(
+    c: &mut Criterion,
+    parse_in: impl for<'a> Fn(&bumpalo::Bump, &'a str) -> miette::Result<S::Parsed<'a>>,
+) {
+    let Ok(input) = std::fs::read_to_string(input_path(S::DAY)) else {
+        eprintln!("Skipping day {}: no input", S::DAY);
+        return;
+    };
+
+    let mut group = c.benchmark_group(format!("arena/day_{:02}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    let mut arena = bumpalo::Bump::new();
+    group.bench_function("parse_in", |b| {
+        b.iter(|| {
+            arena.reset();
+            parse_in(&arena, black_box(&input)).map(drop)
+        });
+    });
+    group.finish();
+}
+
+#[cfg(feature = "arena")]
+fn arena(c: &mut Criterion) {
+    use advent_of_code_2023::days::{day_03, day_05};
+
+    bench_arena::<Day03>(c, day_03::parse_in);
+    bench_arena::<Day05>(c, day_05::parse_in);
+}
+
+criterion_group!(
+    days,
+    bench_day::<Day01>,
+    bench_day::<Day02>,
+    bench_day::<Day03>,
+    bench_day::<Day04>,
+    bench_day::<Day05>,
+    bench_day::<Day06>,
+    bench_day::<Day07>,
+    bench_day::<Day08>,
+    bench_day::<Day09>,
+    bench_day::<Day10>,
+    bench_day::<Day11>,
+    bench_day::<Day12>,
+    bench_day::<Day13>,
+    bench_day::<Day14>,
+    bench_day::<Day15>,
+    bench_day::<Day16>,
+    bench_day::<Day17>,
+    bench_day::<Day18>,
+    bench_day::<Day19>,
+    bench_day::<Day20>,
+    bench_day::<Day21>,
+    bench_day::<Day22>,
+    bench_day::<Day23>,
+    bench_day::<Day24>,
+    bench_day::<Day25>,
+);
+criterion_group!(scans, bench_day_01_part_1_scan, bench_day_01_scan);
+criterion_group!(
+    parsers,
+    bench_day_02_parsers,
+    bench_day_03_parsers,
+    bench_day_04_parsers,
+    bench_day_05_parsers
+);
+#[cfg(feature = "arena")]
+criterion_group!(arenas, arena);
+
+#[cfg(not(feature = "arena"))]
+criterion_main!(days, scans, parsers);
+#[cfg(feature = "arena")]
+criterion_main!(days, scans, parsers, arenas);
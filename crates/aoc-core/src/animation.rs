@@ -0,0 +1,48 @@
+//! Playing back the intermediate states of the days that simulate
+//! something, one frame at a time in the terminal.
+
+use std::{fmt::Display, io::Write, time::Duration};
+
+use miette::IntoDiagnostic;
+
+/// Clears the whole terminal.
+const CLEAR_SCREEN: &str = "\x1b[2J";
+
+/// Moves the cursor back to the top left without clearing anything, so
+/// each frame draws over the last one instead of scrolling.
+const CURSOR_HOME: &str = "\x1b[H";
+
+/// Clears from the cursor to the end of the screen, in case a frame is
+/// shorter than the one before it.
+const CLEAR_TO_END: &str = "\x1b[J";
+
+/// Something with a series of states worth watching.
+pub trait Animate {
+    /// Each state in order, drawn as text.
+    fn frames(&self) -> Vec<String>;
+}
+
+/// Draw each of `frames` over the last one on stdout, waiting `delay`
+/// between them.
+///
+/// # Errors
+///
+/// Returns an error if we can't write to stdout.
+pub fn play<T: Display>(
+    frames: impl IntoIterator<Item = T>,
+    delay: Duration,
+) -> miette::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    write!(stdout, "{CLEAR_SCREEN}").into_diagnostic()?;
+    for (number, frame) in frames.into_iter().enumerate() {
+        write!(
+            stdout,
+            "{CURSOR_HOME}{frame}\nFrame {}{CLEAR_TO_END}",
+            number + 1
+        )
+        .into_diagnostic()?;
+        stdout.flush().into_diagnostic()?;
+        std::thread::sleep(delay);
+    }
+    writeln!(stdout).into_diagnostic()
+}
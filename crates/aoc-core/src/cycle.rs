@@ -0,0 +1,122 @@
+//! Finding cycles in sequences like `x, f(x), f(f(x)), ...` using
+//! Brent's algorithm, so we can jump ahead to (say) the billionth
+//! value without computing all the ones in between.
+
+/// Where a sequence starts repeating itself, and how often.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The index of the first value that's part of the cycle.
+    pub start: usize,
+    /// The number of values in the cycle.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The smallest index whose value is the same as the value at `n`.
+    #[must_use]
+    pub const fn equivalent_index(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+/// Find the cycle in the sequence `initial, step(initial), ...`.
+///
+/// The sequence has to repeat eventually or this will never return.
+/// This only keeps two values of the sequence around at a time, unlike
+/// remembering every value we've seen.
+pub fn find_cycle<T: Clone + PartialEq>(initial: &T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // First find the length by letting the hare run ahead, moving the
+    // tortoise up to the hare every time the distance between them
+    // reaches the next power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then start the hare `length` steps ahead of the tortoise, and
+    // they'll first meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The value at index `n` of the sequence `initial, step(initial), ...`.
+///
+/// This uses the sequence's cycle to skip ahead, so like `find_cycle()`
+/// it never returns if the sequence doesn't repeat.
+pub fn nth_with_cycle<T: Clone + PartialEq>(
+    initial: &T,
+    mut step: impl FnMut(&T) -> T,
+    n: usize,
+) -> T {
+    let cycle = find_cycle(initial, &mut step);
+    (0..cycle.equivalent_index(n)).fold(initial.clone(), |value, _| step(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_cycle_after_a_tail() {
+        // 0, 1, 2, 3, 4, 5, 6, 2, 3, ...
+        let step = |&x: &u32| if x == 6 { 2 } else { x + 1 };
+        assert_eq!(
+            find_cycle(&0, step),
+            Cycle {
+                start: 2,
+                length: 5
+            }
+        );
+    }
+
+    #[test]
+    fn finds_cycle_with_no_tail() {
+        let step = |&x: &u32| (x + 3) % 7;
+        assert_eq!(
+            find_cycle(&0, step),
+            Cycle {
+                start: 0,
+                length: 7
+            }
+        );
+        assert_eq!(
+            find_cycle(&5, |&x: &u32| x),
+            Cycle {
+                start: 0,
+                length: 1
+            }
+        );
+    }
+
+    #[test]
+    fn skips_ahead_using_the_cycle() {
+        let step = |&x: &u64| (x * x + 1) % 255;
+        let expected = (0..1_000).fold(3, |x, _| step(&x));
+        assert_eq!(nth_with_cycle(&3, step, 1_000), expected);
+        assert_eq!(nth_with_cycle(&3, step, 0), 3);
+    }
+}
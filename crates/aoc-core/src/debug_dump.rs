@@ -0,0 +1,78 @@
+//! Writing intermediate state to files in `target/aoc-debug/`, for
+//! looking through after a run.
+//!
+//! This only does anything with the `debug-dumps` feature; without it,
+//! `dump()` doesn't even build the contents, so the days can call it
+//! wherever it's useful.
+
+use std::path::PathBuf;
+
+/// Where the dumps go: `aoc-debug/` in the `CARGO_TARGET_DIR` if that's
+/// set, and in `target/` at the top of the workspace if it isn't.
+#[must_use]
+pub fn dump_dir() -> PathBuf {
+    let target_dir = std::env::var_os("CARGO_TARGET_DIR").map_or_else(
+        || {
+            [env!("CARGO_MANIFEST_DIR"), "..", "..", "target"]
+                .iter()
+                .collect()
+        },
+        PathBuf::from,
+    );
+    target_dir.join("aoc-debug")
+}
+
+/// Add `contents()` to the end of the file `name` in `dump_dir()`.
+///
+/// Each file is emptied the first time it's written to in a run, so it
+/// only has this run's state in it. A dump that can't be written is reported
+/// on stderr rather than returned, since it shouldn't stop a day from
+/// being solved.
+#[cfg(feature = "debug-dumps")]
+pub fn dump(name: &str, contents: impl FnOnce() -> String) {
+    use std::{fs::OpenOptions, io::Write, sync::Mutex};
+
+    /// The files that have already been written to in this run.
+    static STARTED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    let path = dump_dir().join(name);
+    let mut started = STARTED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    let fresh = !started.iter().any(|started_name| started_name == name);
+    let written = path
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|()| {
+            OpenOptions::new()
+                .create(true)
+                .write(true)
+                .append(!fresh)
+                .truncate(fresh)
+                .open(&path)
+        })
+        .and_then(|mut file| file.write_all(contents().as_bytes()));
+    match written {
+        Ok(()) if fresh => started.push(name.to_string()),
+        Ok(()) => {}
+        Err(error) => eprintln!("Couldn't write {}: {error}", path.display()),
+    }
+}
+
+#[cfg(not(feature = "debug-dumps"))]
+#[allow(clippy::needless_pass_by_value)]
+pub fn dump(_name: &str, _contents: impl FnOnce() -> String) {}
+
+#[cfg(all(test, feature = "debug-dumps"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dumps_start_fresh_and_then_append() {
+        let name = "aoc_core/dump_test.txt";
+        dump(name, || "first\n".to_string());
+        dump(name, || "second\n".to_string());
+        let written = std::fs::read_to_string(dump_dir().join(name)).unwrap();
+        assert_eq!(written, "first\nsecond\n");
+    }
+}
@@ -0,0 +1,139 @@
+use std::ops::Add;
+
+use crate::grid::Position;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CardinalDirection {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl CardinalDirection {
+    pub const ALL: [Self; 4] = [Self::North, Self::South, Self::East, Self::West];
+
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::West => Self::South,
+            Self::South => Self::East,
+            Self::East => Self::North,
+        }
+    }
+
+    #[must_use]
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    /// The direction we're heading after bouncing off a `/` mirror.
+    #[must_use]
+    pub const fn rotate_slash(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::North,
+            Self::South => Self::West,
+            Self::West => Self::South,
+        }
+    }
+
+    /// The direction we're heading after bouncing off a `\` mirror.
+    #[must_use]
+    pub const fn rotate_backslash(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::South,
+            Self::South => Self::East,
+            Self::West => Self::North,
+        }
+    }
+
+    /// The two directions perpendicular to this one, which is where a
+    /// beam goes when it hits a splitter side-on.
+    #[must_use]
+    pub const fn split(self) -> [Self; 2] {
+        match self {
+            Self::East | Self::West => [Self::North, Self::South],
+            Self::North | Self::South => [Self::East, Self::West],
+        }
+    }
+}
+
+/// Taking a step from a position in a grid, which gives `None` if
+/// the step would take us to a negative row or column. Steps off the
+/// bottom or right edge of a grid aren't caught here, but indexing
+/// with `Array2::get()` will return `None` for those.
+impl Add<CardinalDirection> for Position {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: CardinalDirection) -> Self::Output {
+        let (row, col) = self;
+        Some(match rhs {
+            CardinalDirection::North => (row.checked_sub(1)?, col),
+            CardinalDirection::South => (row.checked_add(1)?, col),
+            CardinalDirection::East => (row, col.checked_add(1)?),
+            CardinalDirection::West => (row, col.checked_sub(1)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning_left_undoes_turning_right() {
+        for direction in CardinalDirection::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+        }
+    }
+
+    #[test]
+    fn mirrors_are_their_own_inverses() {
+        for direction in CardinalDirection::ALL {
+            assert_eq!(
+                direction.rotate_slash().reverse().rotate_slash(),
+                direction.reverse()
+            );
+            assert_eq!(
+                direction.rotate_backslash().reverse().rotate_backslash(),
+                direction.reverse()
+            );
+        }
+    }
+
+    #[test]
+    fn split_goes_both_ways_perpendicular() {
+        for direction in CardinalDirection::ALL {
+            let split = direction.split();
+            assert!(split.contains(&direction.turn_left()));
+            assert!(split.contains(&direction.turn_right()));
+        }
+    }
+
+    #[test]
+    fn steps_off_the_top_left_are_none() {
+        assert_eq!((0, 3) + CardinalDirection::North, None);
+        assert_eq!((3, 0) + CardinalDirection::West, None);
+        assert_eq!((0, 0) + CardinalDirection::South, Some((1, 0)));
+        assert_eq!((0, 0) + CardinalDirection::East, Some((0, 1)));
+    }
+}
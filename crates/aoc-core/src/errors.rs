@@ -0,0 +1,204 @@
+//! The errors the days share, and the conventions for all of them.
+//!
+//! Every diagnostic code has the form `aoc::<where>::<what>`, so the
+//! codes are stable and say where an error came from:
+//! `aoc::dayNN::...` (with a two-digit day) for a day's errors,
+//! `aoc::core::...` for the shared errors here, and
+//! `aoc::doctor::...` for `aoc doctor`'s problems.
+//!
+//! Each day has one top-level error enum, `DayNNError`, with a variant
+//! for everything that can go wrong on that day, each with its own
+//! code. A shared error that a day passes along goes in a variant that
+//! gives it the day's code, using `Coded` if it has labels worth
+//! keeping. The days' tests check all this with `check_codes()`.
+
+use std::{
+    collections::HashSet,
+    fmt::{Debug, Display},
+    path::PathBuf,
+};
+
+use miette::{Diagnostic, LabeledSpan, NarratableReportHandler, Report, SourceCode, SourceSpan};
+use ndarray::ShapeError;
+use pest::{error::InputLocation, RuleType};
+use strum::IntoEnumIterator;
+
+/// Errors that can come up when parsing a rectangular grid of characters.
+///
+/// Days that parse grids wrap this in their own error type, typically as
+/// a `#[from]` variant marked `#[error(transparent)]` with the day's code.
+#[derive(Debug, Diagnostic, thiserror::Error)]
+pub enum GridError {
+    #[error("Tried to parse a grid with no lines")]
+    #[diagnostic(code(aoc::core::empty_grid))]
+    Empty,
+
+    #[error("Row {row} has {found} characters, but the first row has {expected}")]
+    #[diagnostic(code(aoc::core::ragged_grid))]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error(transparent)]
+    #[diagnostic(code(aoc::core::grid_shape))]
+    ArrayShape(#[from] ShapeError),
+}
+
+/// The puzzle input for a day isn't where we expected it. The inputs
+/// can't be shared, so they aren't in the repository, and each person
+/// has to download their own.
+#[derive(Debug, Diagnostic, thiserror::Error)]
+#[error("There's no input for day {day} at {}", path.display())]
+#[diagnostic(
+    code(aoc::core::missing_input),
+    help("Download it with `aoc fetch --day {day}`, or copy your input to that path")
+)]
+pub struct MissingInputError {
+    pub day: u8,
+    pub path: PathBuf,
+}
+
+/// A failure from one of the `pest` grammars. `pest`'s own errors only
+/// keep the offending line, so this holds on to the whole input so
+/// `miette` can point to where the grammar gave up.
+#[derive(Debug, Diagnostic, thiserror::Error)]
+#[error("Failed to parse the input: {message}")]
+#[diagnostic(code(aoc::core::pest))]
+pub struct PestError {
+    #[source_code]
+    src: String,
+
+    message: String,
+
+    #[label("{message}")]
+    location: SourceSpan,
+}
+
+impl PestError {
+    #[must_use]
+    pub fn new<R: RuleType>(src: &str, error: &pest::error::Error<R>) -> Self {
+        let location = match error.location {
+            InputLocation::Pos(offset) => SourceSpan::new(offset.into(), 0),
+            InputLocation::Span((start, end)) => SourceSpan::new(start.into(), end - start),
+        };
+        Self {
+            src: src.to_string(),
+            message: error.variant.message().into_owned(),
+            location,
+        }
+    }
+}
+
+/// One of the shared errors, like `PestError`, as a particular day
+/// reports it: it has the same message, labels, and source code, but
+/// the day's own code.
+#[derive(Debug)]
+pub struct Coded<E> {
+    code: &'static str,
+    error: E,
+}
+
+impl<E> Coded<E> {
+    #[must_use]
+    pub const fn new(code: &'static str, error: E) -> Self {
+        Self { code, error }
+    }
+}
+
+impl<E: Display> Display for Coded<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+
+impl<E: std::error::Error> std::error::Error for Coded<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl<E: Diagnostic> Diagnostic for Coded<E> {
+    fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        Some(Box::new(self.code))
+    }
+
+    fn severity(&self) -> Option<miette::Severity> {
+        self.error.severity()
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.help()
+    }
+
+    fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.error.url()
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.error.source_code()
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.error.labels()
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.error.related()
+    }
+
+    fn diagnostic_source(&self) -> Option<&dyn Diagnostic> {
+        self.error.diagnostic_source()
+    }
+}
+
+/// Check a day's top-level error against the conventions above, for
+/// the days' tests.
+///
+/// Given `examples` with one value of each variant, this checks that
+/// every variant is there (going by the `Kind` that
+/// `strum::EnumDiscriminants` derives), and that each has its own code
+/// starting with `aoc::dayNN::`.
+///
+/// # Panics
+///
+/// Panics if any of that isn't true.
+pub fn check_codes<'a, E, Kind>(day: u8, examples: &'a [E])
+where
+    E: Diagnostic,
+    Kind: From<&'a E> + IntoEnumIterator + PartialEq + Debug,
+{
+    let kinds: Vec<Kind> = examples.iter().map(Kind::from).collect();
+    assert_eq!(
+        kinds,
+        Kind::iter().collect::<Vec<_>>(),
+        "There should be one example of each variant, in order"
+    );
+    let prefix = format!("aoc::day{day:02}::");
+    let mut codes = HashSet::new();
+    for example in examples {
+        let code = example
+            .code()
+            .as_deref()
+            .map(ToString::to_string)
+            .unwrap_or_default();
+        assert!(
+            code.starts_with(&prefix),
+            "{example:?} has the code {code:?}, which doesn't start with {prefix}"
+        );
+        assert!(codes.insert(code.clone()), "{code} is used more than once");
+    }
+}
+
+/// `miette`'s fancy reports need a terminal, so render errors as plain
+/// text that still includes the labels and snippets, for the browser
+/// playground and the Python bindings.
+#[must_use]
+pub fn render_plain(report: &Report) -> String {
+    let mut rendered = String::new();
+    match NarratableReportHandler::new().render_report(&mut rendered, report.as_ref()) {
+        Ok(()) => rendered,
+        Err(_) => report.to_string(),
+    }
+}
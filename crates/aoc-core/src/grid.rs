@@ -0,0 +1,298 @@
+use std::{
+    fmt::{Display, Write},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use ndarray::{Array2, ArrayView1};
+
+use crate::{direction::CardinalDirection, errors::GridError};
+
+/// A `(row, column)` position in a grid.
+pub type Position = (usize, usize);
+
+/// Parse a rectangular block of text into an `Array2`, converting
+/// each character with `parse_cell`.
+///
+/// # Errors
+///
+/// Returns an error if `s` has no lines (or its first line is empty),
+/// if the lines aren't all the same length, or if `parse_cell` fails on
+/// any character.
+pub fn parse_grid<T, E>(
+    s: &str,
+    parse_cell: impl FnMut(char) -> Result<T, E>,
+) -> Result<Array2<T>, E>
+where
+    E: From<GridError>,
+{
+    let num_columns = s
+        .lines()
+        .next()
+        .map(str::len)
+        .filter(|&len| len > 0)
+        .ok_or(GridError::Empty)?;
+    if let Some((row, line)) = s
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.len() != num_columns)
+    {
+        return Err(GridError::Ragged {
+            row,
+            expected: num_columns,
+            found: line.len(),
+        }
+        .into());
+    }
+    let cells = s
+        .lines()
+        .flat_map(str::chars)
+        .map(parse_cell)
+        .collect::<Result<Vec<_>, _>>()?;
+    let num_rows = cells.len() / num_columns;
+    Ok(Array2::from_shape_vec((num_rows, num_columns), cells).map_err(GridError::from)?)
+}
+
+/// A type that a grid can be made of, with one character per cell.
+pub trait CellParse: Sized {
+    /// What goes wrong parsing a cell. This also has to be able to hold
+    /// a `GridError`, for when the grid as a whole has the wrong shape.
+    type Error: From<GridError>;
+
+    /// The cell that `c` stands for, or an error if it isn't a legal
+    /// cell.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `c` isn't one of this type's characters.
+    fn from_char(c: char) -> Result<Self, Self::Error>;
+}
+
+/// A rectangular grid of cells, indexed by `(row, column)` positions.
+///
+/// Any cell type that implements `CellParse` can be parsed with
+/// `str::parse()`, and any
+/// cell type that implements `Display` gets a `Display` for the whole
+/// grid that prints it back out the way it was parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    array: Array2<T>,
+}
+
+impl<T> Grid<T> {
+    #[must_use]
+    pub fn num_rows(&self) -> usize {
+        self.array.nrows()
+    }
+
+    #[must_use]
+    pub fn num_cols(&self) -> usize {
+        self.array.ncols()
+    }
+
+    #[must_use]
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.array.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.array.get_mut(position)
+    }
+
+    /// The position one step from `position` in `direction`, or `None`
+    /// if that would take us off any edge of the grid.
+    #[must_use]
+    pub fn step(&self, position: Position, direction: CardinalDirection) -> Option<Position> {
+        let (row, col) = (position + direction)?;
+        (row < self.num_rows() && col < self.num_cols()).then_some((row, col))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.array.rows().into_iter()
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.array.columns().into_iter()
+    }
+
+    /// Every cell in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.array.iter()
+    }
+
+    /// Every position in the grid, in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let num_cols = self.num_cols();
+        (0..self.num_rows()).flat_map(move |row| (0..num_cols).map(move |col| (row, col)))
+    }
+
+    /// The underlying array, for days that need `ndarray`'s lanes and
+    /// axis operations.
+    #[must_use]
+    pub const fn array(&self) -> &Array2<T> {
+        &self.array
+    }
+
+    pub const fn array_mut(&mut self) -> &mut Array2<T> {
+        &mut self.array
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(array: Array2<T>) -> Self {
+        Self { array }
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        &self.array[position]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        &mut self.array[position]
+    }
+}
+
+impl<T: CellParse> FromStr for Grid<T> {
+    type Err = T::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_grid(s, T::from_char).map(Self::from)
+    }
+}
+
+/// A grid is serialized as a list of its rows, each a list of cells.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Grid<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows().map(|row| row.into_iter().collect::<Vec<_>>()))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                Display::fmt(cell, f)?;
+            }
+            f.write_char('\n')?;
+        }
+        Ok(())
+    }
+}
+
+/// The positions of every occurrence of `target` in a block of text,
+/// in reading order.
+pub fn find_all(s: &str, target: char) -> impl Iterator<Item = Position> + '_ {
+    s.lines().enumerate().flat_map(move |(row, line)| {
+        line.chars()
+            .enumerate()
+            .filter(move |&(_, c)| c == target)
+            .map(move |(col, _)| (row, col))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_rectangular_grid() {
+        let grid = parse_grid("12\n34\n56", |c| c.to_digit(10).ok_or(GridError::Empty)).unwrap();
+        assert_eq!(grid.dim(), (3, 2));
+        assert_eq!(grid[(2, 0)], 5);
+    }
+
+    #[test]
+    fn rejects_empty_first_line() {
+        let result = parse_grid("\n..", Ok::<_, GridError>);
+        assert!(matches!(result, Err(GridError::Empty)));
+    }
+
+    #[test]
+    fn rejects_ragged_grid() {
+        let result = parse_grid("..\n...\n.", Ok::<_, GridError>);
+        assert!(matches!(
+            result,
+            Err(GridError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Cell {
+        Open,
+        Wall,
+    }
+
+    impl CellParse for Cell {
+        type Error = GridError;
+
+        fn from_char(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '.' => Ok(Self::Open),
+                '#' => Ok(Self::Wall),
+                _ => Err(GridError::Empty),
+            }
+        }
+    }
+
+    impl Display for Cell {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_char(match self {
+                Self::Open => '.',
+                Self::Wall => '#',
+            })
+        }
+    }
+
+    #[test]
+    fn grid_parses_and_displays() {
+        let grid: Grid<Cell> = "#..\n.#.".parse().unwrap();
+        assert_eq!((grid.num_rows(), grid.num_cols()), (2, 3));
+        assert_eq!(grid[(1, 1)], Cell::Wall);
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "#..\n.#.\n");
+    }
+
+    #[test]
+    fn grid_steps_stay_in_bounds() {
+        let grid: Grid<Cell> = "...\n...".parse().unwrap();
+        assert_eq!(grid.step((1, 2), CardinalDirection::East), None);
+        assert_eq!(grid.step((1, 2), CardinalDirection::South), None);
+        assert_eq!(grid.step((0, 0), CardinalDirection::North), None);
+        assert_eq!(grid.step((0, 2), CardinalDirection::South), Some((1, 2)));
+    }
+
+    #[test]
+    fn grid_rows_cols_and_positions() {
+        let grid: Grid<Cell> = "#.\n.#\n..".parse().unwrap();
+        assert_eq!(grid.rows().count(), 3);
+        assert_eq!(grid.cols().count(), 2);
+        let walls = grid
+            .positions()
+            .filter(|&position| grid[position] == Cell::Wall)
+            .collect::<Vec<_>>();
+        assert_eq!(walls, [(0, 0), (1, 1)]);
+    }
+
+    #[test]
+    fn finds_every_occurrence() {
+        let positions = find_all("S..\n..S", 'S').collect::<Vec<_>>();
+        assert_eq!(positions, [(0, 0), (1, 2)]);
+    }
+
+    #[test]
+    fn grid_display_snapshot() {
+        let grid: Grid<Cell> = "#...#\n.#.#.\n..#..".parse().unwrap();
+        insta::assert_snapshot!(grid);
+    }
+}
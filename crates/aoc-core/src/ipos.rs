@@ -0,0 +1,166 @@
+use std::{
+    num::TryFromIntError,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use crate::{direction::CardinalDirection, grid::Position};
+
+/// A signed `(row, column)` position, for problems where we can wander
+/// off the top or left of the grid we were given, or where there's no
+/// grid at all and the coordinates can get very large.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IPos(pub i64, pub i64);
+
+impl IPos {
+    pub const ORIGIN: Self = Self(0, 0);
+
+    #[must_use]
+    pub const fn row(self) -> i64 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn col(self) -> i64 {
+        self.1
+    }
+
+    /// Move `distance` steps in `direction`.
+    #[must_use]
+    pub fn offset(self, direction: CardinalDirection, distance: i64) -> Self {
+        self + Self::from(direction) * distance
+    }
+
+    #[must_use]
+    pub const fn manhattan_distance(self, other: Self) -> u64 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+
+    /// Wrap this position onto a grid of the given size that's tiled
+    /// infinitely in every direction, giving the corresponding position
+    /// in the original grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension isn't positive.
+    #[must_use]
+    pub const fn wrap(self, num_rows: i64, num_cols: i64) -> Position {
+        // `rem_euclid` is never negative, so these casts can't fail.
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        (
+            self.0.rem_euclid(num_rows) as usize,
+            self.1.rem_euclid(num_cols) as usize,
+        )
+    }
+}
+
+/// The unit step in that direction, where North is up (decreasing rows).
+impl From<CardinalDirection> for IPos {
+    fn from(direction: CardinalDirection) -> Self {
+        match direction {
+            CardinalDirection::North => Self(-1, 0),
+            CardinalDirection::South => Self(1, 0),
+            CardinalDirection::East => Self(0, 1),
+            CardinalDirection::West => Self(0, -1),
+        }
+    }
+}
+
+impl TryFrom<Position> for IPos {
+    type Error = TryFromIntError;
+
+    fn try_from((row, col): Position) -> Result<Self, Self::Error> {
+        Ok(Self(i64::try_from(row)?, i64::try_from(col)?))
+    }
+}
+
+/// Fails if either coordinate is negative.
+impl TryFrom<IPos> for Position {
+    type Error = TryFromIntError;
+
+    fn try_from(IPos(row, col): IPos) -> Result<Self, Self::Error> {
+        Ok((usize::try_from(row)?, usize::try_from(col)?))
+    }
+}
+
+impl Add for IPos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Add<CardinalDirection> for IPos {
+    type Output = Self;
+
+    fn add(self, rhs: CardinalDirection) -> Self::Output {
+        self + Self::from(rhs)
+    }
+}
+
+impl Sub for IPos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl Mul<i64> for IPos {
+    type Output = Self;
+
+    fn mul(self, rhs: i64) -> Self::Output {
+        Self(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl Neg for IPos {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0, -self.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_can_go_negative() {
+        assert_eq!(IPos::ORIGIN + CardinalDirection::North, IPos(-1, 0));
+        assert_eq!(IPos::ORIGIN + CardinalDirection::West, IPos(0, -1));
+        assert_eq!(
+            IPos(2, 3).offset(CardinalDirection::South, 1_000_000),
+            IPos(1_000_002, 3)
+        );
+    }
+
+    #[test]
+    fn opposite_steps_cancel() {
+        for direction in CardinalDirection::ALL {
+            let position = IPos(4, -7);
+            assert_eq!(position + direction + direction.reverse(), position);
+            assert_eq!(IPos::from(direction.reverse()), -IPos::from(direction));
+        }
+    }
+
+    #[test]
+    fn manhattan_distance_ignores_signs() {
+        assert_eq!(IPos(-3, 4).manhattan_distance(IPos(2, -1)), 10);
+        assert_eq!((IPos(1, 2) - IPos(4, 6)) * 2, IPos(-6, -8));
+    }
+
+    #[test]
+    fn converts_to_and_from_positions() {
+        assert_eq!(IPos::try_from((3, 5)), Ok(IPos(3, 5)));
+        assert_eq!(Position::try_from(IPos(3, 5)), Ok((3, 5)));
+        assert!(Position::try_from(IPos(-1, 5)).is_err());
+    }
+
+    #[test]
+    fn wraps_onto_the_original_grid() {
+        assert_eq!(IPos(-1, 11).wrap(11, 11), (10, 0));
+        assert_eq!(IPos(25, -23).wrap(11, 11), (3, 10));
+    }
+}
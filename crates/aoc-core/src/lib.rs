@@ -0,0 +1,22 @@
+//! Code shared between the individual days' solutions, so each day
+//! doesn't have to re-implement the same grid and parsing boilerplate.
+
+pub mod animation;
+pub mod cycle;
+pub mod debug_dump;
+pub mod direction;
+pub mod errors;
+pub mod grid;
+pub mod ipos;
+pub mod logging;
+pub mod math;
+pub mod memo;
+pub mod parsing;
+pub mod polygon;
+pub mod progress;
+pub mod ranges;
+pub mod solver;
+pub mod viz;
+
+pub use direction::CardinalDirection;
+pub use grid::{CellParse, Grid, Position};
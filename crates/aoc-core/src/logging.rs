@@ -0,0 +1,11 @@
+use tracing_subscriber::EnvFilter;
+
+/// Send `tracing` output to stderr, so it never mixes with the answers
+/// on stdout. Nothing is logged unless it's turned on with `RUST_LOG`,
+/// e.g., `RUST_LOG=aoc_day12=debug`.
+pub fn init() {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+}
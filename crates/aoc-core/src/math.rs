@@ -0,0 +1,123 @@
+//! Bits of number theory that keep coming up when puzzles ask when
+//! several cycles will line up.
+
+use num::Integer;
+
+#[must_use]
+pub fn gcd<T: Integer + Copy>(a: T, b: T) -> T {
+    a.gcd(&b)
+}
+
+/// The least common multiple of all the values, or `None` if there
+/// aren't any.
+pub fn lcm_of<T: Integer>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().reduce(|a, b| a.lcm(&b))
+}
+
+/// Returns `(gcd(a, b), s, t)` where `a*s + b*t = gcd(a, b)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (divisor, s, t) = extended_gcd(b, a.rem_euclid(b));
+        (divisor, t, s - a.div_euclid(b) * t)
+    }
+}
+
+/// Solve the system `x ≡ residues[i] (mod moduli[i])` with the Chinese
+/// remainder theorem.
+///
+/// Returns `Some((x, m))` where `m` is the least common multiple of the
+/// moduli and `x` is the unique solution in `0..m`, or `None` if there's
+/// no solution (which can only happen when the moduli aren't pairwise
+/// coprime) or `m` doesn't fit in a `u64`. With no equations at all,
+/// every `x` works, so the answer is `(0, 1)`.
+///
+/// # Panics
+///
+/// Panics if there are different numbers of residues and moduli, or if
+/// any modulus is 0.
+#[must_use]
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Option<(u64, u64)> {
+    assert_eq!(
+        residues.len(),
+        moduli.len(),
+        "Every residue needs a modulus"
+    );
+    // `x` solves all the equations so far, and so does `x` plus any
+    // multiple of `m`.
+    let (mut x, mut m) = (0i128, 1i128);
+    for (&residue, &modulus) in residues.iter().zip(moduli) {
+        assert_ne!(modulus, 0, "Moduli have to be positive");
+        let (residue, modulus) = (i128::from(residue), i128::from(modulus));
+        // We need `x + m*k ≡ residue (mod modulus)`, i.e.,
+        // `m*k ≡ residue - x (mod modulus)`, which has a solution
+        // exactly when `gcd(m, modulus)` divides `residue - x`.
+        let (divisor, inverse, _) = extended_gcd(m, modulus);
+        let difference = residue - x;
+        if difference % divisor != 0 {
+            return None;
+        }
+        let step = modulus / divisor;
+        let lcm = m
+            .checked_mul(step)
+            .filter(|&lcm| lcm <= i128::from(u64::MAX))?;
+        // Both factors are less than `step`, so this product can't
+        // overflow a `u128` even when the moduli are huge.
+        let k = (difference / divisor).rem_euclid(step).unsigned_abs()
+            * inverse.rem_euclid(step).unsigned_abs()
+            % step.unsigned_abs();
+        // `m * k < m * step = lcm`, which fits in a `u64`.
+        x = (x + m * i128::try_from(k).ok()?).rem_euclid(lcm);
+        m = lcm;
+    }
+    Some((u64::try_from(x).ok()?, u64::try_from(m).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gcd_and_lcm() {
+        assert_eq!(gcd(12u64, 18), 6);
+        assert_eq!(gcd(17u64, 5), 1);
+        assert_eq!(lcm_of([4u64, 6, 10]), Some(60));
+        assert_eq!(lcm_of([7u64]), Some(7));
+        assert_eq!(lcm_of(Vec::<u64>::new()), None);
+    }
+
+    #[test]
+    fn crt_with_coprime_moduli() {
+        // The classic example from Sunzi Suanjing.
+        assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Some((23, 105)));
+        assert_eq!(crt(&[], &[]), Some((0, 1)));
+    }
+
+    #[test]
+    fn crt_with_shared_factors() {
+        assert_eq!(crt(&[2, 4], &[6, 8]), Some((20, 24)));
+        assert_eq!(crt(&[1, 2], &[4, 6]), None);
+        // All zeros gives the lcm, like day 8's cycles.
+        assert_eq!(crt(&[0, 0, 0], &[4, 6, 10]), Some((0, 60)));
+    }
+
+    #[test]
+    fn crt_with_huge_moduli() {
+        let big = u64::MAX - 58; // The largest prime below 2^64
+        assert_eq!(crt(&[big - 1], &[big]), Some((big - 1, big)));
+        assert_eq!(crt(&[1, 2], &[big, big - 1]), None);
+        assert_eq!(crt(&[3, 3], &[big, big]), Some((3, big)));
+    }
+
+    #[test]
+    fn crt_solutions_satisfy_every_equation() {
+        let residues = [3, 11, 20, 0];
+        let moduli = [7, 13, 29, 4];
+        let (x, m) = crt(&residues, &moduli).unwrap();
+        assert_eq!(m, 7 * 13 * 29 * 4);
+        for (residue, modulus) in residues.into_iter().zip(moduli) {
+            assert_eq!(x % modulus, residue);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// A cache for recursive functions, so a solution that counts things
+/// by recursion can skip the sub-problems it has already solved.
+///
+/// The recursive function takes a `&mut Memo` and wraps its body in
+/// `get_or_compute()`, passing the memo along to its recursive calls:
+///
+/// ```
+/// use aoc_core::memo::Memo;
+///
+/// fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+///     memo.get_or_compute(n, |memo| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(n - 1, memo) + fibonacci(n - 2, memo),
+///     })
+/// }
+///
+/// assert_eq!(fibonacci(90, &mut Memo::new()), 2_880_067_194_370_816_120);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The cached value for `key` if there is one, and otherwise the
+    /// result of `compute`, which is cached for next time. `compute`
+    /// gets the memo back so it can make (memoized) recursive calls.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The number of paths from the top left of a grid to `(row, col)`
+    /// moving only down and right.
+    fn num_paths(row: u64, col: u64, memo: &mut Memo<(u64, u64), u64>, calls: &mut u64) -> u64 {
+        *calls += 1;
+        memo.get_or_compute((row, col), |memo| {
+            if row == 0 || col == 0 {
+                1
+            } else {
+                num_paths(row - 1, col, memo, calls) + num_paths(row, col - 1, memo, calls)
+            }
+        })
+    }
+
+    #[test]
+    fn solves_each_subproblem_once() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        assert_eq!(num_paths(16, 16, &mut memo, &mut calls), 601_080_390);
+        assert_eq!(memo.len(), 17 * 17 - 1);
+        // Without the memo this would take over a billion calls.
+        assert!(calls < 1_000);
+    }
+}
@@ -0,0 +1,229 @@
+use std::{ffi::OsString, ops::Range, path::PathBuf, str::FromStr, sync::OnceLock};
+
+use miette::{Context, IntoDiagnostic, SourceSpan};
+
+use crate::errors::MissingInputError;
+
+/// Set by `set_inputs_dir()`, e.g., from `aoc --input-dir`.
+static INPUTS_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Where the sample inputs from the puzzle descriptions live:
+/// `src/inputs/` at the top of the workspace.
+///
+/// That's two levels up from this crate. The tests build these in with `include_str!`, so unlike the
+/// full inputs they can't be moved.
+#[must_use]
+pub fn samples_dir() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), "..", "..", "src", "inputs"]
+        .iter()
+        .collect()
+}
+
+/// Where the full puzzle inputs live.
+///
+/// This is the directory given to
+/// `set_inputs_dir()` if there was one, or else `AOC_INPUT_DIR` if
+/// that's set, so the inputs can be kept outside the repository, e.g.,
+/// in a private submodule. Otherwise they're in `src/inputs/` with the
+/// samples.
+#[must_use]
+pub fn inputs_dir() -> PathBuf {
+    resolve_inputs_dir(INPUTS_DIR.get(), std::env::var_os("AOC_INPUT_DIR"))
+}
+
+fn resolve_inputs_dir(chosen: Option<&PathBuf>, from_env: Option<OsString>) -> PathBuf {
+    chosen.cloned().unwrap_or_else(|| {
+        from_env
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(samples_dir, PathBuf::from)
+    })
+}
+
+/// Use `dir` for the full puzzle inputs instead of `AOC_INPUT_DIR` or
+/// `src/inputs/`. This is meant to be called once at startup; later
+/// calls are ignored, so the inputs can't move partway through a run.
+pub fn set_inputs_dir(dir: PathBuf) {
+    // Only the first call counts, so there's nothing to do with the error.
+    let _ = INPUTS_DIR.set(dir);
+}
+
+/// Where the puzzle input for the given day lives: `day_NN.txt` in
+/// `inputs_dir()`.
+#[must_use]
+pub fn input_path(day: u8) -> PathBuf {
+    inputs_dir().join(format!("day_{day:02}.txt"))
+}
+
+/// Read the puzzle input for the given day from `inputs_dir()`.
+///
+/// # Errors
+///
+/// Returns a `MissingInputError` if there's no input for the day, or
+/// another error if it's there but can't be read.
+pub fn read_input(day: u8) -> miette::Result<String> {
+    let path = input_path(day);
+    if !path.exists() {
+        return Err(MissingInputError { day, path }.into());
+    }
+    std::fs::read_to_string(&path)
+        .into_diagnostic()
+        .wrap_err_with(|| {
+            format!(
+                "Couldn't read the input for day {day} from {}",
+                path.display()
+            )
+        })
+}
+
+/// Parse each line of `s` as a `T`.
+///
+/// # Errors
+///
+/// Returns the first error from parsing a line.
+pub fn parse_lines<T: FromStr>(s: &str) -> Result<Vec<T>, T::Err> {
+    s.lines().map(T::from_str).collect()
+}
+
+/// Parse a whitespace-separated list of numbers (or anything else
+/// that implements `FromStr`).
+///
+/// # Errors
+///
+/// Returns the first error from parsing a number.
+pub fn parse_numbers<T: FromStr>(s: &str) -> Result<Vec<T>, T::Err> {
+    s.split_ascii_whitespace().map(T::from_str).collect()
+}
+
+/// One line of a puzzle input, along with where it is in the input, so
+/// errors about it can point to the right spot in the whole input.
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    /// The whole input, for an error's `#[source_code]`.
+    pub input: &'a str,
+    /// This line, without its line ending.
+    pub text: &'a str,
+    /// The line number, counting from 1 like editors do.
+    pub number: usize,
+    offset: usize,
+}
+
+impl<'a> Line<'a> {
+    /// A line without the rest of the input around it, like when the
+    /// input is being read a line at a time, so its errors only show
+    /// the line itself.
+    #[must_use]
+    pub const fn on_its_own(text: &'a str, number: usize) -> Self {
+        Self {
+            input: text,
+            text,
+            number,
+            offset: 0,
+        }
+    }
+
+    /// A span covering `range` of this line's text, as an offset into
+    /// the whole input.
+    #[must_use]
+    pub fn span(&self, range: Range<usize>) -> SourceSpan {
+        SourceSpan::new((self.offset + range.start).into(), range.len())
+    }
+
+    /// A span covering the whole line.
+    #[must_use]
+    pub fn full_span(&self) -> SourceSpan {
+        self.span(0..self.text.len())
+    }
+
+    /// The whitespace-separated words in this line, each with where it
+    /// is in the line.
+    pub fn words(&self) -> impl Iterator<Item = (Range<usize>, &'a str)> {
+        self.text
+            .split(|c: char| c.is_ascii_whitespace())
+            .scan(0, |start, word| {
+                let range = *start..*start + word.len();
+                // The separators are all one byte long.
+                *start = range.end + 1;
+                Some((range, word))
+            })
+            .filter(|(_, word)| !word.is_empty())
+    }
+}
+
+/// The lines of `input`, like `str::lines()`, but each one knows where
+/// it is in the input.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+        .enumerate()
+        .map(move |(index, (offset, line))| Line {
+            input,
+            text: line
+                .strip_suffix('\n')
+                .map_or(line, |line| line.strip_suffix('\r').unwrap_or(line)),
+            number: index + 1,
+            offset,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_with_extra_whitespace() {
+        let numbers = parse_numbers::<i64>("  7 -15   30 ").unwrap();
+        assert_eq!(numbers, [7, -15, 30]);
+    }
+
+    #[test]
+    fn the_inputs_dir_can_be_chosen() {
+        let chosen = PathBuf::from("chosen");
+        assert_eq!(
+            resolve_inputs_dir(Some(&chosen), Some("from_env".into())),
+            chosen
+        );
+        assert_eq!(
+            resolve_inputs_dir(None, Some("from_env".into())),
+            PathBuf::from("from_env")
+        );
+        assert_eq!(resolve_inputs_dir(None, Some("".into())), samples_dir());
+        assert_eq!(resolve_inputs_dir(None, None), samples_dir());
+    }
+
+    #[test]
+    fn missing_inputs_say_how_to_get_them() {
+        let error = read_input(0).unwrap_err();
+        let missing = error.downcast_ref::<MissingInputError>().unwrap();
+        assert_eq!(missing.path, input_path(0));
+    }
+
+    #[test]
+    fn parses_every_line() {
+        let numbers = parse_lines::<u8>("1\n2\n3\n").unwrap();
+        assert_eq!(numbers, [1, 2, 3]);
+        assert!(parse_lines::<u8>("1\nx").is_err());
+    }
+
+    #[test]
+    fn numbered_lines_know_where_they_are() {
+        let input = "ab cd\r\n\n  ef";
+        let lines: Vec<_> = numbered_lines(input).collect();
+        assert_eq!(
+            lines.iter().map(|line| line.text).collect::<Vec<_>>(),
+            input.lines().collect::<Vec<_>>()
+        );
+        assert_eq!(lines[2].number, 3);
+        let (range, word) = lines[2].words().next().unwrap();
+        assert_eq!(word, "ef");
+        assert_eq!(lines[2].span(range), SourceSpan::new(10.into(), 2));
+        assert_eq!(
+            lines[0].words().map(|(_, word)| word).collect::<Vec<_>>(),
+            ["ab", "cd"]
+        );
+    }
+}
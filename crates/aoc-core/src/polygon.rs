@@ -0,0 +1,79 @@
+//! Areas of polygons on the integer grid, using the shoelace formula
+//! and Pick's theorem.
+
+use crate::ipos::IPos;
+
+/// The area of the closed polygon with these vertices (in order, going
+/// either way around), using the shoelace formula. There's no need to
+/// repeat the first vertex at the end.
+///
+/// The polygons in these puzzles only have horizontal and vertical
+/// edges between integer vertices, so the area is always a whole
+/// number.
+pub fn polygon_area(vertices: impl IntoIterator<Item = IPos>) -> u64 {
+    let mut vertices = vertices.into_iter();
+    let Some(first) = vertices.next() else {
+        return 0;
+    };
+    let cross = |a: IPos, b: IPos| a.row() * b.col() - b.row() * a.col();
+    let (last, twice_area) = vertices.fold((first, 0), |(previous, twice_area), vertex| {
+        (vertex, twice_area + cross(previous, vertex))
+    });
+    let twice_area = (twice_area + cross(last, first)).unsigned_abs();
+    debug_assert!(twice_area % 2 == 0, "The area should be a whole number");
+    twice_area / 2
+}
+
+/// The number of integer points strictly inside a polygon with the given
+/// area and number of integer points on its boundary, from Pick's theorem
+/// (`A = i + b/2 - 1`).
+#[must_use]
+pub const fn interior_points(area: u64, boundary_len: u64) -> u64 {
+    (2 * area + 2 - boundary_len) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_of_a_rectangle() {
+        let rectangle = [IPos(0, 0), IPos(0, 3), IPos(2, 3), IPos(2, 0)];
+        assert_eq!(polygon_area(rectangle), 6);
+        // Direction around the polygon and where we start don't matter.
+        assert_eq!(polygon_area(rectangle.into_iter().rev()), 6);
+        assert_eq!(
+            polygon_area(rectangle.into_iter().cycle().skip(1).take(4)),
+            6
+        );
+    }
+
+    #[test]
+    fn area_of_an_l_shape() {
+        let l_shape = [
+            IPos(0, 0),
+            IPos(0, 1),
+            IPos(2, 1),
+            IPos(2, 3),
+            IPos(3, 3),
+            IPos(3, 0),
+        ];
+        assert_eq!(polygon_area(l_shape), 5);
+    }
+
+    #[test]
+    fn degenerate_polygons_have_no_area() {
+        assert_eq!(polygon_area([]), 0);
+        assert_eq!(polygon_area([IPos(2, 2)]), 0);
+        assert_eq!(polygon_area([IPos(0, 0), IPos(0, 5)]), 0);
+    }
+
+    #[test]
+    fn interior_points_of_a_square() {
+        // A 4x4 square has 16 boundary points around a 3x3 interior.
+        let square = [IPos(0, 0), IPos(0, 4), IPos(4, 4), IPos(4, 0)];
+        assert_eq!(interior_points(polygon_area(square), 16), 9);
+        // A unit square has no points inside it.
+        assert_eq!(interior_points(1, 4), 0);
+    }
+}
@@ -0,0 +1,32 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turn on progress bars for the slow days. They're off by default so
+/// tests, benchmarks, and `run-all` don't draw bars over each other.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// A progress bar for `len` steps that draws to stderr, or a hidden one
+/// if progress bars haven't been turned on with `enable()`.
+///
+/// Days drive these with `indicatif`'s `ParallelProgressIterator`, e.g.,
+/// `records.par_iter().progress_with(progress::bar(records.len()))`.
+// `indicatif` templates look like format strings, but aren't.
+#[allow(clippy::literal_string_with_formatting_args)]
+#[must_use]
+pub fn bar(len: usize) -> ProgressBar {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return ProgressBar::hidden();
+    }
+    let bar = ProgressBar::new(len as u64);
+    if let Ok(style) =
+        ProgressStyle::with_template("{elapsed_precise} [{wide_bar}] {pos}/{len} (eta {eta})")
+    {
+        bar.set_style(style);
+    }
+    bar
+}
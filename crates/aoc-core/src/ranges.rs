@@ -0,0 +1,322 @@
+//! Sets of `u64`s stored as ranges, and piecewise maps that shift
+//! different ranges of `u64`s by different amounts, so we can work with
+//! huge numbers of values without ever looking at them one at a time.
+
+use std::ops::Range;
+
+/// Shift both ends of `range` by `offset`, saturating at the ends of
+/// the `u64`s instead of overflowing.
+const fn shift_range(range: &Range<u64>, offset: i64) -> Range<u64> {
+    range.start.saturating_add_signed(offset)..range.end.saturating_add_signed(offset)
+}
+
+fn intersect_ranges(a: &Range<u64>, b: &Range<u64>) -> Option<Range<u64>> {
+    let start = a.start.max(b.start);
+    let end = a.end.min(b.end);
+    (start < end).then_some(start..end)
+}
+
+/// A set of `u64`s, stored as sorted, disjoint, non-adjacent, non-empty
+/// half-open ranges.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<Range<u64>>,
+}
+
+impl RangeSet {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The number of values in the set.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    #[must_use]
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: u64) -> bool {
+        self.ranges
+            .binary_search_by(|range| {
+                if value < range.start {
+                    std::cmp::Ordering::Greater
+                } else if value >= range.end {
+                    std::cmp::Ordering::Less
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .flat_map(|a| other.ranges.iter().filter_map(|b| intersect_ranges(a, b)))
+            .collect()
+    }
+
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        for range in &self.ranges {
+            let mut start = range.start;
+            for removed in &other.ranges {
+                if removed.end <= start || removed.start >= range.end {
+                    continue;
+                }
+                if removed.start > start {
+                    result.push(start..removed.start);
+                }
+                start = start.max(removed.end);
+            }
+            if start < range.end {
+                result.push(start..range.end);
+            }
+        }
+        result.into_iter().collect()
+    }
+
+    /// Add `offset` to every value in the set. Values that would go
+    /// below 0 or above `u64::MAX` are clamped there.
+    #[must_use]
+    pub fn shift(&self, offset: i64) -> Self {
+        self.ranges
+            .iter()
+            .map(|range| shift_range(range, offset))
+            .collect()
+    }
+}
+
+impl FromIterator<Range<u64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<u64>>>(iter: I) -> Self {
+        let mut ranges = iter
+            .into_iter()
+            .filter(|range| !range.is_empty())
+            .collect::<Vec<_>>();
+        ranges.sort_unstable_by_key(|range| range.start);
+        let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self { ranges: merged }
+    }
+}
+
+/// A map from `u64`s to `u64`s that adds a different offset to each
+/// of a collection of disjoint source ranges, and leaves every value
+/// outside those ranges alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RangeMap {
+    /// Sorted by the start of the source range.
+    entries: Vec<(Range<u64>, i64)>,
+}
+
+impl RangeMap {
+    /// The source ranges and their offsets, sorted by source.
+    pub fn entries(&self) -> impl Iterator<Item = &(Range<u64>, i64)> {
+        self.entries.iter()
+    }
+
+    /// Where `value` ends up.
+    #[must_use]
+    pub fn get(&self, value: u64) -> u64 {
+        let index = self
+            .entries
+            .partition_point(|(range, _)| range.end <= value);
+        match self.entries.get(index) {
+            Some((range, offset)) if range.contains(&value) => value.saturating_add_signed(*offset),
+            _ => value,
+        }
+    }
+
+    /// Every piece of the map, including the unmapped gaps between the
+    /// entries (with an offset of 0), so together they cover all the
+    /// `u64`s (except `u64::MAX`, which can't be in a `Range`).
+    fn pieces(&self) -> impl Iterator<Item = (Range<u64>, i64)> + '_ {
+        let mut next_start = 0;
+        self.entries
+            .iter()
+            .flat_map(move |(range, offset)| {
+                let gap = (next_start..range.start, 0);
+                next_start = range.end;
+                [gap, (range.clone(), *offset)]
+            })
+            .chain(std::iter::once_with(move || {
+                let last_end = self.entries.last().map_or(0, |(range, _)| range.end);
+                (last_end..u64::MAX, 0)
+            }))
+            .filter(|(range, _)| !range.is_empty())
+    }
+
+    /// Where all the values in `set` end up.
+    #[must_use]
+    pub fn apply(&self, set: &RangeSet) -> RangeSet {
+        self.pieces()
+            .flat_map(|(piece, offset)| {
+                set.ranges()
+                    .iter()
+                    .filter_map(move |range| intersect_ranges(range, &piece))
+                    .map(move |overlap| shift_range(&overlap, offset))
+            })
+            .collect()
+    }
+
+    /// Every value that ends up somewhere in `set`. More than one range
+    /// can land on the same values, so this can be bigger than `set`.
+    #[must_use]
+    pub fn preimage(&self, set: &RangeSet) -> RangeSet {
+        self.pieces()
+            .flat_map(|(piece, offset)| {
+                let image = shift_range(&piece, offset);
+                set.ranges()
+                    .iter()
+                    .filter_map(move |range| intersect_ranges(range, &image))
+                    .map(move |overlap| shift_range(&overlap, -offset))
+            })
+            .collect()
+    }
+
+    /// The map that applies `self` and then `other`.
+    #[must_use]
+    pub fn compose(&self, other: &Self) -> Self {
+        self.pieces()
+            .flat_map(|(piece, offset)| {
+                let image = shift_range(&piece, offset);
+                other
+                    .pieces()
+                    .filter_map(move |(other_piece, other_offset)| {
+                        let overlap = intersect_ranges(&image, &other_piece)?;
+                        Some((shift_range(&overlap, -offset), offset + other_offset))
+                    })
+            })
+            .filter(|&(_, offset)| offset != 0)
+            .collect()
+    }
+}
+
+/// The ranges have to be disjoint, but can come in any order.
+impl FromIterator<(Range<u64>, i64)> for RangeMap {
+    fn from_iter<I: IntoIterator<Item = (Range<u64>, i64)>>(iter: I) -> Self {
+        let mut entries = iter
+            .into_iter()
+            .filter(|(range, _)| !range.is_empty())
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|(range, _)| range.start);
+        debug_assert!(
+            entries
+                .windows(2)
+                .all(|pair| pair[0].0.end <= pair[1].0.start),
+            "The ranges in a `RangeMap` can't overlap"
+        );
+        Self { entries }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<u64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn overlapping_and_adjacent_ranges_merge() {
+        let ranges = set(&[5..8, 0..2, 7..10, 2..3, 12..12]);
+        assert_eq!(ranges.ranges(), [0..3, 5..10]);
+        assert_eq!(ranges.len(), 8);
+        assert!(ranges.contains(9));
+        assert!(!ranges.contains(10));
+    }
+
+    #[test]
+    #[expect(
+        clippy::single_range_in_vec_init,
+        reason = "These are sets of ranges, which sometimes only have one range"
+    )]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25]);
+        assert_eq!(a.union(&b).ranges(), [0..30]);
+        assert_eq!(a.intersect(&b).ranges(), [5..10, 20..25]);
+        assert_eq!(a.subtract(&b).ranges(), [0..5, 25..30]);
+        assert_eq!(b.subtract(&a).ranges(), [10..20]);
+        assert_eq!(a.shift(-5).ranges(), [0..5, 15..25]);
+    }
+
+    #[test]
+    #[expect(
+        clippy::single_range_in_vec_init,
+        reason = "These are sets of ranges, which sometimes only have one range"
+    )]
+    fn map_moves_only_mapped_values() {
+        let map: RangeMap = [(98..100, -48), (50..98, 2)].into_iter().collect();
+        assert_eq!(map.get(0), 0);
+        assert_eq!(map.get(50), 52);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(100), 100);
+        assert_eq!(map.apply(&set(&[45..55])).ranges(), [45..50, 52..57]);
+    }
+
+    #[test]
+    #[expect(
+        clippy::single_range_in_vec_init,
+        reason = "These are sets of ranges, which sometimes only have one range"
+    )]
+    fn preimage_finds_everything_that_lands_in_the_set() {
+        let map: RangeMap = [(98..100, -48), (50..98, 2)].into_iter().collect();
+        // 48 and 49 stay put, 52 to 54 come from 50 to 52, and 50 and 51
+        // come from 98 and 99.
+        assert_eq!(map.preimage(&set(&[48..55])).ranges(), [48..53, 98..100]);
+        for value in 0..120 {
+            assert_eq!(
+                map.preimage(&set(&[20..60])).contains(value),
+                (20..60).contains(&map.get(value))
+            );
+        }
+    }
+
+    #[test]
+    fn composition_matches_applying_in_turn() {
+        let first: RangeMap = [(98..100, -48), (50..98, 2)].into_iter().collect();
+        let second: RangeMap = [(15..52, -15), (52..54, -15), (0..15, 39)]
+            .into_iter()
+            .collect();
+        let composed = first.compose(&second);
+        for value in 0..120 {
+            assert_eq!(composed.get(value), second.get(first.get(value)));
+        }
+        let seeds = set(&[79..93, 55..68]);
+        assert_eq!(composed.apply(&seeds), second.apply(&first.apply(&seeds)));
+    }
+}
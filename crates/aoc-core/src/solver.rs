@@ -0,0 +1,179 @@
+use std::{fmt::Display, time::Duration};
+
+use miette::Diagnostic;
+use num::BigInt;
+// `std::time::Instant` panics in the browser, so we use `web-time`,
+// which is just `std`'s version everywhere else.
+use web_time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::One => f.write_str("1"),
+            Self::Two => f.write_str("2"),
+        }
+    }
+}
+
+/// A puzzle answer. Every answer this year is an integer, but different
+/// days compute them with different integer types, so we store them all
+/// as a `BigInt`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Answer(BigInt);
+
+macro_rules! answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Self(BigInt::from(value))
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(u32, u64, usize, i64, BigInt);
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+/// The error from a part that hasn't been solved yet, so callers can
+/// tell "not done" apart from a part that actually failed.
+#[derive(Debug, Diagnostic, thiserror::Error)]
+#[error("Day {day} part {part} hasn't been solved")]
+pub struct Unsolved {
+    pub day: u8,
+    pub part: Part,
+}
+
+/// The result of running one part, along with how long it took.
+#[derive(Debug)]
+pub struct PartRun {
+    pub part: Part,
+    pub answer: miette::Result<Answer>,
+    pub solve_time: Duration,
+}
+
+impl PartRun {
+    #[must_use]
+    pub fn is_unsolved(&self) -> bool {
+        self.answer
+            .as_ref()
+            .is_err_and(|error| error.downcast_ref::<Unsolved>().is_some())
+    }
+}
+
+/// The results of parsing a day's input once and running some of its
+/// parts, with timings for each phase.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub parts: Vec<PartRun>,
+}
+
+/// A solution to one day's puzzle, split into parsing the input and
+/// then solving each part using the parsed input.
+///
+/// The parsed input is a generic associated type so that days can
+/// borrow from the input string instead of copying out of it.
+pub trait Solver {
+    const DAY: u8;
+
+    type Parsed<'input>;
+
+    /// # Errors
+    ///
+    /// Returns an error if `input` isn't a valid input for this day.
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>>;
+
+    /// # Errors
+    ///
+    /// Returns an error if there's no answer for this input, or if part 1
+    /// hasn't been solved for this day.
+    fn part_1(_parsed: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Err(Unsolved {
+            day: Self::DAY,
+            part: Part::One,
+        })?
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if there's no answer for this input, or if part 2
+    /// hasn't been solved for this day.
+    fn part_2(_parsed: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Err(Unsolved {
+            day: Self::DAY,
+            part: Part::Two,
+        })?
+    }
+
+    /// Solve one part using already parsed input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if that part fails.
+    fn solve_part(parsed: &Self::Parsed<'_>, part: Part) -> miette::Result<Answer> {
+        match part {
+            Part::One => Self::part_1(parsed),
+            Part::Two => Self::part_2(parsed),
+        }
+    }
+
+    /// Parse `input` once and then solve each of `parts`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input doesn't parse or any part fails.
+    fn solve(input: &str, parts: &[Part]) -> miette::Result<Vec<Answer>> {
+        let parsed = Self::parse(input)?;
+        parts
+            .iter()
+            .map(|&part| Self::solve_part(&parsed, part))
+            .collect()
+    }
+
+    /// Like `solve()`, but times parsing and each part separately, and
+    /// keeps going if a part fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input doesn't parse.
+    fn run(input: &str, parts: &[Part]) -> miette::Result<DayRun> {
+        let start = Instant::now();
+        let parsed = Self::parse(input)?;
+        let parse_time = start.elapsed();
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = Self::solve_part(&parsed, part);
+                PartRun {
+                    part,
+                    answer,
+                    solve_time: start.elapsed(),
+                }
+            })
+            .collect();
+        Ok(DayRun {
+            day: Self::DAY,
+            parse_time,
+            parts,
+        })
+    }
+}
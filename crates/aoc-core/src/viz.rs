@@ -0,0 +1,252 @@
+//! Drawing grids, paths through them, and ranges as SVG, so we can see
+//! what a day's solution is actually doing. With the `png` feature,
+//! pictures can also be rendered to PNG with `resvg`.
+
+use std::{fmt::Display, ops::Range, path::Path};
+
+use itertools::Itertools;
+use miette::{Context, IntoDiagnostic};
+
+use crate::grid::{Grid, Position};
+
+/// How many pixels wide and tall each grid cell is.
+pub const CELL_SIZE: usize = 10;
+
+/// A picture of a grid with `num_rows` by `num_cols` cells. Everything
+/// is drawn in cell coordinates, and later drawing goes on top of
+/// earlier drawing.
+#[derive(Debug, Clone)]
+pub struct Svg {
+    num_rows: usize,
+    num_cols: usize,
+    elements: Vec<String>,
+}
+
+impl Svg {
+    #[must_use]
+    pub const fn new(num_rows: usize, num_cols: usize) -> Self {
+        Self {
+            num_rows,
+            num_cols,
+            elements: Vec::new(),
+        }
+    }
+
+    /// A picture the size of `grid`, with each cell filled with the
+    /// color from `fill`, or left blank if that returns `None`.
+    #[must_use]
+    pub fn for_grid<T>(grid: &Grid<T>, fill: impl Fn(&T) -> Option<&'static str>) -> Self {
+        let mut svg = Self::new(grid.num_rows(), grid.num_cols());
+        for position in grid.positions() {
+            if let Some(color) = fill(&grid[position]) {
+                svg.cell(position, color);
+            }
+        }
+        svg
+    }
+
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.num_cols * CELL_SIZE
+    }
+
+    #[must_use]
+    pub const fn height(&self) -> usize {
+        self.num_rows * CELL_SIZE
+    }
+
+    /// Fill the cell at `position` with `color`.
+    pub fn cell(&mut self, (row, col): Position, color: &str) -> &mut Self {
+        self.elements.push(format!(
+            r#"<rect x="{}" y="{}" width="{CELL_SIZE}" height="{CELL_SIZE}" fill="{color}"/>"#,
+            col * CELL_SIZE,
+            row * CELL_SIZE,
+        ));
+        self
+    }
+
+    /// Draw a dot in the middle of the cell at `position`.
+    pub fn dot(&mut self, position: Position, color: &str) -> &mut Self {
+        let (x, y) = center(position);
+        self.elements.push(format!(
+            r#"<circle cx="{x}" cy="{y}" r="{}" fill="{color}"/>"#,
+            CELL_SIZE * 2 / 5
+        ));
+        self
+    }
+
+    /// Draw a line through the middles of the cells in `positions`, and
+    /// back to the first one if `closed` is true.
+    pub fn path(
+        &mut self,
+        positions: impl IntoIterator<Item = Position>,
+        color: &str,
+        closed: bool,
+    ) -> &mut Self {
+        let points = positions
+            .into_iter()
+            .map(|position| {
+                let (x, y) = center(position);
+                format!("{x},{y}")
+            })
+            .join(" ");
+        let shape = if closed { "polygon" } else { "polyline" };
+        self.elements.push(format!(
+            r#"<{shape} points="{points}" fill="none" stroke="{color}" stroke-width="{}" stroke-linejoin="round"/>"#,
+            CELL_SIZE / 4
+        ));
+        self
+    }
+
+    /// Write `text` in the middle of the cell at `position`, small
+    /// enough that one character fits in the cell.
+    pub fn text(&mut self, position: Position, text: &str, color: &str) -> &mut Self {
+        let (x, y) = center(position);
+        let text = text
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;");
+        self.elements.push(format!(
+            r#"<text x="{x}" y="{y}" font-size="{}" font-family="monospace" text-anchor="middle" dominant-baseline="central" fill="{color}">{text}</text>"#,
+            CELL_SIZE * 4 / 5
+        ));
+        self
+    }
+
+    /// Draw `ranges` as bars across row `row`, scaled so that `domain`
+    /// spans the full width of the picture.
+    // Precision doesn't matter when we're turning things into pixels.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn ranges(
+        &mut self,
+        row: usize,
+        ranges: &[Range<u64>],
+        domain: &Range<u64>,
+        color: &str,
+    ) -> &mut Self {
+        let scale = self.width() as f64 / (domain.end - domain.start).max(1) as f64;
+        for range in ranges {
+            let start = range.start.clamp(domain.start, domain.end) - domain.start;
+            let end = range.end.clamp(domain.start, domain.end) - domain.start;
+            self.elements.push(format!(
+                r#"<rect x="{:.2}" y="{}" width="{:.2}" height="{CELL_SIZE}" fill="{color}"/>"#,
+                start as f64 * scale,
+                row * CELL_SIZE,
+                (end - start) as f64 * scale,
+            ));
+        }
+        self
+    }
+
+    /// Save the picture to `path`, as a PNG if the extension is `.png`
+    /// (which needs the `png` feature) and as SVG otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be written, or if we're asked
+    /// for a PNG without the `png` feature.
+    pub fn save(&self, path: &Path) -> miette::Result<()> {
+        if path.extension().is_some_and(|extension| extension == "png") {
+            return self.save_png(path);
+        }
+        std::fs::write(path, self.to_string())
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't write the picture to {}", path.display()))
+    }
+
+    #[cfg(feature = "png")]
+    fn save_png(&self, path: &Path) -> miette::Result<()> {
+        use resvg::{tiny_skia, usvg};
+
+        let tree =
+            usvg::Tree::from_str(&self.to_string(), &usvg::Options::default()).into_diagnostic()?;
+        let size = tree.size().to_int_size();
+        let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+            .ok_or_else(|| miette::miette!("Can't render an empty picture"))?;
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        pixmap
+            .save_png(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't write the picture to {}", path.display()))
+    }
+
+    #[cfg(not(feature = "png"))]
+    #[allow(clippy::unused_self)]
+    fn save_png(&self, _path: &Path) -> miette::Result<()> {
+        Err(miette::miette!(
+            help = "Rebuild with `--features png`, or save to a `.svg` file instead",
+            "Saving PNGs needs the `png` feature"
+        ))
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (self.width(), self.height());
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+        )?;
+        writeln!(
+            f,
+            r#"<rect width="{width}" height="{height}" fill="white"/>"#
+        )?;
+        for element in &self.elements {
+            writeln!(f, "{element}")?;
+        }
+        f.write_str("</svg>")
+    }
+}
+
+/// Something we can draw a picture of.
+pub trait Visualize {
+    fn to_svg(&self) -> Svg;
+}
+
+const fn center((row, col): Position) -> (usize, usize) {
+    (
+        col * CELL_SIZE + CELL_SIZE / 2,
+        row * CELL_SIZE + CELL_SIZE / 2,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_cells_and_paths_in_pixels() {
+        let mut svg = Svg::new(2, 3);
+        svg.cell((1, 2), "red")
+            .path([(0, 0), (0, 1)], "blue", false);
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"width="30" height="20""#));
+        assert!(svg.contains(r#"<rect x="20" y="10" width="10" height="10" fill="red"/>"#));
+        assert!(svg.contains(r#"<polyline points="5,5 15,5""#));
+        assert!(svg.ends_with("</svg>"));
+    }
+
+    #[test]
+    fn escapes_text() {
+        let mut svg = Svg::new(1, 2);
+        svg.text((0, 1), "&", "black");
+        assert!(svg.to_string().contains(r#"x="15" y="5""#));
+        assert!(svg.to_string().contains(">&amp;</text>"));
+    }
+
+    #[test]
+    fn scales_ranges_to_the_width() {
+        let mut svg = Svg::new(1, 10);
+        svg.ranges(0, &[5..10, 15..30], &(0..20), "green");
+        let svg = svg.to_string();
+        assert!(svg.contains(r#"x="25.00" y="0" width="25.00""#));
+        assert!(svg.contains(r#"x="75.00" y="0" width="25.00""#));
+    }
+
+    #[test]
+    #[cfg(not(feature = "png"))]
+    fn png_needs_the_feature() {
+        let svg = Svg::new(1, 1);
+        assert!(svg.save(Path::new("never_written.png")).is_err());
+    }
+}
@@ -0,0 +1,454 @@
+use std::{cmp::Reverse, io::BufRead, str::FromStr, sync::LazyLock};
+
+use aho_corasick::AhoCorasick;
+use aoc_core::{
+    parsing::{numbered_lines, Line},
+    solver::{Answer, Solver},
+};
+use miette::{Diagnostic, SourceSpan};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 1.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day01ErrorKind), derive(EnumIter))]
+pub enum Day01Error {
+    #[error("There are no digits on line {line_number}")]
+    #[diagnostic(
+        code(aoc::day01::no_digits),
+        help("Part 1 only counts the digits themselves; part 2 also counts them spelled out, like \"one\"")
+    )]
+    NoDigits {
+        #[source_code]
+        src: String,
+        line_number: usize,
+        #[label("No digits in this line")]
+        location: SourceSpan,
+    },
+
+    #[error("Couldn't read the calibration document")]
+    #[diagnostic(code(aoc::day01::read))]
+    Read(#[from] std::io::Error),
+
+    #[error("Illegal digit word on line {line_number}")]
+    #[diagnostic(
+        code(aoc::day01::illegal_digit_word),
+        help("Each line of a digit word list should be `word=value`, like `one=1`")
+    )]
+    IllegalDigitWord {
+        #[source_code]
+        src: String,
+        line_number: usize,
+        #[label("Expected `word=value`")]
+        location: SourceSpan,
+    },
+}
+
+/// The spelled out digits, in order, so `DIGIT_WORDS[d - 1]` is `d`.
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The first and last of `digits`, which are the same if there's only one.
+fn first_and_last(mut digits: impl DoubleEndedIterator<Item = u32>) -> Option<(u32, u32)> {
+    let first = digits.next()?;
+    Some((first, digits.next_back().unwrap_or(first)))
+}
+
+/// Part 1 only counts the actual digits in `line`.
+fn get_digits(line: &str) -> impl DoubleEndedIterator<Item = u32> + '_ {
+    line.chars().filter_map(|c| c.to_digit(10))
+}
+
+/// Part 1's first and last digits in `line`, a character at a time.
+/// `digits_swar()` is the fast version; this is kept to check it
+/// against and to compare their speeds.
+#[must_use]
+pub fn digits(line: &str) -> Option<(u32, u32)> {
+    first_and_last(get_digits(line))
+}
+
+/// `0x01` in every byte of a `u64`.
+const ONES: u64 = u64::MAX / 255;
+
+/// The bytes of `chunk` that are ASCII digits, as `0x80` in those bytes
+/// of the result and `0x00` in the rest. This is the "has a byte
+/// between" trick from Bit Twiddling Hacks: with the top bit of each
+/// byte masked off, no byte's arithmetic can carry into the next, so
+/// all eight bytes are checked at once.
+const fn digit_bytes(chunk: u64) -> u64 {
+    let low_bits = chunk & (ONES * 0x7F);
+    // The bytes below 0x3A, just past `b'9'`, and the ones above 0x2F,
+    // just before `b'0'`.
+    let below = ONES * (0x7F + 0x3A) - low_bits;
+    let above = low_bits + ONES * (0x7F - 0x2F);
+    below & above & !chunk & (ONES * 0x80)
+}
+
+/// The value of the ASCII digit `byte`.
+fn digit_value(byte: u8) -> u32 {
+    u32::from(byte - b'0')
+}
+
+/// Part 1's first and last digits in `line`, checking eight bytes at a
+/// time.
+///
+/// This is SIMD within a register, like `memchr` does without real SIMD
+/// instructions. The first digit is found scanning forward from the
+/// start and the last scanning backward from the end, so most of a
+/// long line is never looked at. Bytes of non-ASCII characters all
+/// have their top bit set, so they're never mistaken for digits.
+#[must_use]
+pub fn digits_swar(line: &str) -> Option<(u32, u32)> {
+    let bytes = line.as_bytes();
+    let first = {
+        let chunks = bytes.chunks_exact(8);
+        let rest_start = bytes.len() - chunks.remainder().len();
+        chunks
+            .enumerate()
+            .find_map(|(index, chunk)| {
+                let digits = digit_bytes(u64::from_le_bytes(chunk.try_into().ok()?));
+                // Little-endian, so the first byte is the lowest one.
+                (digits != 0).then(|| index * 8 + digits.trailing_zeros() as usize / 8)
+            })
+            .or_else(|| {
+                bytes[rest_start..]
+                    .iter()
+                    .position(u8::is_ascii_digit)
+                    .map(|position| rest_start + position)
+            })?
+    };
+    let last = {
+        let chunks = bytes.rchunks_exact(8);
+        let rest = chunks.remainder();
+        chunks
+            .enumerate()
+            .find_map(|(index, chunk)| {
+                let digits = digit_bytes(u64::from_le_bytes(chunk.try_into().ok()?));
+                let chunk_start = bytes.len() - (index + 1) * 8;
+                // The last byte is the highest one.
+                (digits != 0).then(|| chunk_start + 7 - digits.leading_zeros() as usize / 8)
+            })
+            .or_else(|| rest.iter().rposition(u8::is_ascii_digit))?
+    };
+    Some((digit_value(bytes[first]), digit_value(bytes[last])))
+}
+
+/// The words that part 2 counts as digits, each with the value it
+/// stands for.
+///
+/// They're data rather than code, so other languages or longer lists
+/// (with "zero" or "ten", say) just need a different list. The digits
+/// `0` to `9` always count as themselves too.
+///
+/// A list can be read from text with one `word=value` per line, where
+/// blank lines and lines starting with `#` are skipped.
+///
+/// Words can overlap, as in "oneight", and one can be inside another,
+/// like "eight" in "eighteen". Where two words start at the same spot,
+/// the longer one is the one that counts.
+#[derive(Debug, Clone)]
+pub struct DigitWords {
+    /// The words and then the digits, in the order `finder` numbers them.
+    patterns: Vec<(String, u32)>,
+    finder: AhoCorasick,
+}
+
+impl DigitWords {
+    /// The digit words in `words`, along with the digits themselves.
+    /// An empty word would be found everywhere, so those are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the words are too big to search for, which would take
+    /// billions of letters.
+    #[must_use]
+    pub fn new<W: Into<String>>(words: impl IntoIterator<Item = (W, u32)>) -> Self {
+        let patterns: Vec<(String, u32)> = words
+            .into_iter()
+            .map(|(word, value)| (word.into(), value))
+            .filter(|(word, _)| !word.is_empty())
+            .chain((0..=9).map(|digit: u32| (digit.to_string(), digit)))
+            .collect();
+        let finder = AhoCorasick::new(patterns.iter().map(|(word, _)| word))
+            .expect("the digit words should fit in an automaton");
+        Self { patterns, finder }
+    }
+
+    /// The puzzle's own list: "one" to "nine".
+    #[must_use]
+    pub fn english() -> &'static Self {
+        static ENGLISH: LazyLock<DigitWords> =
+            LazyLock::new(|| DigitWords::new(DIGIT_WORDS.into_iter().zip(1..)));
+        &ENGLISH
+    }
+
+    /// The first and last digits in `line`, in one pass over it. The
+    /// overlapping matches come out in the order they end in, so we
+    /// keep the one that starts first (and is longest) and the one
+    /// that starts last (and is longest).
+    #[must_use]
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let mut matches = self.finder.find_overlapping_iter(line);
+        let found = matches.next()?;
+        let (first, last) = matches.fold((found, found), |(first, last), found| {
+            let first =
+                if (found.start(), Reverse(found.len())) < (first.start(), Reverse(first.len())) {
+                    found
+                } else {
+                    first
+                };
+            let last = if (found.start(), found.len()) > (last.start(), last.len()) {
+                found
+            } else {
+                last
+            };
+            (first, last)
+        });
+        let value = |found: aho_corasick::Match| self.patterns[found.pattern().as_usize()].1;
+        Some((value(first), value(last)))
+    }
+
+    /// The same as `first_and_last()`, but by checking for every word
+    /// at every position in `line`. This is kept to check the automaton
+    /// against and to compare their speeds.
+    #[must_use]
+    pub fn first_and_last_by_windows(&self, line: &str) -> Option<(u32, u32)> {
+        // Every suffix of `line`, so each word that starts at that spot
+        // is a prefix of it.
+        let windows = line.char_indices().map(|(i, _)| &line[i..]);
+        first_and_last(windows.filter_map(|window| {
+            self.patterns
+                .iter()
+                .filter(|(word, _)| window.starts_with(word.as_str()))
+                .max_by_key(|(word, _)| word.len())
+                .map(|&(_, value)| value)
+        }))
+    }
+}
+
+impl FromStr for DigitWords {
+    type Err = Day01Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = numbered_lines(s)
+            .filter(|line| {
+                let text = line.text.trim();
+                !text.is_empty() && !text.starts_with('#')
+            })
+            .map(|line| {
+                line.text
+                    .split_once('=')
+                    .and_then(|(word, value)| {
+                        let word = word.trim();
+                        (!word.is_empty()).then_some((word, value.trim().parse().ok()?))
+                    })
+                    .ok_or_else(|| Day01Error::IllegalDigitWord {
+                        src: s.to_string(),
+                        line_number: line.number,
+                        location: line.full_span(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::new(words))
+    }
+}
+
+/// Part 2's first and last digits in `line`, counting "one" to "nine".
+#[must_use]
+pub fn digits_and_words(line: &str) -> Option<(u32, u32)> {
+    DigitWords::english().first_and_last(line)
+}
+
+/// `digits_and_words()` by checking every word at every position.
+#[must_use]
+pub fn digits_and_words_by_windows(line: &str) -> Option<(u32, u32)> {
+    DigitWords::english().first_and_last_by_windows(line)
+}
+
+/// The first digit in `line` followed by the last, as a two-digit number,
+/// where `find_digits` finds the first and last digits.
+fn calibration_value(
+    line: &Line,
+    find_digits: impl Fn(&str) -> Option<(u32, u32)>,
+) -> Result<u32, Day01Error> {
+    let (first, last) = find_digits(line.text).ok_or_else(|| Day01Error::NoDigits {
+        src: line.input.to_string(),
+        line_number: line.number,
+        location: line.full_span(),
+    })?;
+    Ok(10 * first + last)
+}
+
+fn calibration_total(
+    lines: &[Line],
+    find_digits: impl Fn(&str) -> Option<(u32, u32)> + Copy,
+) -> Result<u32, Day01Error> {
+    lines
+        .iter()
+        .map(|line| calibration_value(line, find_digits))
+        .sum()
+}
+
+/// Part 2's total for `input`, counting `words` as digits.
+///
+/// # Errors
+///
+/// Returns an error on the first line without any digits.
+pub fn calibration_total_with(input: &str, words: &DigitWords) -> Result<u32, Day01Error> {
+    let lines = numbered_lines(input).collect::<Vec<_>>();
+    calibration_total(&lines, |line| words.first_and_last(line))
+}
+
+/// Add up the calibration values from `reader` a line at a time.
+///
+/// Documents of any size (or piped into stdin) can be handled without
+/// reading them all into memory first. `find_digits` finds each line's
+/// first and last digits, so it's `digits` for part 1, and
+/// `digits_and_words` or `DigitWords::first_and_last()` for part 2.
+/// The total is a `u64` since a big enough document would overflow a
+/// `u32`.
+///
+/// # Errors
+///
+/// Returns an error if `reader` fails, or on the first line without any
+/// digits, pointing to that line on its own.
+pub fn stream_calibration_total(
+    mut reader: impl BufRead,
+    find_digits: impl Fn(&str) -> Option<(u32, u32)>,
+) -> Result<u64, Day01Error> {
+    let mut total = 0;
+    let mut text = String::new();
+    for number in 1.. {
+        text.clear();
+        if reader.read_line(&mut text)? == 0 {
+            break;
+        }
+        let line = Line::on_its_own(text.trim_end_matches(['\n', '\r']), number);
+        total += u64::from(calibration_value(&line, &find_digits)?);
+    }
+    Ok(total)
+}
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    const DAY: u8 = 1;
+
+    type Parsed<'input> = Vec<Line<'input>>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(numbered_lines(input).collect())
+    }
+
+    fn part_1(lines: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(calibration_total(lines, digits_swar)?.into())
+    }
+
+    fn part_2(lines: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(calibration_total(lines, digits_and_words)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+    use test_case::test_case;
+
+    #[test]
+    fn check_test_inputs() {
+        let input = include_str!("../../../src/inputs/day_01_test.txt");
+        let lines = numbered_lines(input).collect::<Vec<_>>();
+        assert_eq!(calibration_total(&lines, digits).unwrap(), 142);
+
+        let input = include_str!("../../../src/inputs/day_01_part_2_test.txt");
+        let lines = numbered_lines(input).collect::<Vec<_>>();
+        assert_eq!(calibration_total(&lines, digits_and_words).unwrap(), 281);
+    }
+
+    #[test_case("two1nine", Some((2, 9)) ; "words around a digit")]
+    #[test_case("oneight", Some((1, 8)) ; "overlapping words")]
+    #[test_case("xtwone3four", Some((2, 4)) ; "overlap at the start")]
+    #[test_case("7", Some((7, 7)) ; "single digit")]
+    #[test_case("0eighthree", Some((0, 3)) ; "zero and overlap at the end")]
+    #[test_case("zero", None ; "zero isn't a word")]
+    fn scanners_agree(line: &str, expected: Option<(u32, u32)>) {
+        assert_eq!(digits_and_words(line), expected);
+        assert_eq!(digits_and_words_by_windows(line), expected);
+    }
+
+    #[test_case("eighteen3", Some((18, 3)) ; "longest word at a spot wins")]
+    #[test_case("2seventeen", Some((2, 17)) ; "longest word at the end wins")]
+    #[test_case("zeroten", Some((0, 10)) ; "words that aren't digits")]
+    #[test_case("eightwo", Some((8, 2)) ; "the usual words still count")]
+    fn extended_words(line: &str, expected: Option<(u32, u32)>) {
+        let words = DigitWords::from_str(
+            "# The puzzle's words, and a few more\none=1\ntwo=2\nthree=3\nfour=4\nfive=5\n\
+             six=6\nseven=7\neight=8\nnine=9\n\nzero=0\nten=10\nseventeen=17\neighteen=18\n",
+        )
+        .unwrap();
+        assert_eq!(words.first_and_last(line), expected);
+        assert_eq!(words.first_and_last_by_windows(line), expected);
+    }
+
+    #[test]
+    fn other_languages() {
+        let words = DigitWords::new([("un", 1), ("deux", 2), ("trois", 3)]);
+        let total = calibration_total_with("deuxabcun\n4trois\n", &words).unwrap();
+        assert_eq!(total, 21 + 43);
+    }
+
+    #[test]
+    fn points_at_the_line_without_digits() {
+        let input = "1abc2\npqrstu\n";
+        let lines = numbered_lines(input).collect::<Vec<_>>();
+        let error = calibration_total(&lines, digits);
+        assert!(matches!(
+            error,
+            Err(Day01Error::NoDigits { line_number: 2, location, .. })
+                if location == SourceSpan::new(6.into(), 6)
+        ));
+    }
+
+    #[test]
+    fn streaming_gets_the_same_totals() {
+        let input = include_str!("../../../src/inputs/day_01_test.txt");
+        let total = stream_calibration_total(input.as_bytes(), digits).unwrap();
+        assert_eq!(total, 142);
+
+        let input = include_str!("../../../src/inputs/day_01_part_2_test.txt");
+        let total = stream_calibration_total(input.as_bytes(), digits_and_words).unwrap();
+        assert_eq!(total, 281);
+
+        let error = stream_calibration_total(&b"1abc2\r\npqrstu\r\n"[..], digits);
+        assert!(matches!(
+            error,
+            Err(Day01Error::NoDigits { line_number: 2, location, .. })
+                if location == SourceSpan::new(0.into(), 6)
+        ));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let lines = numbered_lines("abc").collect::<Vec<_>>();
+        let examples = [
+            calibration_total(&lines, digits_and_words).unwrap_err(),
+            std::io::Error::other("the document went away").into(),
+            DigitWords::from_str("one=1\ntwo").unwrap_err(),
+        ];
+        check_codes::<_, Day01ErrorKind>(1, &examples);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn swar_matches_scalar(line in "[a-z0-9é/:]{0,40}") {
+            prop_assert_eq!(digits_swar(&line), digits(&line));
+        }
+    }
+}
@@ -0,0 +1,585 @@
+use std::fmt::Display;
+
+use aoc_core::{
+    errors::{Coded, PestError},
+    solver::{Answer, Solver},
+};
+use itertools::Itertools;
+use miette::{Diagnostic, LabeledSpan, SourceSpan};
+use pest::error::ErrorVariant;
+use pest_consume::{match_nodes, Error, Nodes, Parser};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 2.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day02ErrorKind), derive(EnumIter))]
+pub enum Day02Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(Coded<PestError>),
+
+    #[error("Failed to parse the games")]
+    #[diagnostic(code(aoc::day02::nom_parse))]
+    NomParse {
+        #[source_code]
+        src: String,
+
+        #[label("Parsing failed here ({kind:?})")]
+        location: SourceSpan,
+
+        kind: nom::error::ErrorKind,
+    },
+
+    #[error("Failed to parse the games")]
+    #[diagnostic(code(aoc::day02::chumsky_parse))]
+    ChumskyParse {
+        #[source_code]
+        src: String,
+
+        #[label(collection)]
+        problems: Vec<LabeledSpan>,
+    },
+}
+
+impl From<PestError> for Day02Error {
+    fn from(error: PestError) -> Self {
+        Self::Parse(Coded::new("aoc::day02::parse", error))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+/// The cubes the Elf shows in one handful.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Reveal {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Reveal {
+    /// Whether there are enough cubes of each color in `bag` to show
+    /// this handful.
+    #[must_use]
+    pub const fn is_possible(&self, bag: &Bag) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+}
+
+/// How many cubes of each color are in the bag.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bag {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl Bag {
+    /// The bag the Elf asks about in part 1.
+    pub const PART_1: Self = Self {
+        red: 12,
+        green: 13,
+        blue: 14,
+    };
+
+    /// A bag with no cubes at all.
+    pub const EMPTY: Self = Self {
+        red: 0,
+        green: 0,
+        blue: 0,
+    };
+
+    /// The number of cubes of each color multiplied together.
+    #[must_use]
+    pub const fn power(&self) -> u32 {
+        self.red * self.green * self.blue
+    }
+
+    /// The smallest bag that holds everything in this one and enough to
+    /// show `reveal` as well.
+    #[must_use]
+    pub fn holding(self, reveal: &Reveal) -> Self {
+        Self {
+            red: self.red.max(reveal.red),
+            green: self.green.max(reveal.green),
+            blue: self.blue.max(reveal.blue),
+        }
+    }
+}
+
+/// Colors with a count of zero are left out, since the input only lists
+/// the colors that were actually revealed.
+impl Display for Reveal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let counts = [
+            (self.red, "red"),
+            (self.green, "green"),
+            (self.blue, "blue"),
+        ];
+        let counts = counts
+            .iter()
+            .filter(|(count, _)| *count > 0)
+            .map(|(count, color)| format!("{count} {color}"))
+            .join(", ");
+        f.write_str(&counts)
+    }
+}
+
+type CubeCount = (u32, Color);
+
+impl FromIterator<CubeCount> for Reveal {
+    fn from_iter<I: IntoIterator<Item = CubeCount>>(iter: I) -> Self {
+        let mut red = 0;
+        let mut green = 0;
+        let mut blue = 0;
+        for (count, color) in iter {
+            match color {
+                Color::Red => red = red.max(count),
+                Color::Green => green = green.max(count),
+                Color::Blue => blue = blue.max(count),
+            }
+        }
+        Self { red, green, blue }
+    }
+}
+
+/// One game: its number and the handfuls the Elf showed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
+    pub number: u32,
+    pub reveals: Vec<Reveal>,
+}
+
+impl Game {
+    /// The fewest cubes of each color this game could have been played
+    /// with.
+    #[must_use]
+    pub fn minimum_bag(&self) -> Bag {
+        self.reveals.iter().fold(Bag::EMPTY, Bag::holding)
+    }
+
+    /// The power of `minimum_bag()`.
+    #[must_use]
+    pub fn power(&self) -> u32 {
+        self.minimum_bag().power()
+    }
+
+    /// Whether this game could have been played with the cubes in `bag`.
+    #[must_use]
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.reveals.iter().all(|reveal| reveal.is_possible(bag))
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Game {}: {}",
+            self.number,
+            self.reveals.iter().join("; ")
+        )
+    }
+}
+
+#[derive(Parser)]
+#[grammar = "grammars/day_02.pest"]
+struct GameParser;
+
+type Result<T> = std::result::Result<T, Error<Rule>>;
+type Node<'i> = pest_consume::Node<'i, Rule, ()>;
+
+#[allow(clippy::unnecessary_wraps, clippy::result_large_err)]
+#[pest_consume::parser]
+impl GameParser {
+    fn input(input: Node) -> Result<Vec<Game>> {
+        Ok(match_nodes!(input.into_children();
+            [game(g)..] => g.collect(),
+        ))
+    }
+
+    fn game(input: Node) -> Result<Game> {
+        Ok(match_nodes!(input.into_children();
+            [int(n), reveal(r)..] => Game { number: n, reveals: r.collect() },
+        ))
+    }
+
+    fn int(input: Node) -> Result<u32> {
+        let span = input.as_span();
+        input.as_str().parse().map_err(|e| {
+            Error::new_from_span(
+                ErrorVariant::CustomError {
+                    message: format!("ParseIntError: {e}"),
+                },
+                span,
+            )
+        })
+    }
+
+    fn reveal(input: Node) -> Result<Reveal> {
+        Ok(match_nodes!(input.into_children();
+            [cubeCount(c)..] => c.collect::<Reveal>(),
+        ))
+    }
+
+    fn cubeCount(input: Node) -> Result<CubeCount> {
+        Ok(match_nodes!(input.into_children();
+            [int(n), color(c)] => (n, c),
+        ))
+    }
+
+    fn color(input: Node) -> Result<Color> {
+        Ok(match_nodes!(input.into_children();
+            [red(c)] => c, [green(c)] => c, [blue(c)] => c,
+        ))
+    }
+
+    fn red(input: Node) -> Result<Color> {
+        Ok(Color::Red)
+    }
+
+    fn green(input: Node) -> Result<Color> {
+        Ok(Color::Green)
+    }
+
+    fn blue(input: Node) -> Result<Color> {
+        Ok(Color::Blue)
+    }
+}
+
+/// Parse the games with the `pest` grammar.
+///
+/// # Errors
+///
+/// Returns an error pointing at where the input stops looking like a
+/// list of games.
+pub fn parse_games(input: &str) -> std::result::Result<Vec<Game>, Day02Error> {
+    GameParser::parse(Rule::input, input)
+        .and_then(Nodes::single)
+        .and_then(GameParser::input)
+        .map_err(|error| PestError::new(input, &error).into())
+}
+
+/// The same games as `parse_games()`, parsed with `nom` combinators
+/// instead of a grammar.
+mod nom_parser {
+    use nom::{
+        branch::alt,
+        bytes::complete::tag,
+        character::complete::{multispace0, newline, space1, u32},
+        combinator::{all_consuming, value},
+        multi::separated_list1,
+        sequence::{preceded, separated_pair, terminated},
+        IResult,
+    };
+
+    use super::{Color, CubeCount, Game, Reveal};
+
+    fn color(input: &str) -> IResult<&str, Color> {
+        alt((
+            value(Color::Red, tag("red")),
+            value(Color::Green, tag("green")),
+            value(Color::Blue, tag("blue")),
+        ))(input)
+    }
+
+    fn cube_count(input: &str) -> IResult<&str, CubeCount> {
+        separated_pair(u32, space1, color)(input)
+    }
+
+    fn reveal(input: &str) -> IResult<&str, Reveal> {
+        separated_list1(tag(", "), cube_count)(input)
+            .map(|(input, counts)| (input, counts.into_iter().collect()))
+    }
+
+    fn game(input: &str) -> IResult<&str, Game> {
+        let (input, number) = preceded(terminated(tag("Game"), space1), u32)(input)?;
+        let (input, reveals) = preceded(tag(": "), separated_list1(tag("; "), reveal))(input)?;
+        Ok((input, Game { number, reveals }))
+    }
+
+    pub fn games(input: &str) -> IResult<&str, Vec<Game>> {
+        all_consuming(terminated(separated_list1(newline, game), multispace0))(input)
+    }
+}
+
+/// Parse the games with `nom`, which gets the same `Game`s as
+/// `parse_games()` so the two parsers can be compared.
+///
+/// # Errors
+///
+/// Returns an error pointing at where `nom` gave up.
+pub fn parse_games_nom(input: &str) -> std::result::Result<Vec<Game>, Day02Error> {
+    nom_parser::games(input)
+        .map(|(_, games)| games)
+        .map_err(|error| {
+            let (offset, kind) = match error {
+                nom::Err::Error(error) | nom::Err::Failure(error) => {
+                    (input.len() - error.input.len(), error.code)
+                }
+                nom::Err::Incomplete(_) => (input.len(), nom::error::ErrorKind::Eof),
+            };
+            Day02Error::NomParse {
+                src: input.to_string(),
+                location: SourceSpan::new(offset.into(), 0),
+                kind,
+            }
+        })
+}
+
+/// The same games again, parsed with `chumsky`.
+mod chumsky_parser {
+    use chumsky::prelude::*;
+    use miette::LabeledSpan;
+
+    use super::{Color, Game, Reveal};
+
+    /// Use `Rich` errors so parse failures know where in the input they happened.
+    type Extra<'a> = extra::Err<Rich<'a, char>>;
+
+    fn games<'a>() -> impl Parser<'a, &'a str, Vec<Game>, Extra<'a>> {
+        let number = text::int(10).try_map(|digits: &str, span| {
+            digits
+                .parse::<u32>()
+                .map_err(|error| Rich::custom(span, error))
+        });
+        let spaces = just(' ').repeated().at_least(1);
+        let color = choice((
+            just("red").to(Color::Red),
+            just("green").to(Color::Green),
+            just("blue").to(Color::Blue),
+        ));
+        let cube_count = number.then_ignore(spaces).then(color);
+        let reveal = cube_count
+            .separated_by(just(", "))
+            .at_least(1)
+            .collect::<Vec<_>>()
+            .map(|counts| counts.into_iter().collect::<Reveal>());
+        let game = just("Game")
+            .ignore_then(spaces)
+            .ignore_then(number)
+            .then_ignore(just(": "))
+            .then(reveal.separated_by(just("; ")).at_least(1).collect())
+            .map(|(number, reveals)| Game { number, reveals });
+        game.separated_by(text::newline())
+            .at_least(1)
+            .collect()
+            .padded()
+    }
+
+    /// The games in `input`, or where and why each problem chumsky found
+    /// went wrong.
+    pub fn parse(input: &str) -> Result<Vec<Game>, Vec<LabeledSpan>> {
+        games().parse(input).into_result().map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|error| {
+                    let span = error.span();
+                    LabeledSpan::new(Some(error.to_string()), span.start, span.end - span.start)
+                })
+                .collect()
+        })
+    }
+}
+
+/// Parse the games with `chumsky`, which, like `parse_games_nom()`,
+/// gets the same `Game`s as `parse_games()`.
+///
+/// # Errors
+///
+/// Returns an error labelling every problem `chumsky` found.
+pub fn parse_games_chumsky(input: &str) -> std::result::Result<Vec<Game>, Day02Error> {
+    chumsky_parser::parse(input).map_err(|problems| Day02Error::ChumskyParse {
+        src: input.to_string(),
+        problems,
+    })
+}
+
+/// The sum of the numbers of the games that could have been played with
+/// the cubes in `bag`; part 1 asks about `Bag::PART_1`.
+#[must_use]
+pub fn sum_of_legal_game_ids(games: &[Game], bag: &Bag) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_possible(bag))
+        .map(|game| game.number)
+        .sum()
+}
+
+/// The sum of the powers of the fewest cubes each game could have been
+/// played with.
+#[must_use]
+pub fn sum_of_game_powers(games: &[Game]) -> u32 {
+    games.iter().map(Game::power).sum()
+}
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    const DAY: u8 = 2;
+
+    type Parsed<'input> = Vec<Game>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(parse_games(input)?)
+    }
+
+    fn part_1(games: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(sum_of_legal_game_ids(games, &Bag::PART_1).into())
+    }
+
+    fn part_2(games: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(sum_of_game_powers(games).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_02_test.txt");
+        let games = parse_games(input).unwrap();
+        assert_eq!(sum_of_legal_game_ids(&games, &Bag::PART_1), 8);
+        assert_eq!(sum_of_game_powers(&games), 2286);
+    }
+
+    #[test]
+    fn minimum_bag_holds_every_reveal() {
+        let games = parse_games("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green").unwrap();
+        let bag = games[0].minimum_bag();
+        assert_eq!(
+            bag,
+            Bag {
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        );
+        assert_eq!(games[0].power(), 48);
+        assert!(games[0].is_possible(&bag));
+        assert!(!games[0].is_possible(&Bag { blue: 5, ..bag }));
+    }
+
+    #[test]
+    fn bigger_bags_allow_more_games() {
+        let input = include_str!("../../../src/inputs/day_02_test.txt");
+        let games = parse_games(input).unwrap();
+        let bag = Bag {
+            red: 20,
+            ..Bag::PART_1
+        };
+        // Game 3 needs 20 red; game 4 still needs 15 blue.
+        assert_eq!(sum_of_legal_game_ids(&games, &bag), 8 + 3);
+        assert_eq!(sum_of_legal_game_ids(&games, &Bag::EMPTY), 0);
+    }
+
+    #[test]
+    fn every_parser_gets_the_same_games() {
+        let input = include_str!("../../../src/inputs/day_02_test.txt");
+        let games = parse_games(input).unwrap();
+        assert_eq!(parse_games_nom(input).unwrap(), games);
+        assert_eq!(parse_games_chumsky(input).unwrap(), games);
+    }
+
+    #[test]
+    fn nom_error_points_into_input() {
+        let error = parse_games_nom("Game 1: purple 3").unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 8);
+    }
+
+    #[test]
+    fn parse_error_points_into_input() {
+        let error = parse_games("Game 1: purple 3").unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 8);
+    }
+
+    #[test]
+    fn every_parser_rejects_numbers_too_big_for_a_u32() {
+        let input = "Game 1: 99999999999 red";
+        let error = parse_games(input).unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels[0].offset(), 8);
+        assert!(parse_games_nom(input).is_err());
+        assert!(parse_games_chumsky(input).is_err());
+    }
+
+    #[test]
+    fn chumsky_error_points_into_input() {
+        let error = parse_games_chumsky("Game 1: purple 3").unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 8);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            parse_games("Game 1: purple 3").unwrap_err(),
+            parse_games_nom("Game 1: purple 3").unwrap_err(),
+            parse_games_chumsky("Game 1: purple 3").unwrap_err(),
+        ];
+        check_codes::<_, Day02ErrorKind>(2, &examples);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn reveal() -> impl Strategy<Value = Reveal> {
+        (0..20u32, 0..20u32, 0..20u32)
+            .prop_filter("a reveal has to show at least one cube", |&(r, g, b)| {
+                r + g + b > 0
+            })
+            .prop_map(|(red, green, blue)| Reveal { red, green, blue })
+    }
+
+    fn game() -> impl Strategy<Value = Game> {
+        (1..1000u32, prop::collection::vec(reveal(), 1..6))
+            .prop_map(|(number, reveals)| Game { number, reveals })
+    }
+
+    proptest! {
+        #[test]
+        fn games_round_trip(games in prop::collection::vec(game(), 1..10)) {
+            let input = games.iter().join("\n");
+            let parsed = parse_games(&input).unwrap();
+            prop_assert_eq!(&parse_games_nom(&input).unwrap(), &games);
+            prop_assert_eq!(&parse_games_chumsky(&input).unwrap(), &games);
+            prop_assert_eq!(parsed, games);
+        }
+
+        #[test]
+        fn power_is_product_of_maximums(game in game()) {
+            let max = |color: fn(&Reveal) -> u32| game.reveals.iter().map(color).max().unwrap();
+            prop_assert_eq!(
+                game.power(),
+                max(|r| r.red) * max(|r| r.green) * max(|r| r.blue)
+            );
+        }
+    }
+}
@@ -0,0 +1,614 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    errors::{Coded, PestError},
+    parsing::numbered_lines,
+    solver::{Answer, Solver},
+    viz::{Svg, Visualize},
+};
+use itertools::Itertools;
+use miette::{Diagnostic, SourceSpan};
+use pest::error::ErrorVariant;
+use pest_consume::{match_nodes, Error, Nodes, Parser};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 3.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day03ErrorKind), derive(EnumIter))]
+pub enum Day03Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(Coded<PestError>),
+
+    #[error("This number is too big to be a part number")]
+    #[diagnostic(
+        code(aoc::day03::number_too_large),
+        help("Part numbers have to fit in a `u32`")
+    )]
+    NumberTooLarge {
+        #[source_code]
+        src: String,
+
+        #[label("This one")]
+        location: SourceSpan,
+    },
+}
+
+impl From<PestError> for Day03Error {
+    fn from(error: PestError) -> Self {
+        Self::Parse(Coded::new("aoc::day03::parse", error))
+    }
+}
+
+trait NextTwo
+where
+    Self: Iterator,
+{
+    fn next_two(self) -> Option<(Self::Item, Self::Item)>;
+}
+
+impl<T> NextTwo for T
+where
+    T: Iterator,
+{
+    fn next_two(mut self) -> Option<(Self::Item, Self::Item)> {
+        let first = self.next()?;
+        let second = self.next()?;
+
+        if self.next().is_some() {
+            return None;
+        }
+
+        Some((first, second))
+    }
+}
+
+/// A line and column in the schematic, both counting from 0.
+pub type Location = (usize, usize);
+
+/// The locations around the run of cells on `line` from `start` up to
+/// (but not including) `end`, leaving out any that would be above or to
+/// the left of the grid. Ones past the bottom or right edge just won't
+/// have anything in them.
+fn surrounding(line: usize, start: usize, end: usize) -> impl Iterator<Item = Location> {
+    // The positions above, extending one to the left and right for the
+    // diagonals.
+    let top_line = line
+        .checked_sub(1)
+        .into_iter()
+        .flat_map(move |above| (start.saturating_sub(1)..=end).map(move |column| (above, column)));
+    // The positions below, also extending one to each side.
+    let bottom_line = (start.saturating_sub(1)..=end).map(move |column| (line + 1, column));
+    // The positions to the left and right.
+    [
+        start.checked_sub(1).map(|left| (line, left)),
+        Some((line, end)),
+    ]
+    .into_iter()
+    .flatten()
+    .chain(top_line)
+    .chain(bottom_line)
+}
+
+/// A number in the schematic, which is only a part number if there's a
+/// symbol next to it. It's on `line`, from column `start` up to (but not
+/// including) `end`.
+#[derive(Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Part {
+    pub number: u32,
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Part {
+    fn adjacent_fields(&self) -> impl Iterator<Item = Location> {
+        surrounding(self.line, self.start, self.end)
+    }
+
+    /// Every location covered by this part's digits.
+    pub fn locations(&self) -> impl Iterator<Item = Location> + '_ {
+        (self.start..self.end).map(|column| (self.line, column))
+    }
+}
+
+#[derive(Debug)]
+struct Symbol {
+    #[allow(clippy::struct_field_names)]
+    symbol: char,
+    line: usize,
+    column: usize,
+}
+
+#[derive(Debug)]
+enum Cell {
+    Part(Part),
+    Symbol(Symbol),
+}
+
+/// The numbers and symbols in the schematic, with queries for which
+/// of them are next to each other.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Schematic {
+    parts: Vec<Part>,
+    // JSON can't have tuples as keys.
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_symbols"))]
+    symbols: HashMap<Location, char>,
+    /// The index in `parts` of the part covering each location, so we
+    /// don't have to search the parts to find what's next to a symbol.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    part_indices: HashMap<Location, usize>,
+}
+
+/// The symbols as a list of `[line, column, symbol]`, in reading order.
+#[cfg(feature = "serde")]
+fn serialize_symbols<S: serde::Serializer>(
+    symbols: &HashMap<Location, char>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    let mut symbols: Vec<_> = symbols
+        .iter()
+        .map(|(&(line, column), &symbol)| (line, column, symbol))
+        .collect();
+    symbols.sort_unstable();
+    serializer.collect_seq(symbols)
+}
+
+impl Schematic {
+    fn with_capacity(num_parts: usize, num_symbols: usize) -> Self {
+        Self {
+            parts: Vec::with_capacity(num_parts),
+            symbols: HashMap::with_capacity(num_symbols),
+            part_indices: HashMap::new(),
+        }
+    }
+
+    fn add_part(&mut self, part: Part) {
+        let index = self.parts.len();
+        self.part_indices
+            .extend(part.locations().map(|location| (location, index)));
+        self.parts.push(part);
+    }
+
+    fn add_symbol(&mut self, location: Location, symbol: char) {
+        self.symbols.insert(location, symbol);
+    }
+
+    /// Every number in the schematic, whether or not it's a part number,
+    /// in reading order.
+    #[must_use]
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Every symbol in the schematic and where it is, in no particular
+    /// order.
+    pub fn symbols(&self) -> impl Iterator<Item = (Location, char)> + '_ {
+        self.symbols
+            .iter()
+            .map(|(&location, &symbol)| (location, symbol))
+    }
+
+    /// The symbol at `location`, if there is one.
+    #[must_use]
+    pub fn symbol_at(&self, location: Location) -> Option<char> {
+        self.symbols.get(&location).copied()
+    }
+
+    /// The number with a digit at `location`, if there is one.
+    #[must_use]
+    pub fn part_at(&self, location: Location) -> Option<&Part> {
+        self.part_indices
+            .get(&location)
+            .map(|&index| &self.parts[index])
+    }
+
+    /// The numbers next to `location`, including diagonally, each once
+    /// even if several of its digits are next to `location`.
+    pub fn parts_adjacent_to(&self, location: Location) -> impl Iterator<Item = &Part> + '_ {
+        let (line, column) = location;
+        surrounding(line, column, column + 1)
+            .filter_map(|location| self.part_indices.get(&location))
+            .unique()
+            .map(|&index| &self.parts[index])
+    }
+
+    /// The symbols next to any of `part`'s digits, and where they are.
+    pub fn symbols_adjacent_to<'a>(
+        &'a self,
+        part: &'a Part,
+    ) -> impl Iterator<Item = (Location, char)> + 'a {
+        part.adjacent_fields()
+            .filter_map(|location| Some((location, self.symbol_at(location)?)))
+    }
+
+    /// The part numbers: the numbers with at least one symbol next to
+    /// them.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Part> + '_ {
+        self.parts
+            .iter()
+            .filter(|part| self.symbols_adjacent_to(part).next().is_some())
+    }
+
+    /// The ratios of the gears made with `symbol`. A `symbol` is a gear
+    /// if it's next to exactly two numbers, and its ratio is the product
+    /// of those two numbers. Part 2 asks about `*`.
+    pub fn gear_ratios(&self, symbol: char) -> impl Iterator<Item = u32> + '_ {
+        self.symbols()
+            .filter(move |&(_, found)| found == symbol)
+            .filter_map(|(location, _)| self.gear_ratio(location))
+    }
+
+    /// The product of the two numbers next to `location`, if there are
+    /// exactly two.
+    fn gear_ratio(&self, location: Location) -> Option<u32> {
+        self.parts_adjacent_to(location)
+            .next_two()
+            .map(|(a, b)| a.number * b.number)
+    }
+
+    fn sum_of_part_numbers(&self) -> u32 {
+        self.part_numbers().map(|part| part.number).sum()
+    }
+
+    fn sum_of_gear_ratios(&self) -> u32 {
+        self.gear_ratios('*').sum()
+    }
+}
+
+/// Part numbers are drawn on green and the numbers that don't count on
+/// red, with part 2's gears on yellow and the other symbols on gray.
+impl Visualize for Schematic {
+    fn to_svg(&self) -> Svg {
+        let (num_rows, num_cols) = self
+            .parts
+            .iter()
+            .map(|part| (part.line, part.end - 1))
+            .chain(self.symbols.keys().copied())
+            .fold((0, 0), |(num_rows, num_cols), (line, column)| {
+                (num_rows.max(line + 1), num_cols.max(column + 1))
+            });
+        let mut svg = Svg::new(num_rows, num_cols);
+        for part in &self.parts {
+            let color = if self.symbols_adjacent_to(part).next().is_some() {
+                "palegreen"
+            } else {
+                "lightcoral"
+            };
+            // Padded, in case the number had leading zeros.
+            let digits = format!("{:0width$}", part.number, width = part.end - part.start);
+            for (location, digit) in part.locations().zip(digits.chars()) {
+                svg.cell(location, color)
+                    .text(location, &digit.to_string(), "black");
+            }
+        }
+        for (location, symbol) in self.symbols() {
+            let color = if symbol == '*' && self.gear_ratio(location).is_some() {
+                "gold"
+            } else {
+                "lightgray"
+            };
+            svg.cell(location, color)
+                .text(location, &symbol.to_string(), "black");
+        }
+        svg
+    }
+}
+
+impl Extend<Cell> for Schematic {
+    fn extend<I: IntoIterator<Item = Cell>>(&mut self, iter: I) {
+        for cell in iter {
+            match cell {
+                Cell::Part(part) => self.add_part(part),
+                Cell::Symbol(symbol) => {
+                    self.add_symbol((symbol.line, symbol.column), symbol.symbol);
+                }
+            }
+        }
+    }
+}
+
+impl FromIterator<Cell> for Schematic {
+    fn from_iter<I: IntoIterator<Item = Cell>>(iter: I) -> Self {
+        let mut schematic = Self::with_capacity(0, 0);
+        schematic.extend(iter);
+        schematic
+    }
+}
+
+#[derive(Parser)]
+#[grammar = "grammars/day_03.pest"]
+struct SchematicParser;
+
+type Result<T> = std::result::Result<T, Error<Rule>>;
+type Node<'i> = pest_consume::Node<'i, Rule, ()>;
+
+#[allow(clippy::unnecessary_wraps, clippy::result_large_err)]
+#[pest_consume::parser]
+impl SchematicParser {
+    fn input(input: Node) -> Result<Schematic> {
+        Ok(match_nodes!(input.into_children();
+            [cell(c)..] => c.collect::<Schematic>(),
+        ))
+    }
+
+    fn cell(input: Node) -> Result<Cell> {
+        Ok(match_nodes!(input.into_children();
+            [number(p)] => Cell::Part(p),
+            [symbol(s)] => Cell::Symbol(s),
+        ))
+    }
+
+    fn number(input: Node) -> Result<Part> {
+        let span = input.as_span();
+        let number = input.as_str().parse().map_err(|e| {
+            Error::new_from_span(
+                ErrorVariant::CustomError {
+                    message: format!("ParseIntError: {e}"),
+                },
+                span,
+            )
+        })?;
+        let (line, start) = span.start_pos().line_col();
+        let (_, end) = span.end_pos().line_col();
+        // `pest` counts lines and columns from 1.
+        Ok(Part {
+            number,
+            line: line - 1,
+            start: start - 1,
+            end: end - 1,
+        })
+    }
+
+    fn symbol(input: Node) -> Result<Symbol> {
+        let span = input.as_span();
+        let symbol = input.as_str().chars().next().ok_or_else(|| {
+            Error::new_from_span(
+                ErrorVariant::CustomError {
+                    message: format!("Symbol must be single character: {}", input.as_str()),
+                },
+                span,
+            )
+        })?;
+        let (line, column) = span.start_pos().line_col();
+        Ok(Symbol {
+            symbol,
+            line: line - 1,
+            column: column - 1,
+        })
+    }
+}
+
+/// Parse the schematic with the `pest` grammar.
+///
+/// This is slower than `parse_schematic()`, since `pest` finds each
+/// token's line and column by searching back through the input, and is
+/// kept to compare with it.
+///
+/// # Errors
+///
+/// Returns an error if a number is too big for a `u32` or the input is
+/// empty.
+pub fn parse_schematic_pest(input: &str) -> std::result::Result<Schematic, Day03Error> {
+    SchematicParser::parse(Rule::input, input)
+        .and_then(Nodes::single)
+        .and_then(SchematicParser::input)
+        .map_err(|error| PestError::new(input, &error).into())
+}
+
+/// Parse the schematic by walking each line once, a character at a
+/// time: `.` is empty, a run of digits is a part number, and anything
+/// else is a symbol.
+///
+/// # Errors
+///
+/// Returns an error pointing at any number too big for a `u32`.
+pub fn parse_schematic(input: &str) -> std::result::Result<Schematic, Day03Error> {
+    let mut schematic = Schematic::with_capacity(0, 0);
+    for (line_index, line) in numbered_lines(input).enumerate() {
+        let mut chars = line.text.char_indices().enumerate().peekable();
+        while let Some((column, (start_offset, c))) = chars.next() {
+            if c == '.' {
+                continue;
+            }
+            if !c.is_ascii_digit() {
+                schematic.add_symbol((line_index, column), c);
+                continue;
+            }
+            let mut end = (column + 1, start_offset + 1);
+            while let Some((column, (offset, _))) = chars.next_if(|(_, (_, c))| c.is_ascii_digit())
+            {
+                end = (column + 1, offset + 1);
+            }
+            let (end_column, end_offset) = end;
+            let number = line.text[start_offset..end_offset].parse().map_err(|_| {
+                Day03Error::NumberTooLarge {
+                    src: input.to_string(),
+                    location: line.span(start_offset..end_offset),
+                }
+            })?;
+            schematic.add_part(Part {
+                number,
+                line: line_index,
+                start: column,
+                end: end_column,
+            });
+        }
+    }
+    Ok(schematic)
+}
+
+/// Parse the schematic like `parse_schematic_pest()`, but gather the
+/// cells in `arena` first, so the parts and symbols can each be
+/// allocated once at their final size instead of growing as cells turn
+/// up.
+///
+/// # Errors
+///
+/// Returns the same errors as `parse_schematic_pest()`.
+#[cfg(feature = "arena")]
+pub fn parse_in(arena: &bumpalo::Bump, input: &str) -> miette::Result<Schematic> {
+    let cells =
+        cells_in(arena, input).map_err(|error| Day03Error::from(PestError::new(input, &error)))?;
+    let num_parts = cells
+        .iter()
+        .filter(|cell| matches!(cell, Cell::Part(_)))
+        .count();
+    let mut schematic = Schematic::with_capacity(num_parts, cells.len() - num_parts);
+    schematic.extend(cells);
+    Ok(schematic)
+}
+
+#[cfg(feature = "arena")]
+#[allow(clippy::result_large_err)]
+fn cells_in<'a>(
+    arena: &'a bumpalo::Bump,
+    input: &str,
+) -> Result<bumpalo::collections::Vec<'a, Cell>> {
+    let node = SchematicParser::parse(Rule::input, input)?.single()?;
+    let mut cells = bumpalo::collections::Vec::new_in(arena);
+    for cell in node.into_children() {
+        cells.push(SchematicParser::cell(cell)?);
+    }
+    Ok(cells)
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    const DAY: u8 = 3;
+
+    type Parsed<'input> = Schematic;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(parse_schematic(input)?)
+    }
+
+    fn part_1(schematic: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(schematic.sum_of_part_numbers().into())
+    }
+
+    fn part_2(schematic: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(schematic.sum_of_gear_ratios().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_03_test.txt");
+        let schematic = parse_schematic(input).unwrap();
+        assert_eq!(schematic.sum_of_part_numbers(), 4361);
+        assert_eq!(schematic.sum_of_gear_ratios(), 467_835);
+    }
+
+    #[test]
+    fn scanner_matches_pest() {
+        let input = include_str!("../../../src/inputs/day_03_test.txt");
+        let scanned = parse_schematic(input).unwrap();
+        let parsed = parse_schematic_pest(input).unwrap();
+        assert_eq!(scanned.parts, parsed.parts);
+        assert_eq!(scanned.symbols, parsed.symbols);
+    }
+
+    #[test]
+    fn parts_and_gears_on_every_edge() {
+        let input = "2*3\n*.*\n4*5\n";
+        for schematic in [
+            parse_schematic(input).unwrap(),
+            parse_schematic_pest(input).unwrap(),
+        ] {
+            assert_eq!(schematic.sum_of_part_numbers(), 2 + 3 + 4 + 5);
+            // Top, left, right, and bottom.
+            assert_eq!(
+                schematic.sum_of_gear_ratios(),
+                2 * 3 + 2 * 4 + 3 * 5 + 4 * 5
+            );
+        }
+    }
+
+    #[test]
+    fn parts_along_the_edges() {
+        let schematic = parse_schematic("12.\n..#\n.34\n").unwrap();
+        assert_eq!(schematic.sum_of_part_numbers(), 12 + 34);
+        let schematic = parse_schematic("1..\n...\n..2\n").unwrap();
+        assert_eq!(schematic.sum_of_part_numbers(), 0);
+    }
+
+    #[test]
+    fn adjacency_queries() {
+        let input = include_str!("../../../src/inputs/day_03_test.txt");
+        let schematic = parse_schematic(input).unwrap();
+        let numbers = schematic
+            .parts_adjacent_to((1, 3))
+            .map(|part| part.number)
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, [467, 35]);
+
+        let first = schematic.part_at((0, 2)).unwrap();
+        assert_eq!(first.number, 467);
+        assert_eq!(
+            schematic.symbols_adjacent_to(first).collect::<Vec<_>>(),
+            [((1, 3), '*')]
+        );
+        let second = schematic.part_at((0, 5)).unwrap();
+        assert_eq!(schematic.symbols_adjacent_to(second).count(), 0);
+        assert_eq!(schematic.symbol_at((3, 6)), Some('#'));
+    }
+
+    #[test]
+    fn gears_can_use_any_symbol() {
+        let schematic = parse_schematic("3#4\n..*\n.5.\n").unwrap();
+        assert_eq!(schematic.gear_ratios('#').collect::<Vec<_>>(), [12]);
+        assert_eq!(schematic.gear_ratios('*').collect::<Vec<_>>(), [20]);
+        assert_eq!(schematic.gear_ratios('$').count(), 0);
+    }
+
+    #[test]
+    fn picture_colors_each_kind_of_cell() {
+        let schematic = parse_schematic("3*4\n...\n#.5\n").unwrap();
+        let svg = schematic.to_svg().to_string();
+        assert!(svg.contains(r#"width="30" height="30""#));
+        // The gear, 3 and 4 as part numbers, and 5 as a number that
+        // doesn't count.
+        assert!(svg.contains(r#"<rect x="10" y="0" width="10" height="10" fill="gold"/>"#));
+        assert!(svg.contains(r#"<rect x="0" y="0" width="10" height="10" fill="palegreen"/>"#));
+        assert!(svg.contains(r#"<rect x="20" y="20" width="10" height="10" fill="lightcoral"/>"#));
+        assert!(svg.contains(r#"<rect x="0" y="20" width="10" height="10" fill="lightgray"/>"#));
+    }
+
+    #[test]
+    fn too_large_numbers_are_pointed_out() {
+        let error = parse_schematic("..*...\n.99999999999\n").unwrap_err();
+        assert!(matches!(
+            error,
+            Day03Error::NumberTooLarge { location, .. } if location == SourceSpan::new(8.into(), 11)
+        ));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            parse_schematic_pest("").unwrap_err(),
+            parse_schematic("99999999999").unwrap_err(),
+        ];
+        check_codes::<_, Day03ErrorKind>(3, &examples);
+    }
+
+    #[cfg(feature = "arena")]
+    #[test]
+    fn arena_parse_matches() {
+        let input = include_str!("../../../src/inputs/day_03_test.txt");
+        let arena = bumpalo::Bump::new();
+        let schematic = parse_in(&arena, input).unwrap();
+        assert_eq!(schematic.parts, parse_schematic_pest(input).unwrap().parts);
+        assert_eq!(schematic.sum_of_gear_ratios(), 467_835);
+        assert!(parse_in(&arena, "").is_err());
+    }
+}
@@ -0,0 +1,369 @@
+use std::str::FromStr;
+
+use aoc_core::{
+    errors::{Coded, PestError},
+    solver::{Answer, Solver},
+};
+use fixedbitset::FixedBitSet;
+use miette::{Diagnostic, SourceSpan};
+use pest::error::ErrorVariant;
+use pest_consume::{match_nodes, Error, Nodes, Parser};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 4.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day04ErrorKind), derive(EnumIter))]
+pub enum Day04Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(Coded<PestError>),
+
+    #[error("Failed to parse the scratchcards")]
+    #[diagnostic(code(aoc::day04::nom_parse))]
+    NomParse {
+        #[source_code]
+        src: String,
+
+        #[label("Parsing failed here ({kind:?})")]
+        location: SourceSpan,
+
+        kind: nom::error::ErrorKind,
+    },
+}
+
+impl From<PestError> for Day04Error {
+    fn from(error: PestError) -> Self {
+        Self::Parse(Coded::new("aoc::day04::parse", error))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ScratchCard {
+    winning_numbers: FixedBitSet,
+    our_numbers: FixedBitSet,
+}
+
+impl ScratchCard {
+    fn num_winning_numbers(&self) -> usize {
+        self.winning_numbers.intersection(&self.our_numbers).count()
+    }
+
+    fn value(&self) -> usize {
+        let num_winning_numbers = self.num_winning_numbers();
+        if num_winning_numbers == 0 {
+            0
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            2usize.pow(num_winning_numbers as u32 - 1)
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ScratchCards {
+    cards: Vec<ScratchCard>,
+}
+
+impl FromStr for ScratchCards {
+    type Err = Day04Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        ScratchCardsParser::parse(Rule::input, s)
+            .and_then(Nodes::single)
+            .and_then(ScratchCardsParser::input)
+            .map(|cards| Self { cards })
+            .map_err(|error| PestError::new(s, &error).into())
+    }
+}
+
+impl IntoIterator for ScratchCards {
+    type Item = ScratchCard;
+    type IntoIter = std::vec::IntoIter<Self::Item>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cards.into_iter()
+    }
+}
+
+impl ScratchCards {
+    fn sum_of_values(&self) -> usize {
+        self.cards.par_iter().map(ScratchCard::value).sum()
+    }
+
+    /// How many copies we end up with of each card, counting the
+    /// original. Each copy of a card wins a copy of each of the next
+    /// few cards, so by the time we get to a card we know how many
+    /// copies of it there are. Wins past the end of the table are
+    /// ignored.
+    #[must_use]
+    pub fn copy_counts(&self) -> Vec<usize> {
+        // Each card's wins don't depend on the others, so they can be
+        // counted in parallel before the copies cascade.
+        let wins = self
+            .cards
+            .par_iter()
+            .map(ScratchCard::num_winning_numbers)
+            .collect::<Vec<_>>();
+        let mut counts = vec![1; self.cards.len()];
+        for (i, num_wins) in wins.into_iter().enumerate() {
+            let last_won = (i + num_wins).min(self.cards.len() - 1);
+            let copies = counts[i];
+            for count in &mut counts[i + 1..=last_won] {
+                *count += copies;
+            }
+        }
+        counts
+    }
+
+    /// The total number of cards, originals and copies, from
+    /// `copy_counts()`.
+    #[must_use]
+    pub fn num_winning_cards(&self) -> usize {
+        self.copy_counts().iter().sum()
+    }
+}
+
+/// The biggest number a card can have. The real cards only go up to 99,
+/// and this keeps each card's bitsets to a couple of kilobytes at most.
+const MAX_NUMBER: usize = 9_999;
+
+/// The error for a number on a card that's bigger than `MAX_NUMBER`.
+fn too_big(number: &str) -> String {
+    format!("{number} is bigger than the largest allowed number, {MAX_NUMBER}")
+}
+
+/// The `numbers` as a bitset, allocated once at the size the biggest
+/// one needs.
+fn number_set(numbers: impl Iterator<Item = usize>) -> FixedBitSet {
+    let numbers = numbers.collect::<Vec<_>>();
+    let size = numbers.iter().max().map_or(0, |&max| max + 1);
+    let mut set = FixedBitSet::with_capacity(size);
+    set.extend(numbers);
+    set
+}
+
+#[derive(Parser)]
+#[grammar = "grammars/day_04.pest"]
+struct ScratchCardsParser;
+
+type Result<T> = std::result::Result<T, Error<Rule>>;
+type Node<'i> = pest_consume::Node<'i, Rule, ()>;
+
+#[allow(clippy::unnecessary_wraps, clippy::result_large_err)]
+#[pest_consume::parser]
+impl ScratchCardsParser {
+    fn input(input: Node) -> Result<Vec<ScratchCard>> {
+        Ok(match_nodes! { input.into_children();
+            [scratchcard(c)..] => c.collect::<Vec<ScratchCard>>(),
+        })
+    }
+
+    fn scratchcard(input: Node) -> Result<ScratchCard> {
+        Ok(match_nodes! { input.into_children();
+            [number(_), numbers(winning_numbers), numbers(our_numbers)] => ScratchCard {
+                winning_numbers,
+                our_numbers,
+            },
+        })
+    }
+
+    fn numbers(input: Node) -> Result<FixedBitSet> {
+        let numbers = input
+            .into_children()
+            .map(|node| {
+                let (span, text) = (node.as_span(), node.as_str());
+                let number = Self::number(node)?;
+                if number > MAX_NUMBER {
+                    return Err(Error::new_from_span(
+                        ErrorVariant::CustomError {
+                            message: too_big(text),
+                        },
+                        span,
+                    ));
+                }
+                Ok(number)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(number_set(numbers.into_iter()))
+    }
+
+    fn number(input: Node) -> Result<usize> {
+        let span = input.as_span();
+        input.as_str().parse().map_err(|e| {
+            Error::new_from_span(
+                ErrorVariant::CustomError {
+                    message: format!("ParseIntError: {e}"),
+                },
+                span,
+            )
+        })
+    }
+}
+
+/// The same scratchcards as the `pest` grammar gets, parsed with `nom`
+/// combinators a line at a time.
+mod nom_parser {
+    use fixedbitset::FixedBitSet;
+    use nom::{
+        bytes::complete::tag,
+        character::complete::{digit1, multispace0, newline, space0, space1},
+        combinator::{all_consuming, map_res},
+        multi::separated_list1,
+        sequence::{delimited, preceded, separated_pair, terminated, tuple},
+        IResult,
+    };
+
+    use super::{number_set, too_big, ScratchCard, MAX_NUMBER};
+
+    fn number(input: &str) -> IResult<&str, usize> {
+        map_res(digit1, str::parse)(input)
+    }
+
+    /// A number on a card, which can't be bigger than `MAX_NUMBER`.
+    fn card_number(input: &str) -> IResult<&str, usize> {
+        map_res(number, |number| {
+            if number > MAX_NUMBER {
+                Err(too_big(&number.to_string()))
+            } else {
+                Ok(number)
+            }
+        })(input)
+    }
+
+    fn numbers(input: &str) -> IResult<&str, FixedBitSet> {
+        separated_list1(space1, card_number)(input)
+            .map(|(input, numbers)| (input, number_set(numbers.into_iter())))
+    }
+
+    fn scratch_card(input: &str) -> IResult<&str, ScratchCard> {
+        let header = tuple((tag("Card"), space1, number, tag(":"), space1));
+        let (input, (winning_numbers, our_numbers)) = preceded(
+            header,
+            separated_pair(numbers, delimited(space1, tag("|"), space1), numbers),
+        )(input)?;
+        Ok((
+            input,
+            ScratchCard {
+                winning_numbers,
+                our_numbers,
+            },
+        ))
+    }
+
+    pub fn scratch_cards(input: &str) -> IResult<&str, Vec<ScratchCard>> {
+        all_consuming(terminated(
+            separated_list1(newline, terminated(scratch_card, space0)),
+            multispace0,
+        ))(input)
+    }
+}
+
+/// Parse the scratchcards with `nom`, which gets the same cards as
+/// `ScratchCards::from_str()` so the two can be compared.
+///
+/// # Errors
+///
+/// Returns an error pointing at where `nom` gave up.
+pub fn parse_scratch_cards_nom(input: &str) -> std::result::Result<ScratchCards, Day04Error> {
+    nom_parser::scratch_cards(input)
+        .map(|(_, cards)| ScratchCards { cards })
+        .map_err(|error| {
+            let (offset, kind) = match error {
+                nom::Err::Error(error) | nom::Err::Failure(error) => {
+                    (input.len() - error.input.len(), error.code)
+                }
+                nom::Err::Incomplete(_) => (input.len(), nom::error::ErrorKind::Eof),
+            };
+            Day04Error::NomParse {
+                src: input.to_string(),
+                location: SourceSpan::new(offset.into(), 0),
+                kind,
+            }
+        })
+}
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    const DAY: u8 = 4;
+
+    type Parsed<'input> = ScratchCards;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(ScratchCards::from_str(input)?)
+    }
+
+    fn part_1(scratch_cards: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(scratch_cards.sum_of_values().into())
+    }
+
+    fn part_2(scratch_cards: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(scratch_cards.num_winning_cards().into())
+    }
+}
+
+#[cfg(test)]
+mod day_04_tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_04_test.txt");
+        let scratch_cards = ScratchCards::from_str(input).unwrap();
+        assert_eq!(scratch_cards.sum_of_values(), 13);
+        assert_eq!(scratch_cards.num_winning_cards(), 30);
+    }
+
+    #[test]
+    fn copies_cascade() {
+        let input = include_str!("../../../src/inputs/day_04_test.txt");
+        let scratch_cards = ScratchCards::from_str(input).unwrap();
+        assert_eq!(scratch_cards.copy_counts(), [1, 2, 4, 8, 14, 1]);
+        let scratch_cards = ScratchCards::from_str("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n").unwrap();
+        assert_eq!(scratch_cards.copy_counts(), [1, 2]);
+    }
+
+    #[test]
+    fn numbers_are_capped() {
+        for input in ["Card 1: 18446744073709551615 | 1\n", "Card 1: 1 | 10000\n"] {
+            assert!(matches!(
+                ScratchCards::from_str(input),
+                Err(Day04Error::Parse(_))
+            ));
+            assert!(matches!(
+                parse_scratch_cards_nom(input),
+                Err(Day04Error::NomParse { .. })
+            ));
+        }
+        let input = "Card 1: 9999 | 9999\n";
+        assert_eq!(ScratchCards::from_str(input).unwrap().sum_of_values(), 1);
+        assert_eq!(parse_scratch_cards_nom(input).unwrap().sum_of_values(), 1);
+    }
+
+    #[test]
+    fn numbers_past_255() {
+        let input = "Card 1: 100 256 999 | 999 256 7\nCard 2: 1000 | 1000\nCard 3: 300 | 301\n";
+        let scratch_cards = ScratchCards::from_str(input).unwrap();
+        assert_eq!(scratch_cards.sum_of_values(), 2 + 1);
+        assert_eq!(scratch_cards.num_winning_cards(), 4 + 2 + 1);
+    }
+
+    #[test]
+    fn nom_gets_the_same_cards() {
+        let input = include_str!("../../../src/inputs/day_04_test.txt");
+        let parsed = parse_scratch_cards_nom(input).unwrap();
+        assert_eq!(parsed.cards, ScratchCards::from_str(input).unwrap().cards);
+        assert!(parse_scratch_cards_nom("Card 1: 99999999999999999999999 | 1").is_err());
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            ScratchCards::from_str("Card 1: 1 2").unwrap_err(),
+            parse_scratch_cards_nom("Card 1: 1 2").unwrap_err(),
+        ];
+        check_codes::<_, Day04ErrorKind>(4, &examples);
+    }
+}
@@ -0,0 +1,995 @@
+use std::{fmt::Display, ops::Range, str::FromStr, sync::Arc};
+
+use aoc_core::{
+    debug_dump::dump,
+    errors::{Coded, PestError},
+    progress,
+    ranges::{RangeMap, RangeSet},
+    solver::{Answer, Solver},
+};
+use indicatif::ParallelProgressIterator;
+use miette::{Diagnostic, LabeledSpan};
+use pest::error::ErrorVariant;
+use pest_consume::{match_nodes, Error, Nodes, Parser};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 5.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day05ErrorKind), derive(EnumIter))]
+pub enum Day05Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(Coded<PestError>),
+
+    #[error("No location found")]
+    #[diagnostic(
+        code(aoc::day05::no_location),
+        help("Part 2 needs the seeds to come in pairs of a start and a length")
+    )]
+    NoLocation,
+
+    #[error("Failed to parse the almanac")]
+    #[diagnostic(code(aoc::day05::chumsky_parse))]
+    ChumskyParse {
+        #[source_code]
+        src: String,
+
+        #[label(collection)]
+        problems: Vec<LabeledSpan>,
+    },
+
+    #[error("The seed range starting at {start} with length {length} goes past the largest possible seed")]
+    #[diagnostic(
+        code(aoc::day05::seed_range_too_big),
+        help("Part 2 reads the seeds as pairs of a start and a length, and every range has to fit in a u64")
+    )]
+    SeedRangeTooBig { start: u64, length: u64 },
+}
+
+impl From<PestError> for Day05Error {
+    fn from(error: PestError) -> Self {
+        Self::Parse(Coded::new("aoc::day05::parse", error))
+    }
+}
+
+/// The name of one kind of value in the almanac, like `seed` or `soil`.
+/// These come from the maps' titles, so an almanac can call them
+/// whatever it likes, as long as each map picks up where the one before
+/// it left off. The maps on either side of a name share it, so each name
+/// is only stored once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Category(Arc<str>);
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Category {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Almanac {
+    /// Part 1 treats these as individual seeds, while part 2 treats
+    /// each pair of values as the start and length of a range of seeds.
+    seeds: Vec<u64>,
+    /// All the maps from one type of value to another, e.g., from
+    /// `seed` to `soil`, composed into one. For this to work, the maps
+    /// have to be in the right order, so the `target` of one map is the
+    /// `source` of the next; the parser checks that they are.
+    combined_mapping: Option<Mapping>,
+}
+
+impl Display for Almanac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("seeds:")?;
+        for seed in &self.seeds {
+            f.write_str(" ")?;
+            seed.fmt(f)?;
+        }
+        f.write_str("\n\n")?;
+
+        if let Some(mapping) = &self.combined_mapping {
+            mapping.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Almanac {
+    fn new(seeds: Vec<u64>, maps: impl IntoIterator<Item = Mapping>) -> Self {
+        let combined_mapping = maps.into_iter().reduce(Mapping::compose);
+        if let Some(mapping) = &combined_mapping {
+            dump("day_05/combined_mapping.txt", || format!("{mapping}\n"));
+        }
+        Self {
+            seeds,
+            combined_mapping,
+        }
+    }
+
+    /// `self`, as long as each pair of seeds is a range that ends by
+    /// `u64::MAX`, which `seed_ranges()` counts on.
+    fn check_seed_ranges(self) -> std::result::Result<Self, Day05Error> {
+        let too_big = self
+            .seeds
+            .chunks_exact(2)
+            .find(|pair| pair[0].checked_add(pair[1]).is_none());
+        if let Some(&[start, length]) = too_big {
+            return Err(Day05Error::SeedRangeTooBig { start, length });
+        }
+        Ok(self)
+    }
+
+    /// All the maps composed into one, from the first map's source all
+    /// the way to the last one's target, or `None` if the almanac doesn't
+    /// have any maps.
+    #[must_use]
+    pub const fn combined_mapping(&self) -> Option<&Mapping> {
+        self.combined_mapping.as_ref()
+    }
+
+    /// The single mapping from seeds all the way to locations, or `None`
+    /// if the almanac doesn't have any maps.
+    #[must_use]
+    pub fn seed_to_location(&self) -> Option<&RangeMap> {
+        self.combined_mapping
+            .as_ref()
+            .map(|mapping| &mapping.ranges)
+    }
+
+    /// The seeds that end up somewhere in `locations`, which is how to
+    /// work back from a location to the seeds that could have led
+    /// there.
+    #[must_use]
+    pub fn seeds_for_locations(&self, locations: Range<u64>) -> RangeSet {
+        let locations = std::iter::once(locations).collect::<RangeSet>();
+        match &self.combined_mapping {
+            Some(mapping) => mapping.ranges.preimage(&locations),
+            None => locations,
+        }
+    }
+
+    fn lowest_seed_location(&self) -> Option<u64> {
+        let mapping = self.combined_mapping.as_ref()?;
+        self.seeds.iter().map(|&seed| mapping.convert(seed)).min()
+    }
+
+    /// The seed ranges for part 2. The parsers have already checked that
+    /// none of them go past `u64::MAX`.
+    fn seed_ranges(&self) -> impl Iterator<Item = Range<u64>> + '_ {
+        self.seeds
+            .chunks_exact(2)
+            .map(|pair| pair[0]..(pair[0] + pair[1]))
+    }
+
+    /// Push all the seed ranges through the combined mapping at once, which
+    /// gives us every location reachable from any of the seeds as a set of
+    /// ranges, so the lowest location is just the start of the first one.
+    fn lowest_seed_range_location(&self) -> Option<u64> {
+        let mapping = self.combined_mapping.as_ref()?;
+        let seeds = self.seed_ranges().collect::<RangeSet>();
+        mapping.ranges.apply(&seeds).min()
+    }
+
+    /// Part 2 the slow way, converting every seed in every range one at
+    /// a time like part 1 does, to check the range math against. The
+    /// seeds are handed out in chunks so the threads (and the progress
+    /// bar) aren't fighting over every single seed.
+    #[must_use]
+    pub fn brute_force_lowest_seed_range_location(&self) -> Option<u64> {
+        const CHUNK_SIZE: usize = 1 << 20;
+
+        let mapping = self.combined_mapping.as_ref()?;
+        let chunks: Vec<Range<u64>> = self
+            .seed_ranges()
+            .flat_map(|seeds| {
+                let end = seeds.end;
+                seeds
+                    .step_by(CHUNK_SIZE)
+                    .map(move |start| start..start.saturating_add(CHUNK_SIZE as u64).min(end))
+            })
+            .collect();
+        chunks
+            .par_iter()
+            .progress_with(progress::bar(chunks.len()))
+            .filter_map(|chunk| chunk.clone().map(|seed| mapping.convert(seed)).min())
+            .min()
+    }
+}
+
+/// A map from one type of value to another, like one of the maps in the
+/// almanac, or several of them composed together.
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Mapping {
+    source: Category,
+    target: Category,
+    ranges: RangeMap,
+}
+
+impl Display for Mapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.source.fmt(f)?;
+        f.write_str("-to-")?;
+        self.target.fmt(f)?;
+        f.write_str(" map:\n")?;
+
+        for (range, offset) in self.ranges.entries() {
+            let dest_start = i128::from(range.start) + i128::from(*offset);
+            dest_start.fmt(f)?;
+            f.write_str(" ")?;
+            range.start.fmt(f)?;
+            f.write_str(" ")?;
+            let range_len = range.end - range.start;
+            range_len.fmt(f)?;
+            f.write_str("\n")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Mapping {
+    /// Make sure none of `ranges` overlap, since then a value could map
+    /// to two places. If any do, the error says which two, so whichever
+    /// parser is building the map can say where they are.
+    fn new(
+        source: Category,
+        target: Category,
+        ranges: &[(Range<u64>, i64)],
+    ) -> std::result::Result<Self, Overlap> {
+        let mut order = (0..ranges.len()).collect::<Vec<_>>();
+        // A stable sort, so duplicates are reported in the order they're in.
+        order.sort_by_key(|&index| ranges[index].0.start);
+        if let Some(pair) = order
+            .windows(2)
+            .find(|pair| ranges[pair[0]].0.end > ranges[pair[1]].0.start)
+        {
+            return Err(Overlap {
+                first: pair[0],
+                second: pair[1],
+            });
+        }
+        Ok(Self {
+            source,
+            target,
+            ranges: ranges.iter().cloned().collect(),
+        })
+    }
+
+    /// Make sure this map picks up where `previous` left off, so they can
+    /// be composed, and share `previous`'s name for the category they
+    /// have in common. The error is the message to point at this map's
+    /// title with if it doesn't.
+    fn follow(mut self, previous: Option<&Self>) -> std::result::Result<Self, String> {
+        match previous {
+            Some(previous) if previous.target != self.source => Err(format!(
+                "This map is from {}, but the one before it was to {}",
+                self.source, previous.target
+            )),
+            Some(previous) => {
+                self.source = previous.target.clone();
+                Ok(self)
+            }
+            None => Ok(self),
+        }
+    }
+
+    /// Where `value` ends up; values that none of the ranges cover stay
+    /// where they are.
+    #[must_use]
+    pub fn convert(&self, value: u64) -> u64 {
+        self.ranges.get(value)
+    }
+
+    /// Where all the values in `values` end up, as ranges sorted by where
+    /// they start. A range can be split into several pieces by the map, and
+    /// pieces that land next to each other are merged.
+    #[must_use]
+    pub fn convert_range(&self, values: Range<u64>) -> Vec<Range<u64>> {
+        let values = std::iter::once(values).collect::<RangeSet>();
+        self.ranges.apply(&values).ranges().to_vec()
+    }
+
+    // Compose two mappings, returning a new mapping that maps from the source
+    // space of `self` to the target space of `other`.
+    #[allow(clippy::needless_pass_by_value)]
+    fn compose(self, other: Self) -> Self {
+        Self {
+            source: self.source,
+            target: other.target,
+            ranges: self.ranges.compose(&other.ranges),
+        }
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = Day05Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        AlmanacParser::parse(Rule::input, s)
+            .and_then(Nodes::single)
+            .and_then(AlmanacParser::input)
+            .map_err(|error| Day05Error::from(PestError::new(s, &error)))?
+            .check_seed_ranges()
+    }
+}
+
+/// Two lines of a map whose source ranges overlap, as their positions
+/// among the map's lines; `first` is the one whose range starts first.
+struct Overlap {
+    first: usize,
+    second: usize,
+}
+
+impl Overlap {
+    /// What to report about the overlap, where `line_numbers` has the line
+    /// of the input that each of the map's `ranges` came from.
+    fn message(&self, ranges: &[(Range<u64>, i64)], line_numbers: &[usize]) -> String {
+        format!(
+            "The source range {:?} on line {} overlaps {:?} on line {}",
+            ranges[self.second].0,
+            line_numbers[self.second],
+            ranges[self.first].0,
+            line_numbers[self.first]
+        )
+    }
+}
+
+/// Build a map out of `ranges`, which came from the lines at
+/// `line_spans`. If two of them overlap, the error points at the one
+/// that starts later, and says which lines they're both on.
+#[allow(clippy::result_large_err)]
+fn pest_mapping(
+    source: Category,
+    target: Category,
+    ranges: &[(Range<u64>, i64)],
+    line_spans: &[pest::Span],
+) -> Result<Mapping> {
+    Mapping::new(source, target, ranges).map_err(|overlap| {
+        let line_numbers = line_spans
+            .iter()
+            .map(|span| span.start_pos().line_col().0)
+            .collect::<Vec<_>>();
+        custom_error(
+            line_spans[overlap.second],
+            overlap.message(ranges, &line_numbers),
+        )
+    })
+}
+
+/// What parsers report when a line of a map goes past `u64::MAX`.
+const RANGE_TOO_BIG: &str = "This range goes past the largest possible value";
+
+/// The source range and offset for a line of a map, or `None` if either
+/// end of it goes past `u64::MAX` or the offset doesn't fit in an `i64`.
+fn range_mapping(dest_start: u64, source_start: u64, length: u64) -> Option<(Range<u64>, i64)> {
+    let source_end = source_start.checked_add(length)?;
+    dest_start.checked_add(length)?;
+    let offset = i64::try_from(i128::from(dest_start) - i128::from(source_start)).ok()?;
+    Some((source_start..source_end, offset))
+}
+
+/// A `pest` error pointing at `span`, for the problems the grammar can't
+/// catch by itself.
+fn custom_error(span: pest::Span, message: String) -> Error<Rule> {
+    Error::new_from_span(ErrorVariant::CustomError { message }, span)
+}
+
+#[derive(Parser)]
+#[grammar = "grammars/day_05.pest"]
+struct AlmanacParser;
+
+type Result<T> = std::result::Result<T, Error<Rule>>;
+type Node<'i> = pest_consume::Node<'i, Rule, ()>;
+
+#[allow(clippy::unnecessary_wraps, clippy::result_large_err)]
+#[pest_consume::parser]
+impl AlmanacParser {
+    fn input(input: Node) -> Result<Almanac> {
+        let mut seeds = Vec::new();
+        let mut maps: Vec<Mapping> = Vec::new();
+        for child in input.into_children() {
+            if child.as_rule() == Rule::seeds {
+                seeds = Self::seeds(child)?;
+            } else {
+                let title_span = title_span(&child);
+                let map = Self::map(child)?
+                    .follow(maps.last())
+                    .map_err(|message| custom_error(title_span, message))?;
+                maps.push(map);
+            }
+        }
+        Ok(Almanac::new(seeds, maps))
+    }
+
+    fn seeds(input: Node) -> Result<Vec<u64>> {
+        Ok(match_nodes! { input.into_children();
+            [number(seed)..] => seed.collect(),
+        })
+    }
+
+    fn map(input: Node) -> Result<Mapping> {
+        let line_spans = input
+            .children()
+            .skip(1)
+            .map(|line| line.as_span())
+            .collect::<Vec<_>>();
+        let (source, target, ranges) = match_nodes! { input.into_children();
+            [map_title((source, target)), range_mapping(r)..] => (source, target, r.collect::<Vec<_>>()),
+        };
+        pest_mapping(source, target, &ranges, &line_spans)
+    }
+
+    fn map_title(input: Node) -> Result<(Category, Category)> {
+        Ok(match_nodes! { input.into_children();
+            [category(source), category(target)] => (source, target),
+        })
+    }
+
+    fn range_mapping(input: Node) -> Result<(Range<u64>, i64)> {
+        let span = input.as_span();
+        match_nodes! { input.into_children();
+            [number(dest_start), number(source_start), number(length)] => {
+                range_mapping(dest_start, source_start, length)
+                    .ok_or_else(|| custom_error(span, RANGE_TOO_BIG.to_string()))
+            },
+        }
+    }
+
+    fn category(input: Node) -> Result<Category> {
+        Ok(Category(input.as_str().into()))
+    }
+
+    fn number(input: Node) -> Result<u64> {
+        input.as_str().parse().map_err(|e| input.error(e))
+    }
+}
+
+/// Where the title of the map in `node` is, or the whole map if it
+/// somehow doesn't have one.
+fn title_span<'i>(node: &Node<'i>) -> pest::Span<'i> {
+    node.children()
+        .next()
+        .map_or_else(|| node.as_span(), |title| title.as_span())
+}
+
+/// Parse the almanac like `Day05::parse()`, but gather its parts in
+/// `arena`.
+///
+/// The seeds, each map's ranges, and the maps themselves go in the
+/// arena rather than in `Vec`s that grow as they go and are thrown away
+/// once the maps have been composed.
+///
+/// # Errors
+///
+/// Returns the same errors as `Day05::parse()`.
+#[cfg(feature = "arena")]
+pub fn parse_in(arena: &bumpalo::Bump, input: &str) -> miette::Result<Almanac> {
+    let almanac = almanac_in(arena, input)
+        .map_err(|error| Day05Error::from(PestError::new(input, &error)))?;
+    Ok(almanac.check_seed_ranges()?)
+}
+
+#[cfg(feature = "arena")]
+#[allow(clippy::result_large_err)]
+fn almanac_in(arena: &bumpalo::Bump, input: &str) -> Result<Almanac> {
+    let node = AlmanacParser::parse(Rule::input, input)?.single()?;
+    let mut seeds = bumpalo::collections::Vec::new_in(arena);
+    let mut maps = bumpalo::collections::Vec::new_in(arena);
+    for child in node.into_children() {
+        if child.as_rule() == Rule::seeds {
+            for seed in child.into_children() {
+                seeds.push(AlmanacParser::number(seed)?);
+            }
+        } else {
+            let title_span = title_span(&child);
+            let map = map_in(arena, child)?
+                .follow(maps.last())
+                .map_err(|message| custom_error(title_span, message))?;
+            maps.push(map);
+        }
+    }
+    Ok(Almanac::new(seeds.to_vec(), maps))
+}
+
+#[cfg(feature = "arena")]
+#[allow(clippy::result_large_err)]
+fn map_in(arena: &bumpalo::Bump, node: Node) -> Result<Mapping> {
+    let span = node.as_span();
+    let mut title = None;
+    let mut ranges = bumpalo::collections::Vec::new_in(arena);
+    let mut line_spans = bumpalo::collections::Vec::new_in(arena);
+    for child in node.into_children() {
+        if child.as_rule() == Rule::map_title {
+            title = Some(AlmanacParser::map_title(child)?);
+        } else {
+            line_spans.push(child.as_span());
+            ranges.push(AlmanacParser::range_mapping(child)?);
+        }
+    }
+    let (source, target) =
+        title.ok_or_else(|| custom_error(span, "A map needs a title".to_string()))?;
+    pest_mapping(source, target, &ranges, &line_spans)
+}
+
+/// The same almanac again, parsed with `chumsky`.
+mod chumsky_parser {
+    use chumsky::prelude::*;
+    use miette::LabeledSpan;
+
+    use super::{range_mapping, Almanac, Category, Mapping, RangeMap, RANGE_TOO_BIG};
+
+    /// Use `Rich` errors so parse failures know where in the input they happened.
+    type Extra<'a> = extra::Err<Rich<'a, char>>;
+
+    /// Which line of `input` `offset` is on, counting from 1 like `pest`
+    /// does.
+    fn line_number(input: &str, offset: usize) -> usize {
+        input[..offset].matches('\n').count() + 1
+    }
+
+    /// `input` is only used to work out line numbers for the errors.
+    fn almanac(input: &str) -> impl Parser<'_, &str, Almanac, Extra<'_>> {
+        let number = text::int(10)
+            .try_map(|digits: &str, span| {
+                digits
+                    .parse::<u64>()
+                    .map_err(|error| Rich::custom(span, error))
+            })
+            .padded();
+        let seeds = just("seeds:").ignore_then(number.repeated().at_least(1).collect::<Vec<_>>());
+        let category = any()
+            .filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_')
+            .repeated()
+            .at_least(1)
+            .to_slice()
+            .map(|name: &str| Category(name.into()));
+        let title = category
+            .then_ignore(just("-to-"))
+            .then(category)
+            .map_with(|title, extra| (title, extra.span()));
+        // The checks that aren't about syntax use `validate()` rather than
+        // `try_map()`, so they're reported where the problem is, rather
+        // than losing out to wherever chumsky got furthest.
+        let line = number.then(number).then(number).validate(
+            |((dest_start, source_start), length), extra, emitter| {
+                let range = range_mapping(dest_start, source_start, length).unwrap_or_else(|| {
+                    emitter.emit(Rich::custom(extra.span(), RANGE_TOO_BIG));
+                    (0..0, 0)
+                });
+                (range, extra.span())
+            },
+        );
+        let map = title
+            .then_ignore(just("map:").padded())
+            .then(line.repeated().at_least(1).collect::<Vec<_>>())
+            .validate(move |(((source, target), title_span), lines), _, emitter| {
+                let (ranges, line_spans): (Vec<_>, Vec<SimpleSpan>) = lines.into_iter().unzip();
+                let map = Mapping::new(source.clone(), target.clone(), &ranges).unwrap_or_else(
+                    |overlap| {
+                        let line_numbers = line_spans
+                            .iter()
+                            .map(|span| line_number(input, span.start))
+                            .collect::<Vec<_>>();
+                        emitter.emit(Rich::custom(
+                            line_spans[overlap.second],
+                            overlap.message(&ranges, &line_numbers),
+                        ));
+                        Mapping {
+                            source,
+                            target,
+                            ranges: RangeMap::default(),
+                        }
+                    },
+                );
+                (map, title_span)
+            });
+        seeds
+            .padded()
+            .then(map.padded().repeated().at_least(1).collect::<Vec<_>>())
+            .validate(|(seeds, titled_maps), _, emitter| {
+                let mut maps: Vec<Mapping> = Vec::with_capacity(titled_maps.len());
+                for (map, title_span) in titled_maps {
+                    match map.follow(maps.last()) {
+                        Ok(map) => maps.push(map),
+                        Err(message) => {
+                            emitter.emit(Rich::custom(title_span, message));
+                            break;
+                        }
+                    }
+                }
+                Almanac::new(seeds, maps)
+            })
+    }
+
+    /// The almanac in `input`, or where and why each problem chumsky found
+    /// went wrong.
+    pub fn parse(input: &str) -> Result<Almanac, Vec<LabeledSpan>> {
+        almanac(input).parse(input).into_result().map_err(|errors| {
+            errors
+                .into_iter()
+                .map(|error| {
+                    let span = error.span();
+                    LabeledSpan::new(Some(error.to_string()), span.start, span.end - span.start)
+                })
+                .collect()
+        })
+    }
+}
+
+/// Parse the almanac with `chumsky` instead of the `pest` grammar. It
+/// checks the same things `Day05::parse()` does, and gets the same
+/// `Almanac`.
+///
+/// # Errors
+///
+/// Returns an error labelling every problem `chumsky` found.
+pub fn parse_almanac_chumsky(input: &str) -> std::result::Result<Almanac, Day05Error> {
+    chumsky_parser::parse(input)
+        .map_err(|problems| Day05Error::ChumskyParse {
+            src: input.to_string(),
+            problems,
+        })?
+        .check_seed_ranges()
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    const DAY: u8 = 5;
+
+    type Parsed<'input> = Almanac;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Almanac::from_str(input)?)
+    }
+
+    fn part_1(almanac: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        almanac
+            .lowest_seed_location()
+            .map(Answer::from)
+            .ok_or_else(|| Day05Error::NoLocation.into())
+    }
+
+    fn part_2(almanac: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        almanac
+            .lowest_seed_range_location()
+            .map(Answer::from)
+            .ok_or_else(|| Day05Error::NoLocation.into())
+    }
+}
+
+#[cfg(test)]
+mod day_05_tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let almanac = Almanac::from_str(input).unwrap();
+        assert_eq!(almanac.lowest_seed_location().unwrap(), 35);
+        assert_eq!(almanac.lowest_seed_range_location().unwrap(), 46);
+    }
+
+    #[test]
+    #[expect(
+        clippy::single_range_in_vec_init,
+        reason = "This is a set of ranges, which here only has one range"
+    )]
+    fn lowest_location_leads_back_to_a_seed() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let almanac = Almanac::from_str(input).unwrap();
+        let lowest = almanac.lowest_seed_range_location().unwrap();
+        let seeds = almanac.seed_ranges().collect::<RangeSet>();
+        let found = almanac.seeds_for_locations(lowest..lowest + 1);
+        assert_eq!(found.intersect(&seeds).ranges(), [82..83]);
+        // Nothing lower comes from any of the seeds.
+        assert!(almanac
+            .seeds_for_locations(0..lowest)
+            .intersect(&seeds)
+            .is_empty());
+    }
+
+    #[cfg(feature = "arena")]
+    #[test]
+    fn arena_parse_matches() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let arena = bumpalo::Bump::new();
+        let almanac = parse_in(&arena, input).unwrap();
+        assert_eq!(
+            almanac.to_string(),
+            Almanac::from_str(input).unwrap().to_string()
+        );
+        let overlapping = "seeds: 1\n\nseed-to-soil map:\n50 10 5\n60 12 5\n";
+        assert!(parse_in(&arena, overlapping).is_err());
+    }
+
+    #[test]
+    fn display_test_almanac() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let almanac = Almanac::from_str(input).unwrap();
+        insta::assert_snapshot!(almanac);
+    }
+
+    #[test]
+    fn rejects_numbers_and_ranges_that_overflow() {
+        let too_big = "seeds: 1\n\nseed-to-soil map:\n1 2 99999999999999999999\n";
+        assert!(Almanac::from_str(too_big).is_err());
+        let past_the_end = "seeds: 1\n\nseed-to-soil map:\n1 2 18446744073709551615\n";
+        assert!(Almanac::from_str(past_the_end).is_err());
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Almanac::from_str("").unwrap_err(),
+            Day05Error::NoLocation,
+            parse_almanac_chumsky("").unwrap_err(),
+            Almanac::from_str(SEED_RANGE_TOO_BIG).unwrap_err(),
+        ];
+        check_codes::<_, Day05ErrorKind>(5, &examples);
+    }
+
+    const SEED_RANGE_TOO_BIG: &str =
+        "seeds: 18446744073709551615 5\n\nseed-to-soil map:\n50 98 2\n";
+
+    #[test]
+    fn rejects_seed_ranges_past_the_largest_seed() {
+        for error in [
+            Almanac::from_str(SEED_RANGE_TOO_BIG).unwrap_err(),
+            parse_almanac_chumsky(SEED_RANGE_TOO_BIG).unwrap_err(),
+        ] {
+            assert!(matches!(
+                error,
+                Day05Error::SeedRangeTooBig {
+                    start: u64::MAX,
+                    length: 5
+                }
+            ));
+        }
+        assert!(
+            Almanac::from_str("seeds: 18446744073709551610 5\n\nseed-to-soil map:\n50 98 2\n")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn rejects_overlapping_ranges() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n50 10 5\n60 12 5\n";
+        let error = Almanac::from_str(input).unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), input.find("60").unwrap());
+        assert!(error
+            .to_string()
+            .contains("The source range 12..17 on line 5 overlaps 10..15 on line 4"));
+    }
+
+    #[test]
+    fn rejects_duplicate_ranges() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n50 10 5\n1 2 3\n60 10 5\n";
+        let error = parse_almanac_chumsky(input).unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), input.find("60").unwrap());
+        assert!(labels[0]
+            .label()
+            .unwrap()
+            .contains("The source range 10..15 on line 6 overlaps 10..15 on line 4"));
+    }
+
+    #[test]
+    fn maps_have_to_chain() {
+        let input = "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n1 2 3\n";
+        let error = Almanac::from_str(input).unwrap_err();
+        let labels = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .collect::<Vec<_>>();
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), input.find("water").unwrap());
+        assert!(error.to_string().contains("from water"));
+    }
+
+    #[test]
+    fn chumsky_parses_the_same_almanac() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        assert_eq!(
+            parse_almanac_chumsky(input).unwrap(),
+            Almanac::from_str(input).unwrap()
+        );
+    }
+
+    #[test]
+    fn chumsky_points_at_the_same_problems() {
+        for input in [
+            "seeds: 1\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n1 2 3\n",
+            "seeds: 1\n\nseed-to-soil map:\n50 10 5\n60 12 5\n",
+            "seeds: 1\n\nseed-to-soil map:\n1 18446744073709551615 3\n",
+        ] {
+            let pest_labels = miette::Diagnostic::labels(&Almanac::from_str(input).unwrap_err())
+                .unwrap()
+                .map(|label| label.offset())
+                .collect::<Vec<_>>();
+            let chumsky_labels =
+                miette::Diagnostic::labels(&parse_almanac_chumsky(input).unwrap_err())
+                    .unwrap()
+                    .map(|label| label.offset())
+                    .collect::<Vec<_>>();
+            assert_eq!(chumsky_labels, pest_labels, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn categories_can_have_any_name() {
+        let input = "seeds: 1 2\n\nseed-to-dirt map:\n5 1 1\n\ndirt-to-plot_2 map:\n9 5 1\n";
+        let almanac = Almanac::from_str(input).unwrap();
+        assert_eq!(almanac.lowest_seed_location(), Some(2));
+        assert_eq!(
+            almanac.to_string(),
+            "seeds: 1 2\n\nseed-to-plot_2 map:\n9 1 1\n9 5 1\n"
+        );
+    }
+
+    #[test]
+    fn combined_mapping_converts_values_and_ranges() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let almanac = Almanac::from_str(input).unwrap();
+        let mapping = almanac.combined_mapping().unwrap();
+        assert_eq!(
+            [79, 14, 55, 13].map(|seed| mapping.convert(seed)),
+            [82, 43, 86, 35]
+        );
+        assert_eq!(mapping.convert_range(82..83), vec![46..47]);
+        assert_eq!(mapping.convert_range(79..93), vec![46..56, 60..61, 82..85]);
+    }
+
+    #[test]
+    fn brute_force_agrees_with_the_ranges() {
+        let input = include_str!("../../../src/inputs/day_05_test.txt");
+        let almanac = Almanac::from_str(input).unwrap();
+        assert_eq!(almanac.brute_force_lowest_seed_range_location(), Some(46));
+    }
+
+    #[test]
+    fn single_mapping_round_trips() {
+        let input = "seeds: 79 14 55 13\n\nseed-to-soil map:\n52 50 48\n50 98 2\n";
+        let almanac = Almanac::from_str(input).unwrap();
+        assert_eq!(almanac.to_string(), input);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+    use std::fmt::Write;
+
+    const MAPPING_TYPES: [&str; 8] = [
+        "seed",
+        "soil",
+        "fertilizer",
+        "water",
+        "light",
+        "temperature",
+        "humidity",
+        "location",
+    ];
+
+    /// `(dest_start, source_start, length)` triples, like a line of a map
+    /// in the input.
+    type RangeMapping = (u64, u64, u64);
+
+    /// Range mappings whose source ranges don't overlap, laid out left
+    /// to right with random gaps.
+    fn range_mappings() -> impl Strategy<Value = Vec<RangeMapping>> {
+        prop::collection::vec((0..50u64, 1..50u64, 0..500u64), 1..5).prop_map(|ranges| {
+            let mut source_start = 0;
+            ranges
+                .into_iter()
+                .map(|(gap, length, dest_start)| {
+                    source_start += gap;
+                    let range = (dest_start, source_start, length);
+                    source_start += length;
+                    range
+                })
+                .collect()
+        })
+    }
+
+    /// Seeds as `(start, length)` pairs, which part 1 reads as individual
+    /// seeds and part 2 reads as ranges, along with a mapping for each step
+    /// from seeds to locations.
+    fn almanac() -> impl Strategy<Value = (Vec<u64>, Vec<Vec<RangeMapping>>)> {
+        (
+            prop::collection::vec((0..400u64, 1..20u64), 1..5),
+            prop::collection::vec(range_mappings(), MAPPING_TYPES.len() - 1),
+        )
+            .prop_map(|(seeds, maps)| {
+                let seeds = seeds.into_iter().flat_map(<[u64; 2]>::from);
+                (seeds.collect(), maps)
+            })
+    }
+
+    fn to_input(seeds: &[u64], maps: &[Vec<RangeMapping>]) -> String {
+        let mut input = format!("seeds: {}\n", seeds.iter().join(" "));
+        for (types, ranges) in MAPPING_TYPES.windows(2).zip(maps) {
+            writeln!(input, "\n{}-to-{} map:", types[0], types[1]).unwrap();
+            for (dest_start, source_start, length) in ranges {
+                writeln!(input, "{dest_start} {source_start} {length}").unwrap();
+            }
+        }
+        input
+    }
+
+    /// Push `value` through each map in turn, the slow way.
+    fn map_sequentially(maps: &[Vec<RangeMapping>], value: u64) -> u64 {
+        maps.iter().fold(value, |value, ranges| {
+            ranges
+                .iter()
+                .find(|&&(_, source_start, length)| {
+                    (source_start..source_start + length).contains(&value)
+                })
+                .map_or(value, |&(dest_start, source_start, _)| {
+                    dest_start + (value - source_start)
+                })
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn composed_mapping_matches_sequential_mapping((seeds, maps) in almanac()) {
+            let almanac = Almanac::from_str(&to_input(&seeds, &maps)).unwrap();
+            let mapping = almanac.combined_mapping.as_ref().unwrap();
+            for value in 0..700 {
+                prop_assert_eq!(mapping.ranges.get(value), map_sequentially(&maps, value));
+            }
+        }
+
+        #[test]
+        fn lowest_locations_match_brute_force((seeds, maps) in almanac()) {
+            let almanac = Almanac::from_str(&to_input(&seeds, &maps)).unwrap();
+            let lowest = seeds.iter().map(|&seed| map_sequentially(&maps, seed)).min();
+            prop_assert_eq!(almanac.lowest_seed_location(), lowest);
+            let lowest_in_ranges = seeds
+                .chunks_exact(2)
+                .flat_map(|pair| pair[0]..pair[0] + pair[1])
+                .map(|seed| map_sequentially(&maps, seed))
+                .min();
+            prop_assert_eq!(almanac.lowest_seed_range_location(), lowest_in_ranges);
+        }
+
+        #[test]
+        fn almanacs_round_trip((seeds, maps) in almanac()) {
+            let almanac = Almanac::from_str(&to_input(&seeds, &maps)).unwrap();
+            let reparsed = Almanac::from_str(&almanac.to_string()).unwrap();
+            prop_assert_eq!(reparsed, almanac);
+        }
+
+        /// A single map isn't composed with anything, so it should come
+        /// back out exactly as it went in, down to each range's length.
+        #[test]
+        fn single_maps_display_as_their_input((seeds, maps) in almanac()) {
+            let input = to_input(&seeds, &maps[..1]);
+            prop_assert_eq!(Almanac::from_str(&input).unwrap().to_string(), input);
+        }
+    }
+}
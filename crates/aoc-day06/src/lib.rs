@@ -0,0 +1,202 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use aoc_core::{
+    parsing::parse_numbers,
+    solver::{Answer, Solver},
+};
+use miette::Diagnostic;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 6.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day06ErrorKind), derive(EnumIter))]
+pub enum Day06Error {
+    #[error("Missing the line starting with {0:?}")]
+    #[diagnostic(code(aoc::day06::missing_line))]
+    MissingLine(&'static str),
+    #[error("Expected a line starting with {0:?} but found {1:?}")]
+    #[diagnostic(code(aoc::day06::missing_label))]
+    MissingLabel(&'static str, String),
+    #[error("Illegal integer in the races table")]
+    #[diagnostic(code(aoc::day06::parse_int))]
+    ParseInt(#[from] ParseIntError),
+    #[error("There were {num_times} times but {num_distances} distances")]
+    #[diagnostic(code(aoc::day06::mismatched_counts))]
+    MismatchedCounts {
+        num_times: usize,
+        num_distances: usize,
+    },
+}
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    record_distance: u64,
+}
+
+impl Race {
+    /// Holding the button for `hold_time` milliseconds leaves
+    /// `time - hold_time` milliseconds to travel at `hold_time`
+    /// millimeters per millisecond.
+    const fn distance(&self, hold_time: u64) -> u64 {
+        hold_time * (self.time - hold_time)
+    }
+
+    /// We win when `h * (T - h) > D`, i.e., when `h^2 - T*h + D < 0`, so the
+    /// winning hold times are the integers strictly between the roots
+    /// `(T ± sqrt(T^2 - 4D)) / 2`. The distance is symmetric around `T / 2`,
+    /// so once we have the shortest winning hold time the longest one is
+    /// just `T` minus that.
+    const fn num_ways_to_win(&self) -> u64 {
+        let Some(discriminant) = (self.time * self.time).checked_sub(4 * self.record_distance)
+        else {
+            // No real roots, so the record can't be beaten.
+            return 0;
+        };
+        let half_time = self.time / 2;
+        let mut shortest = (self.time - discriminant.isqrt()) / 2;
+        // `isqrt()` rounds down, so `shortest` might be off by one in either
+        // direction; nudge it onto the exact boundary.
+        while shortest <= half_time && self.distance(shortest) <= self.record_distance {
+            shortest += 1;
+        }
+        while shortest > 0 && self.distance(shortest - 1) > self.record_distance {
+            shortest -= 1;
+        }
+        if shortest > half_time {
+            return 0;
+        }
+        let longest = self.time - shortest;
+        longest - shortest + 1
+    }
+}
+
+/// Append the decimal digits of `b` to those of `a`, so `concat(7, 15)` is `715`.
+fn concat(a: u64, b: u64) -> u64 {
+    a * 10u64.pow(b.checked_ilog10().unwrap_or(0) + 1) + b
+}
+
+#[derive(Debug)]
+pub struct Races {
+    races: Vec<Race>,
+}
+
+impl Races {
+    fn product_of_ways_to_win(&self) -> u64 {
+        self.races.iter().map(Race::num_ways_to_win).product()
+    }
+
+    /// In part 2 the numbers on each line are really a single number
+    /// with bad kerning, so we glue all the races together into one.
+    fn single_race(&self) -> Race {
+        self.races.iter().fold(
+            Race {
+                time: 0,
+                record_distance: 0,
+            },
+            |acc, race| Race {
+                time: concat(acc.time, race.time),
+                record_distance: concat(acc.record_distance, race.record_distance),
+            },
+        )
+    }
+}
+
+fn parse_line<'a>(
+    lines: &mut impl Iterator<Item = &'a str>,
+    label: &'static str,
+) -> Result<Vec<u64>, Day06Error> {
+    let line = lines.next().ok_or(Day06Error::MissingLine(label))?;
+    let values = line
+        .strip_prefix(label)
+        .ok_or_else(|| Day06Error::MissingLabel(label, line.to_string()))?;
+    Ok(parse_numbers(values)?)
+}
+
+impl FromStr for Races {
+    type Err = Day06Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let times = parse_line(&mut lines, "Time:")?;
+        let distances = parse_line(&mut lines, "Distance:")?;
+        if times.len() != distances.len() {
+            return Err(Day06Error::MismatchedCounts {
+                num_times: times.len(),
+                num_distances: distances.len(),
+            });
+        }
+        let races = times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, record_distance)| Race {
+                time,
+                record_distance,
+            })
+            .collect();
+        Ok(Self { races })
+    }
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    const DAY: u8 = 6;
+
+    type Parsed<'input> = Races;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Races::from_str(input)?)
+    }
+
+    fn part_1(races: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(races.product_of_ways_to_win().into())
+    }
+
+    fn part_2(races: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(races.single_race().num_ways_to_win().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+    use test_case::test_case;
+
+    #[test_case(7, 9, 4 ; "first sample race")]
+    #[test_case(15, 40, 8 ; "second sample race")]
+    #[test_case(30, 200, 9 ; "third sample race")]
+    #[test_case(4, 4, 0 ; "record can only be tied")]
+    #[test_case(3, 5, 0 ; "record can't be reached")]
+    fn closed_form_matches_brute_force(time: u64, record_distance: u64, expected: u64) {
+        let race = Race {
+            time,
+            record_distance,
+        };
+        let brute_force = (0..=time)
+            .filter(|&hold_time| race.distance(hold_time) > record_distance)
+            .count();
+        assert_eq!(race.num_ways_to_win(), expected);
+        assert_eq!(u64::try_from(brute_force).unwrap(), expected);
+    }
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_06_test.txt");
+        let races = Races::from_str(input).unwrap();
+        assert_eq!(races.product_of_ways_to_win(), 288);
+        assert_eq!(races.single_race().num_ways_to_win(), 71503);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Races::from_str("").unwrap_err(),
+            Races::from_str("Distance: 9\n").unwrap_err(),
+            Races::from_str("Time: 7x\nDistance: 9\n").unwrap_err(),
+            Races::from_str("Time: 7 15\nDistance: 9\n").unwrap_err(),
+        ];
+        check_codes::<_, Day06ErrorKind>(6, &examples);
+    }
+}
@@ -0,0 +1,565 @@
+use aoc_core::solver::{Answer, Solver};
+use miette::{Diagnostic, SourceSpan};
+use std::{fmt::Display, io::BufRead, num::ParseIntError, str::FromStr};
+use strum::{EnumDiscriminants, EnumIter};
+
+use cards::{Card, Hand};
+pub use cards::{Ranking, RuleSet};
+
+/// Everything that can go wrong on day 7.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day07ErrorKind), derive(EnumIter))]
+pub enum Day07Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    IllegalRound(#[from] RoundParseError),
+
+    #[error("{card:?} is in the ranking more than once")]
+    #[diagnostic(code(aoc::day07::duplicate_card))]
+    DuplicateCard { card: char },
+
+    #[error("The wild card {card:?} isn't in the ranking")]
+    #[diagnostic(
+        code(aoc::day07::unranked_wild_card),
+        help("Put it in the ranking where it should go for breaking ties")
+    )]
+    UnrankedWildCard { card: char },
+
+    #[error("Couldn't read the rounds")]
+    #[diagnostic(code(aoc::day07::read))]
+    Read(#[from] std::io::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Illegal card character {0:?}")]
+pub struct IllegalCardError(char);
+
+#[derive(Debug, thiserror::Error)]
+pub enum RoundProblem {
+    #[error(transparent)]
+    IllegalCard(#[from] IllegalCardError),
+    #[error("A hand has to have 5 cards, but this one has {0}")]
+    WrongHandSize(usize),
+    #[error("Expected a hand and a bid separated by a space")]
+    MissingBid,
+    #[error("Illegal bid: {0}")]
+    IllegalBid(#[from] ParseIntError),
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("Illegal round of Camel Cards on line {line_number}")]
+#[diagnostic(
+    code(aoc::day07::illegal_round),
+    help("Each line should be a hand of 5 cards from \"23456789TJQKA\" followed by a bid")
+)]
+pub struct RoundParseError {
+    #[source_code]
+    src: String,
+
+    line_number: usize,
+
+    #[label("{problem}")]
+    location: SourceSpan,
+
+    problem: RoundProblem,
+}
+
+/// The cards and hands of Camel Cards, and the rankings that say which
+/// cards beat which.
+mod cards {
+    use itertools::Itertools;
+    use std::fmt::Display;
+
+    use super::Day07Error;
+
+    /// Which of the puzzle's rules to rank the hands by.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum RuleSet {
+        /// Part 1's rules, where `J` is a jack, between the ten and the
+        /// queen.
+        #[default]
+        Standard,
+        /// Part 2's rules, where every `J` is a joker instead, which is the
+        /// weakest card but can stand in for any other card when
+        /// classifying a hand.
+        Jokers,
+    }
+
+    /// A card, as the symbol printed on it. How strong it is is up to the
+    /// `Ranking`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Card(pub char);
+
+    impl Display for Card {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    /// Which cards beat which, and which card (if any) is wild. This is
+    /// data rather than the order of an enum's variants, so house rules
+    /// can move cards around or add new ones.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Ranking {
+        /// Every card in the deck, weakest first.
+        order: Vec<Card>,
+        wild: Option<Card>,
+    }
+
+    impl Ranking {
+        /// A ranking with the cards in `order`, weakest first, where the
+        /// `wild` card, if there is one, stands in for whatever card makes
+        /// the best hand. It still has to be somewhere in `order`, for
+        /// breaking ties.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if a card is in `order` more than once, or if
+        /// the `wild` card isn't in it at all.
+        pub fn new(order: &str, wild: Option<char>) -> Result<Self, Day07Error> {
+            let order = order.chars().map(Card).collect::<Vec<_>>();
+            if let Some(card) = order.iter().duplicates().next() {
+                return Err(Day07Error::DuplicateCard { card: card.0 });
+            }
+            let wild = wild.map(Card);
+            if let Some(card) = wild.filter(|card| !order.contains(card)) {
+                return Err(Day07Error::UnrankedWildCard { card: card.0 });
+            }
+            Ok(Self { order, wild })
+        }
+
+        /// Whether `card` is in this ranking's deck.
+        #[must_use]
+        pub fn contains(&self, card: Card) -> bool {
+            self.order.contains(&card)
+        }
+
+        /// How strong `card` is, where cards that aren't in the deck are
+        /// weaker than all the ones that are. A deck can't have more cards
+        /// than there are `char`s, so this always fits in a `u32`.
+        #[allow(clippy::cast_possible_truncation)]
+        fn strength(&self, card: Card) -> u32 {
+            self.order
+                .iter()
+                .position(|&ranked| ranked == card)
+                .map_or(0, |position| position as u32 + 1)
+        }
+    }
+
+    impl From<RuleSet> for Ranking {
+        fn from(rules: RuleSet) -> Self {
+            let (order, wild) = match rules {
+                RuleSet::Standard => ("23456789TJQKA", None),
+                RuleSet::Jokers => ("J23456789TQKA", Some('J')),
+            };
+            Self {
+                order: order.chars().map(Card).collect(),
+                wild: wild.map(Card),
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+    pub enum HandType {
+        HighCard,
+        OnePair,
+        TwoPair,
+        ThreeOfAKind,
+        FullHouse,
+        FourOfAKind,
+        FiveOfAKind,
+    }
+
+    // Deriving `Ord` and `PartialOrd` on the `Hand` struct
+    // will check the fields from top to bottom. So here
+    // it will check `HandType` first, using that result
+    // if it's not `Equal`. If it is `Equal`, then it moves
+    // on to the cards' `strengths`, checking them left to
+    // right, using the order from the `Ranking`. This is
+    // exactly the ordering required by the problem, which
+    // is quite cool.
+    #[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
+    pub struct Hand {
+        hand_type: HandType,
+        strengths: [u32; 5],
+    }
+
+    impl Hand {
+        /// The hand `cards` make when they're ranked by `ranking`.
+        pub fn new(cards: [Card; 5], ranking: &Ranking) -> Self {
+            Self {
+                hand_type: Self::classify(cards, ranking),
+                strengths: cards.map(|card| ranking.strength(card)),
+            }
+        }
+
+        /// What type of hand `cards` is under `ranking`, where any wild
+        /// cards join whichever group of cards makes the best hand. That's
+        /// always the biggest group, so only the two biggest groups matter.
+        pub fn classify(cards: [Card; 5], ranking: &Ranking) -> HandType {
+            let mut counts = cards.iter().counts();
+            let num_jokers = ranking
+                .wild
+                .and_then(|wild| counts.remove(&wild))
+                .unwrap_or_default();
+            let mut counts = counts.into_values().collect::<Vec<_>>();
+            counts.sort_unstable_by(|a, b| b.cmp(a));
+            let biggest = counts.first().copied().unwrap_or_default() + num_jokers;
+            let second_biggest = counts.get(1).copied().unwrap_or_default();
+            match (biggest, second_biggest) {
+                (5.., _) => HandType::FiveOfAKind,
+                (4, _) => HandType::FourOfAKind,
+                (3, 2) => HandType::FullHouse,
+                (3, _) => HandType::ThreeOfAKind,
+                (2, 2) => HandType::TwoPair,
+                (2, _) => HandType::OnePair,
+                _ => HandType::HighCard,
+            }
+        }
+    }
+}
+
+/// A hand and its bid, as dealt; the hand is only ranked once we know
+/// which rules we're playing by.
+#[derive(Debug)]
+struct Round {
+    cards: [Card; 5],
+    bid: u32,
+}
+
+impl Display for Round {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.cards.iter().try_for_each(|card| card.fmt(f))?;
+        write!(f, " {}", self.bid)
+    }
+}
+
+impl Round {
+    /// Parse a single line of the input, whose cards have to be in
+    /// `ranking`'s deck, returning the problem along with the span (within
+    /// `line`) that caused it.
+    fn parse(line: &str, ranking: &Ranking) -> Result<Self, (RoundProblem, SourceSpan)> {
+        let whole_line = SourceSpan::new(0.into(), line.len());
+        let (hand, bid) = line
+            .split_once(' ')
+            .ok_or((RoundProblem::MissingBid, whole_line))?;
+        let cards = hand
+            .char_indices()
+            .map(|(offset, c)| {
+                if ranking.contains(Card(c)) {
+                    Ok(Card(c))
+                } else {
+                    Err((
+                        IllegalCardError(c).into(),
+                        SourceSpan::new(offset.into(), c.len_utf8()),
+                    ))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let num_cards = cards.len();
+        let cards = cards.try_into().map_err(|_| {
+            (
+                RoundProblem::WrongHandSize(num_cards),
+                SourceSpan::new(0.into(), hand.len()),
+            )
+        })?;
+        let bid_start = line.len() - bid.len();
+        let bid = bid.parse().map_err(|error: ParseIntError| {
+            (error.into(), SourceSpan::new(bid_start.into(), bid.len()))
+        })?;
+        Ok(Self { cards, bid })
+    }
+}
+
+#[derive(Debug)]
+pub struct Game {
+    rounds: Vec<Round>,
+}
+
+impl FromStr for Game {
+    type Err = Day07Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::parse_with(s, &RuleSet::Standard.into())
+    }
+}
+
+impl Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.rounds
+            .iter()
+            .try_for_each(|round| writeln!(f, "{round}"))
+    }
+}
+
+impl Game {
+    /// Parse a game whose cards can be any of the ones in `ranking`, for
+    /// house rules with cards of their own. `Game::from_str()` expects the
+    /// usual cards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error pointing at the first round that isn't a hand of
+    /// 5 cards from `ranking` followed by a bid.
+    pub fn parse_with(s: &str, ranking: &Ranking) -> Result<Self, Day07Error> {
+        let rounds = s
+            .lines()
+            .zip(1..)
+            .map(|(line, line_number)| {
+                Round::parse(line, ranking).map_err(|(problem, location)| RoundParseError {
+                    src: line.to_string(),
+                    line_number,
+                    location,
+                    problem,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { rounds })
+    }
+
+    /// Rank every hand under `rules`, and add up each bid times its
+    /// hand's rank.
+    #[must_use]
+    pub fn total_winnings(&self, rules: RuleSet) -> u64 {
+        self.total_winnings_with(&rules.into())
+    }
+
+    /// Like `total_winnings()`, but with the cards ranked by `ranking`,
+    /// to try out house rules.
+    #[must_use]
+    pub fn total_winnings_with(&self, ranking: &Ranking) -> u64 {
+        let hands = self
+            .rounds
+            .iter()
+            .map(|round| (Hand::new(round.cards, ranking), round.bid))
+            .collect();
+        rank_and_total(hands)
+    }
+}
+
+/// Sort `hands` from weakest to strongest, and add up each bid times
+/// its hand's rank.
+fn rank_and_total(mut hands: Vec<(Hand, u32)>) -> u64 {
+    hands.sort_unstable();
+    (1..)
+        .zip(&hands)
+        .map(|(rank, (_, bid))| rank * u64::from(*bid))
+        .sum()
+}
+
+/// Rank the rounds from `reader` under `rules`, reading them a line at a
+/// time.
+///
+/// Games with millions of hands (or piped into stdin) can be ranked
+/// without reading them all into memory first. Only each hand's type,
+/// card strengths, and bid are kept until they're sorted.
+///
+/// # Errors
+///
+/// Returns an error if `reader` fails, or on the first line that isn't
+/// a round, pointing to that line on its own.
+pub fn stream_total_winnings(mut reader: impl BufRead, rules: RuleSet) -> Result<u64, Day07Error> {
+    let ranking = Ranking::from(rules);
+    let mut hands = Vec::new();
+    let mut text = String::new();
+    for line_number in 1.. {
+        text.clear();
+        if reader.read_line(&mut text)? == 0 {
+            break;
+        }
+        let line = text.trim_end_matches(['\n', '\r']);
+        let round =
+            Round::parse(line, &ranking).map_err(|(problem, location)| RoundParseError {
+                src: line.to_string(),
+                line_number,
+                location,
+                problem,
+            })?;
+        hands.push((Hand::new(round.cards, &ranking), round.bid));
+    }
+    Ok(rank_and_total(hands))
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    const DAY: u8 = 7;
+
+    type Parsed<'input> = Game;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Game::from_str(input)?)
+    }
+
+    fn part_1(game: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(game.total_winnings(RuleSet::Standard).into())
+    }
+
+    fn part_2(game: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(game.total_winnings(RuleSet::Jokers).into())
+    }
+}
+
+#[cfg(test)]
+mod day_07_tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    fn round_error(input: &str) -> RoundParseError {
+        match Game::from_str(input).unwrap_err() {
+            Day07Error::IllegalRound(error) => error,
+            error => panic!("Expected an illegal round, got {error:?}"),
+        }
+    }
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_07_test.txt");
+        let game = Game::from_str(input).unwrap();
+        assert_eq!(game.total_winnings(RuleSet::Standard), 6440);
+        assert_eq!(game.total_winnings(RuleSet::Jokers), 5905);
+    }
+
+    #[test]
+    fn illegal_card_is_labeled() {
+        let error = round_error("32T3K 765\nT55X5 684");
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.location, SourceSpan::new(3.into(), 1));
+        assert!(matches!(
+            error.problem,
+            RoundProblem::IllegalCard(IllegalCardError('X'))
+        ));
+    }
+
+    #[test]
+    fn short_hand_is_labeled() {
+        let error = round_error("32T3 765");
+        assert_eq!(error.location, SourceSpan::new(0.into(), 4));
+        assert!(matches!(error.problem, RoundProblem::WrongHandSize(4)));
+    }
+
+    #[test]
+    fn streaming_gets_the_same_totals() {
+        let input = include_str!("../../../src/inputs/day_07_test.txt");
+        let total = stream_total_winnings(input.as_bytes(), RuleSet::Standard).unwrap();
+        assert_eq!(total, 6440);
+        let total = stream_total_winnings(input.as_bytes(), RuleSet::Jokers).unwrap();
+        assert_eq!(total, 5905);
+
+        let error = stream_total_winnings(&b"32T3K 765\r\nT55X5 684\r\n"[..], RuleSet::Standard);
+        assert!(matches!(
+            error,
+            Err(Day07Error::IllegalRound(RoundParseError { line_number: 2, location, .. }))
+                if location == SourceSpan::new(3.into(), 1)
+        ));
+    }
+
+    #[test]
+    fn tens_can_rank_below_nines() {
+        let game = Game::from_str("T2345 1\n92345 10\n").unwrap();
+        assert_eq!(game.total_winnings(RuleSet::Standard), 12);
+        let ranking = Ranking::new("2345678T9JQKA", None).unwrap();
+        assert_eq!(game.total_winnings_with(&ranking), 21);
+    }
+
+    #[test]
+    fn house_rules_can_add_cards() {
+        let ranking = Ranking::new("23456789TJQKAZ", Some('Z')).unwrap();
+        assert!(Game::from_str("ZZ234 5\nAA234 7\n").is_err());
+        let game = Game::parse_with("ZZ234 5\nAA234 7\n", &ranking).unwrap();
+        assert_eq!(game.total_winnings_with(&ranking), 17);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Game::from_str("32T3K").unwrap_err(),
+            Ranking::new("23456789TJQKA2", None).unwrap_err(),
+            Ranking::new("23456789TQKA", Some('J')).unwrap_err(),
+            std::io::Error::other("the rounds went away").into(),
+        ];
+        check_codes::<_, Day07ErrorKind>(7, &examples);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use itertools::Itertools;
+    use proptest::prelude::*;
+
+    fn hand() -> impl Strategy<Value = String> {
+        "[2-9TJQKA]{5}"
+    }
+
+    fn cards() -> impl Strategy<Value = [Card; 5]> {
+        hand().prop_map(|hand| {
+            let cards = hand.chars().map(Card).collect::<Vec<_>>();
+            <[Card; 5]>::try_from(cards).unwrap()
+        })
+    }
+
+    /// Every card that isn't a joker, for trying jokers as each of them.
+    const NOT_JOKERS: &str = "23456789TQKA";
+
+    fn rounds() -> impl Strategy<Value = String> {
+        prop::collection::vec((hand(), 1..1000u32), 1..20).prop_map(|rounds| {
+            rounds
+                .into_iter()
+                .map(|(hand, bid)| format!("{hand} {bid}"))
+                .join("\n")
+                + "\n"
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn games_round_trip(input in rounds()) {
+            let game = Game::from_str(&input).unwrap();
+            prop_assert_eq!(game.to_string(), input);
+        }
+
+        #[test]
+        fn jokers_never_weaken_a_hand(input in rounds()) {
+            let game = Game::from_str(&input).unwrap();
+            for round in &game.rounds {
+                prop_assert!(
+                    Hand::classify(round.cards, &RuleSet::Jokers.into())
+                        >= Hand::classify(round.cards, &RuleSet::Standard.into())
+                );
+            }
+        }
+
+        #[test]
+        fn any_five_cards_can_be_classified(
+            cards in prop::array::uniform5(any::<char>().prop_map(Card)),
+            wild in any::<char>(),
+        ) {
+            let ranking = Ranking::new(&wild.to_string(), Some(wild)).unwrap();
+            Hand::classify(cards, &ranking);
+            Hand::classify(cards, &RuleSet::Standard.into());
+        }
+
+        #[test]
+        fn adding_a_joker_never_weakens_a_hand(cards in cards(), index in 0..5usize) {
+            let jokers = Ranking::from(RuleSet::Jokers);
+            let mut with_joker = cards;
+            with_joker[index] = Card('J');
+            prop_assert!(Hand::classify(with_joker, &jokers) >= Hand::classify(cards, &jokers));
+        }
+
+        /// The jokers make the best hand they could by all pretending to
+        /// be the same card, so trying each card for them has to agree.
+        #[test]
+        fn jokers_play_as_the_best_card(cards in cards()) {
+            let best = NOT_JOKERS
+                .chars()
+                .map(|stand_in| {
+                    let cards = cards.map(|card| if card == Card('J') { Card(stand_in) } else { card });
+                    Hand::classify(cards, &RuleSet::Standard.into())
+                })
+                .max();
+            prop_assert_eq!(Some(Hand::classify(cards, &RuleSet::Jokers.into())), best);
+        }
+    }
+}
@@ -0,0 +1,771 @@
+use std::{collections::HashMap, fmt::Write};
+
+use aoc_core::{
+    debug_dump::dump,
+    math::{crt, lcm_of},
+    solver::{Answer, Solver},
+};
+use chumsky::prelude::*;
+use itertools::{Either, Itertools};
+use miette::{Diagnostic, LabeledSpan};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::{EnumDiscriminants, EnumIter};
+use text::newline;
+
+/// Everything that can go wrong on day 8.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day08ErrorKind), derive(EnumIter))]
+pub enum Day08Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parse(#[from] MapParseError),
+
+    #[error("The ghosts are never all on end nodes at the same time")]
+    #[diagnostic(
+        code(aoc::day08::ghosts_never_meet),
+        help(
+            "Each ghost's cycle has to reach an end node at a step that lines up with the others"
+        )
+    )]
+    GhostsNeverMeet,
+
+    #[error("There's no connection for {node}, which is reached after {step} steps")]
+    #[diagnostic(
+        code(aoc::day08::missing_node),
+        help("Every node that a connection leads to needs a line of its own")
+    )]
+    MissingNode { node: String, step: usize },
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("Failed to parse the map")]
+#[diagnostic(code(aoc::day08::parse))]
+pub struct MapParseError {
+    #[source_code]
+    src: String,
+
+    #[label(collection)]
+    problems: Vec<LabeledSpan>,
+}
+
+impl MapParseError {
+    fn new(src: &str, errors: Vec<Rich<char>>) -> Self {
+        let problems = errors
+            .into_iter()
+            .map(|error| {
+                let span = error.span();
+                LabeledSpan::new(Some(error.to_string()), span.start, span.end - span.start)
+            })
+            .collect();
+        Self {
+            src: src.to_string(),
+            problems,
+        }
+    }
+}
+
+/// Use `Rich` errors so parse failures know where in the input they happened.
+type Extra<'a> = extra::Err<Rich<'a, char>>;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Where this direction's node is in a `[left, right]` pair.
+    const fn index(self) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => 1,
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq)]
+struct Connection<'a> {
+    node_name: &'a str,
+    left: &'a str,
+    right: &'a str,
+}
+
+/// A node's position in `Map::names`. Following the path a step at a
+/// time with these and `Map::next` is much quicker than looking each
+/// node's name up in `Map::connections`.
+type NodeId = u16;
+
+#[derive(Debug)]
+pub struct Map<'a> {
+    path: Vec<Direction>,
+    connections: HashMap<&'a str, Connection<'a>>,
+    /// Every node's name, including ones that connections lead to but
+    /// that don't have connections of their own, which come last.
+    names: Vec<&'a str>,
+    ids: HashMap<&'a str, NodeId>,
+    /// The ids of the nodes to the left and right of each node that has
+    /// connections, indexed by its id.
+    next: Vec<[NodeId; 2]>,
+}
+
+fn parser<'a>() -> impl Parser<'a, &'a str, Map<'a>, Extra<'a>> {
+    let path = path();
+
+    let connection = parse_connection();
+
+    path.padded()
+        .then(
+            connection
+                .map(|c| (c.node_name, c))
+                .separated_by(newline())
+                .at_least(1)
+                .collect::<HashMap<_, _>>(),
+        )
+        .padded()
+        .try_map(|(path, connections), span| {
+            Map::new(path, connections).map_err(|message| Rich::custom(span, message))
+        })
+}
+
+fn parse_connection<'a>() -> impl Parser<'a, &'a str, Connection<'a>, Extra<'a>> {
+    let connections = parse_name().then_ignore(just(',')).then(parse_name());
+    (parse_name())
+        .then_ignore(just('=').padded())
+        .then(connections.delimited_by(just('('), just(')')))
+        .map(|(node_name, (left, right))| Connection {
+            node_name,
+            left,
+            right,
+        })
+}
+
+fn parse_name<'a>() -> impl Parser<'a, &'a str, &'a str, Extra<'a>> {
+    any()
+        .filter(|c: &char| c.is_alphanumeric())
+        .repeated()
+        .exactly(3)
+        .to_slice()
+        .padded()
+}
+
+fn path<'a>() -> impl Parser<'a, &'a str, Vec<Direction>, Extra<'a>> {
+    choice((
+        just('L').to(Direction::Left),
+        just('R').to(Direction::Right),
+    ))
+    .repeated()
+    .at_least(1)
+    .collect::<Vec<_>>()
+    .padded()
+}
+
+impl<'a> Map<'a> {
+    /// Give every node an id, with the ones that have connections first
+    /// in order of their names, and build the table of where each one
+    /// leads.
+    fn new(
+        path: Vec<Direction>,
+        connections: HashMap<&'a str, Connection<'a>>,
+    ) -> Result<Self, String> {
+        let mut names = Vec::new();
+        let mut ids = HashMap::new();
+        let mut intern = |name: &'a str| -> Result<NodeId, String> {
+            if let Some(&id) = ids.get(name) {
+                return Ok(id);
+            }
+            let id = NodeId::try_from(names.len()).map_err(|_| {
+                format!(
+                    "There can't be more than {} nodes",
+                    usize::from(NodeId::MAX) + 1
+                )
+            })?;
+            names.push(name);
+            ids.insert(name, id);
+            Ok(id)
+        };
+
+        let mut nodes: Vec<&str> = connections.keys().copied().collect();
+        nodes.sort_unstable();
+        for &node in &nodes {
+            intern(node)?;
+        }
+        let next = nodes
+            .iter()
+            .map(|&node| {
+                let connection = &connections[node];
+                Ok([intern(connection.left)?, intern(connection.right)?])
+            })
+            .collect::<Result<_, String>>()?;
+        Ok(Self {
+            path,
+            connections,
+            names,
+            ids,
+            next,
+        })
+    }
+
+    /// The network as a Graphviz DOT graph, with each connection labelled
+    /// with the direction that takes it, and the start and end nodes
+    /// filled in green and red. `dot -Tsvg` turns it into a picture
+    /// where each ghost's cycle is easy to pick out.
+    ///
+    /// # Panics
+    ///
+    /// Never in practice, since writing to a `String` can't fail.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        self.write_dot(&mut dot)
+            .expect("writing to a String can't fail");
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String) -> std::fmt::Result {
+        writeln!(dot, "digraph network {{")?;
+        let mut connections: Vec<&Connection> = self.connections.values().collect();
+        connections.sort_unstable_by_key(|connection| connection.node_name);
+        for connection in &connections {
+            let node = connection.node_name;
+            if node.ends_with('A') {
+                writeln!(dot, "    \"{node}\" [style=filled, fillcolor=palegreen];")?;
+            } else if node.ends_with('Z') {
+                writeln!(dot, "    \"{node}\" [style=filled, fillcolor=lightcoral];")?;
+            }
+        }
+        for Connection {
+            node_name,
+            left,
+            right,
+        } in connections
+        {
+            if left == right {
+                writeln!(dot, "    \"{node_name}\" -> \"{left}\" [label=\"LR\"];")?;
+            } else {
+                writeln!(dot, "    \"{node_name}\" -> \"{left}\" [label=\"L\"];")?;
+                writeln!(dot, "    \"{node_name}\" -> \"{right}\" [label=\"R\"];")?;
+            }
+        }
+        writeln!(dot, "}}")
+    }
+
+    /// The id of the node called `name`, which we're starting a walk
+    /// from.
+    fn id(&self, name: &str) -> Result<NodeId, Day08Error> {
+        self.ids
+            .get(name)
+            .copied()
+            .ok_or_else(|| Day08Error::MissingNode {
+                node: name.to_string(),
+                step: 0,
+            })
+    }
+
+    /// The node we get to by going in `direction` from `node`, which
+    /// we reached after `step` steps.
+    fn advance_node(
+        &self,
+        node: NodeId,
+        direction: Direction,
+        step: usize,
+    ) -> Result<NodeId, Day08Error> {
+        let next = self
+            .next
+            .get(usize::from(node))
+            .ok_or_else(|| Day08Error::MissingNode {
+                node: self.names[usize::from(node)].to_string(),
+                step,
+            })?;
+        Ok(next[direction.index()])
+    }
+
+    /// Every node we visit following the path from `start`, with the
+    /// number of steps it took to get there, beginning with `start` after
+    /// 0 steps. The walk goes on forever unless it gets to a node that
+    /// isn't in the map, in which case the last item is that error, so
+    /// use `take()` or `find()` to stop it.
+    pub fn walk<'m>(
+        &'m self,
+        start: &'a str,
+    ) -> impl Iterator<Item = Result<(&'a str, usize), Day08Error>> + 'm {
+        match self.id(start) {
+            Ok(start) => Either::Left(self.walk_ids(start).map(|visit| {
+                visit.map(|(node, step_count)| (self.names[usize::from(node)], step_count))
+            })),
+            Err(error) => Either::Right([Ok((start, 0)), Err(error)].into_iter()),
+        }
+    }
+
+    /// `walk()`, but with the nodes' ids instead of their names.
+    fn walk_ids<'m>(
+        &'m self,
+        start: NodeId,
+    ) -> impl Iterator<Item = Result<(NodeId, usize), Day08Error>> + 'm {
+        // The iterator can't mention how long the names live for, since
+        // its items don't, so pretend they only live as long as the map
+        // is borrowed.
+        let map: &'m Map<'m> = self;
+        std::iter::successors(Some(Ok((start, 0))), move |previous| {
+            let &Ok((node, step_count)) = previous else {
+                return None;
+            };
+            // The parsers make sure the path isn't empty.
+            let direction = map.path[step_count % map.path.len()];
+            Some(
+                map.advance_node(node, direction, step_count)
+                    .map(|next_node| (next_node, step_count + 1)),
+            )
+        })
+    }
+
+    /// When the ghost starting at `starting_node` is on an end node. A
+    /// ghost's position in the path and its node together decide
+    /// everything it does next, so once one of those pairs comes round
+    /// again it's going round in a cycle. End nodes it reaches before
+    /// the first one that repeats are never reached again; the rest
+    /// come round every cycle.
+    fn end_steps(&self, starting_node: &'a str) -> Result<EndSteps, Day08Error> {
+        type PathIndex = usize;
+        type StepCount = usize;
+
+        // (node, LR chain position) -> total step count
+        let mut visited_nodes: HashMap<(NodeId, PathIndex), StepCount> = HashMap::new();
+        // There are only so many (node, path index) pairs, so by this many
+        // steps the ghost has been all the way round its cycle twice. If
+        // no end node has come round again by then, there aren't any in
+        // the cycle.
+        let max_steps = 2 * self.connections.len() * self.path.len();
+
+        for visit in self.walk_ids(self.id(starting_node)?).take(max_steps + 1) {
+            let (current_node, step_count) = visit?;
+            // We only care about storing "end" nodes in the map, and can ignore all the
+            // other nodes (except for the need to count them in path lengths).
+            if !self.names[usize::from(current_node)].ends_with('Z') {
+                continue;
+            }
+            // Where we are in the path decides where we go next, just as
+            // much as the node does.
+            let path_index = step_count % self.path.len();
+            if let Some(&initial_steps_to_node) = visited_nodes.get(&(current_node, path_index)) {
+                // If we've seen this node/path index pair then we've found a cycle!
+                // Any end node from before it that was in the cycle would
+                // have come round again first, so those are all transient.
+                let (mut repeating, mut transient): (Vec<_>, Vec<_>) = visited_nodes
+                    .into_values()
+                    .partition(|&steps| steps >= initial_steps_to_node);
+                repeating.sort_unstable();
+                transient.sort_unstable();
+                return Ok(EndSteps {
+                    transient,
+                    repeating,
+                    cycle_length: step_count - initial_steps_to_node,
+                });
+            }
+            visited_nodes.insert((current_node, path_index), step_count);
+        }
+        let mut transient: Vec<_> = visited_nodes.into_values().collect();
+        transient.sort_unstable();
+        Ok(EndSteps {
+            transient,
+            repeating: Vec::new(),
+            cycle_length: 0,
+        })
+    }
+
+    fn num_steps(&self) -> Result<usize, Day08Error> {
+        let end = self.ids.get("ZZZ").copied();
+        for visit in self.walk_ids(self.id("AAA")?) {
+            let (node, step_count) = visit?;
+            if Some(node) == end {
+                return Ok(step_count);
+            }
+        }
+        unreachable!("Walks only end at a missing node, which is returned above");
+    }
+
+    /// Each starting node, i.e., each node ending in `A`, in order, with
+    /// when the ghost that starts there is on an end node. The ghosts
+    /// don't affect each other, so they're all followed at once.
+    fn ghost_cycles(&self) -> Result<Vec<(&'a str, EndSteps)>, Day08Error> {
+        let mut starting_points: Vec<&str> = self
+            .connections
+            .keys()
+            .copied()
+            .filter(|node| node.ends_with('A'))
+            .collect();
+        starting_points.sort_unstable();
+
+        starting_points
+            .par_iter()
+            .map(|&start| Ok((start, self.end_steps(start)?)))
+            .collect()
+    }
+
+    fn num_ghost_steps(&self) -> Result<usize, Day08Error> {
+        let ghost_cycles = self.ghost_cycles()?;
+        for (start, ends) in &ghost_cycles {
+            tracing::debug!(start, ?ends, "Found a ghost's cycle");
+        }
+        dump("day_08/cycles.txt", || {
+            let header = "start\ttransient ends\trepeating ends\tcycle length\n".to_string();
+            let rows = ghost_cycles.iter().map(|(start, ends)| {
+                format!(
+                    "{start}\t{:?}\t{:?}\t{}\n",
+                    ends.transient, ends.repeating, ends.cycle_length
+                )
+            });
+            std::iter::once(header).chain(rows).collect()
+        });
+        let end_steps: Vec<EndSteps> = ghost_cycles.into_iter().map(|(_, ends)| ends).collect();
+
+        // On the real inputs each ghost reaches exactly one end node per
+        // cycle, and first reaches it after exactly one cycle's worth of
+        // steps, so the answer is just the LCM of the cycle lengths.
+        let synchronized = end_steps
+            .iter()
+            .all(|ends| ends.transient.is_empty() && ends.repeating == [ends.cycle_length]);
+        let answer = if synchronized {
+            lcm_of(end_steps.iter().map(|ends| ends.cycle_length))
+        } else {
+            first_common_step(&end_steps)
+        };
+        answer.ok_or(Day08Error::GhostsNeverMeet)
+    }
+}
+
+/// When one ghost is on an end node: once at each of the `transient`
+/// steps, and at each of the `repeating` steps and every `cycle_length`
+/// steps after them, forever. If there aren't any `repeating` steps,
+/// the ghost's cycle doesn't have an end node in it, and `cycle_length`
+/// is 0.
+#[derive(Debug, Eq, PartialEq)]
+struct EndSteps {
+    transient: Vec<usize>,
+    repeating: Vec<usize>,
+    cycle_length: usize,
+}
+
+impl EndSteps {
+    fn contains(&self, step: usize) -> bool {
+        self.transient.contains(&step)
+            || self
+                .repeating
+                .iter()
+                .any(|&first| step >= first && (step - first).is_multiple_of(self.cycle_length))
+    }
+}
+
+/// The first step where every ghost is on an end node at once, if there
+/// is one. That's either one of the steps that some ghost only passes
+/// once, or it's in all their cycles, which the Chinese remainder
+/// theorem can find for each way of picking one repeating end step per
+/// ghost.
+fn first_common_step(end_steps: &[EndSteps]) -> Option<usize> {
+    let transient = end_steps
+        .iter()
+        .flat_map(|ends| ends.transient.iter().copied())
+        .filter(|&step| end_steps.iter().all(|ends| ends.contains(step)))
+        .min();
+    let repeating = end_steps
+        .iter()
+        .map(|ends| {
+            ends.repeating
+                .iter()
+                .map(|&first| (first as u64, ends.cycle_length as u64))
+        })
+        .multi_cartesian_product()
+        .filter_map(|choices| {
+            let (firsts, cycle_lengths): (Vec<u64>, Vec<u64>) = choices.into_iter().unzip();
+            let (step, period) = crt(&firsts, &cycle_lengths)?;
+            // Every ghost has to have got to its first repeating end
+            // before we can count on it being there.
+            let latest = firsts.iter().copied().max()?;
+            let step = step + latest.saturating_sub(step).div_ceil(period) * period;
+            usize::try_from(step).ok()
+        })
+        .min();
+    transient.into_iter().chain(repeating).min()
+}
+
+fn parse_map(input: &str) -> Result<Map<'_>, Day08Error> {
+    parser()
+        .parse(input)
+        .into_result()
+        .map_err(|errors| MapParseError::new(input, errors).into())
+}
+
+pub struct Day08;
+
+impl Solver for Day08 {
+    const DAY: u8 = 8;
+
+    type Parsed<'input> = Map<'input>;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(parse_map(input)?)
+    }
+
+    fn part_1(map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(map.num_steps()?.into())
+    }
+
+    fn part_2(map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(map.num_ghost_steps()?.into())
+    }
+}
+
+/// Ghost 1 is on `11Z` at every odd step and ghost 2 is on `22Z` at
+/// every even step, so they never line up.
+#[cfg(test)]
+const NEVER_MEET: &str = "L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22Z, 22Z)
+22Z = (22B, 22B)
+";
+
+/// `BBB` is reached after one step, but has no connection of its own.
+#[cfg(test)]
+const MISSING_NODE: &str = "LR
+
+AAA = (BBB, BBB)
+";
+
+#[cfg(test)]
+mod parsing_tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn test_path() {
+        let path = path()
+            .parse("RL\n\n")
+            .into_result()
+            .expect("Failed to parse path");
+        assert_eq!(path, [Direction::Right, Direction::Left]);
+    }
+
+    #[test]
+    fn test_name() {
+        let name = parse_name()
+            .parse(" XYZ ")
+            .into_result()
+            .expect("Failed to parse name");
+        assert_eq!(name, "XYZ");
+    }
+
+    #[test]
+    fn test_connection() {
+        let connection = parse_connection()
+            .parse("AAA = (BBB, CCC)")
+            .into_result()
+            .expect("Failed to parse connection");
+        assert_eq!(
+            connection,
+            Connection {
+                node_name: "AAA",
+                left: "BBB",
+                right: "CCC"
+            }
+        );
+    }
+
+    #[test]
+    fn nodes_without_connections_get_the_last_ids() {
+        let map = parse_map(MISSING_NODE).unwrap();
+        assert_eq!(map.names, ["AAA", "BBB"]);
+        assert_eq!(map.next, [[1, 1]]);
+    }
+
+    #[test]
+    fn node_ids_have_to_fit_in_a_u16() {
+        let names: Vec<String> = (0..=usize::from(NodeId::MAX) + 1)
+            .map(|n| format!("{n:05}"))
+            .collect();
+        let connections = names
+            .iter()
+            .map(|name| {
+                let connection = Connection {
+                    node_name: name,
+                    left: name,
+                    right: name,
+                };
+                (name.as_str(), connection)
+            })
+            .collect();
+        assert!(Map::new(vec![Direction::Left], connections).is_err());
+    }
+
+    #[test]
+    fn networks_draw_as_dot() {
+        let map = parse_map(include_str!("../../../src/inputs/day_08_test_1.txt")).unwrap();
+        let dot = map.to_dot();
+        assert!(dot.starts_with("digraph network {\n"));
+        assert!(dot.contains("    \"AAA\" [style=filled, fillcolor=palegreen];\n"));
+        assert!(dot.contains("    \"ZZZ\" [style=filled, fillcolor=lightcoral];\n"));
+        assert!(dot.contains("    \"AAA\" -> \"BBB\" [label=\"L\"];\n"));
+        assert!(dot.contains("    \"AAA\" -> \"CCC\" [label=\"R\"];\n"));
+        assert!(dot.contains("    \"DDD\" -> \"DDD\" [label=\"LR\"];\n"));
+        assert_eq!(dot.matches("->").count(), 10);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let never_meet = parse_map(NEVER_MEET).unwrap();
+        let examples = [
+            parse_map("").unwrap_err(),
+            never_meet.num_ghost_steps().unwrap_err(),
+            parse_map(MISSING_NODE).unwrap().num_steps().unwrap_err(),
+        ];
+        check_codes::<_, Day08ErrorKind>(8, &examples);
+    }
+}
+
+#[cfg(test)]
+mod ghost_tests {
+    use super::*;
+
+    #[test]
+    fn ghosts_can_pass_several_end_nodes_per_cycle() {
+        // `22Z` comes at both positions in the two-step path, so the
+        // ghost's cycle is six steps long with two ends in it.
+        let map = parse_map(include_str!("../../../src/inputs/day_08_test_3.txt")).unwrap();
+        assert_eq!(
+            map.ghost_cycles().unwrap(),
+            [
+                (
+                    "11A",
+                    EndSteps {
+                        transient: vec![],
+                        repeating: vec![2],
+                        cycle_length: 2
+                    }
+                ),
+                (
+                    "22A",
+                    EndSteps {
+                        transient: vec![],
+                        repeating: vec![3, 6],
+                        cycle_length: 6
+                    }
+                )
+            ]
+        );
+        assert_eq!(map.num_ghost_steps().unwrap(), 6);
+    }
+
+    #[test]
+    fn offset_cycles_use_the_crt() {
+        // Ghost 1 is on an end node at steps 1, 3, 5, ..., and ghost 2 at
+        // steps 3, 6, 9, ..., so the LCM of their cycle lengths (6) is a
+        // step where ghost 1 isn't on one.
+        let map = parse_map(
+            "L
+
+11A = (11Z, 11Z)
+11Z = (11A, 11A)
+22A = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+",
+        )
+        .unwrap();
+        assert_eq!(
+            map.end_steps("11A").unwrap(),
+            EndSteps {
+                transient: vec![],
+                repeating: vec![1],
+                cycle_length: 2
+            }
+        );
+        assert_eq!(map.num_ghost_steps().unwrap(), 3);
+    }
+
+    #[test]
+    fn ghosts_can_meet_before_they_start_cycling() {
+        // Ghost 1 only reaches an end node once, at step 1, and then goes
+        // round `11B` forever.
+        let map = parse_map(
+            "L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22A, 22A)
+",
+        )
+        .unwrap();
+        assert_eq!(
+            map.end_steps("11A").unwrap(),
+            EndSteps {
+                transient: vec![1],
+                repeating: vec![],
+                cycle_length: 0
+            }
+        );
+        assert_eq!(map.num_ghost_steps().unwrap(), 1);
+    }
+
+    #[test]
+    fn ghosts_that_never_meet_are_an_error() {
+        let map = parse_map(NEVER_MEET).unwrap();
+        assert!(matches!(
+            map.num_ghost_steps(),
+            Err(Day08Error::GhostsNeverMeet)
+        ));
+    }
+
+    #[test]
+    fn missing_nodes_are_an_error() {
+        let map = parse_map(MISSING_NODE).unwrap();
+        assert!(matches!(
+            map.num_steps(),
+            Err(Day08Error::MissingNode { node, step: 1 }) if node == "BBB"
+        ));
+        assert!(matches!(
+            map.end_steps("AAA"),
+            Err(Day08Error::MissingNode { step: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn paths_cant_be_empty() {
+        assert!(parse_map("\n\nAAA = (AAA, AAA)\n").is_err());
+    }
+
+    #[test]
+    fn walks_visit_every_node_in_order() {
+        let map = parse_map(include_str!("../../../src/inputs/day_08_test_2.txt")).unwrap();
+        let visits: Vec<_> = map.walk("AAA").take(7).map(Result::unwrap).collect();
+        assert_eq!(
+            visits,
+            [
+                ("AAA", 0),
+                ("BBB", 1),
+                ("AAA", 2),
+                ("BBB", 3),
+                ("AAA", 4),
+                ("BBB", 5),
+                ("ZZZ", 6)
+            ]
+        );
+    }
+
+    #[test]
+    fn walks_end_at_missing_nodes() {
+        let map = parse_map(MISSING_NODE).unwrap();
+        let visits: Vec<_> = map.walk("AAA").collect();
+        assert!(matches!(
+            &visits[..],
+            [
+                Ok(("AAA", 0)),
+                Ok(("BBB", 1)),
+                Err(Day08Error::MissingNode { step: 1, .. })
+            ]
+        ));
+    }
+}
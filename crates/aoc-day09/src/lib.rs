@@ -0,0 +1,372 @@
+use std::{io::BufRead, num::ParseIntError, str::FromStr, sync::OnceLock};
+
+use aoc_core::parsing::{numbered_lines, Line};
+use aoc_core::solver::{Answer, Solver};
+use miette::{Diagnostic, SourceSpan};
+use strum::{EnumDiscriminants, EnumIter};
+
+#[derive(Debug)]
+struct ValueHistory(Vec<i64>);
+
+impl ValueHistory {
+    /// The next value and the one before the first, in a single pass
+    /// through the values, or `None` if they overflow.
+    ///
+    /// Extending every row of the difference table by one place unrolls,
+    /// for `n` values, to
+    ///
+    /// - next: `±(C(n, 0) * values[0] - C(n, 1) * values[1] + ...)`,
+    ///   with `+` when `n` is odd, and
+    /// - previous: `C(n, 1) * values[0] - C(n, 2) * values[1] + ...`,
+    ///
+    /// so there's no need to build the table at all. The binomial
+    /// coefficients get huge long before the answers do, so the sums are
+    /// worked out in `i128`s, which are enough for histories of over a
+    /// hundred values.
+    fn predictions(&self) -> Option<(i64, i64)> {
+        let n = i128::try_from(self.0.len()).ok()?;
+        // `C(n, k)`, for each `k` in turn.
+        let mut coefficient: i128 = 1;
+        let (mut next, mut previous) = (0_i128, 0_i128);
+        for (k, &value) in (0..).zip(&self.0) {
+            let next_coefficient = coefficient.checked_mul(n - k)? / (k + 1);
+            let next_term = coefficient.checked_mul(value.into())?;
+            let previous_term = next_coefficient.checked_mul(value.into())?;
+            if k % 2 == 0 {
+                next = next.checked_add(next_term)?;
+                previous = previous.checked_add(previous_term)?;
+            } else {
+                next = next.checked_sub(next_term)?;
+                previous = previous.checked_sub(previous_term)?;
+            }
+            coefficient = next_coefficient;
+        }
+        let next = if n % 2 == 1 {
+            next
+        } else {
+            next.checked_neg()?
+        };
+        Some((i64::try_from(next).ok()?, i64::try_from(previous).ok()?))
+    }
+}
+
+/// Building the whole difference table, which is slower but obviously
+/// right, to check the closed form against.
+#[cfg(test)]
+mod recursive {
+    use super::ValueHistory;
+    use itertools::Itertools;
+
+    impl ValueHistory {
+        fn differences(&self) -> Self {
+            Self(
+                self.0
+                    .iter()
+                    .tuple_windows()
+                    .map(|(x, y)| y - x)
+                    .collect::<Vec<_>>(),
+            )
+        }
+
+        pub(super) fn predict_recursively(&self) -> i64 {
+            if self.0.iter().all_equal() {
+                return *self.0.first().unwrap();
+            }
+            let last_value = *self.0.last().unwrap();
+            last_value + self.differences().predict_recursively()
+        }
+
+        pub(super) fn predict_backwards_recursively(&self) -> i64 {
+            let first_value = *self.0.first().unwrap();
+            if self.0.iter().all_equal() {
+                return first_value;
+            }
+            first_value - self.differences().predict_backwards_recursively()
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Diagnostic)]
+#[error("Couldn't parse {value:?} on line {line_number} as an integer")]
+#[diagnostic(code(aoc::day09::parse_int))]
+pub struct ValueHistoryParseError {
+    #[source_code]
+    src: String,
+
+    value: String,
+
+    line_number: usize,
+
+    #[label("{source}")]
+    location: SourceSpan,
+
+    source: ParseIntError,
+}
+
+impl ValueHistory {
+    fn parse(line: &Line) -> Result<Self, ValueHistoryParseError> {
+        let values = line
+            .words()
+            .map(|(range, word)| {
+                word.parse().map_err(|source| ValueHistoryParseError {
+                    src: line.input.to_string(),
+                    value: word.to_string(),
+                    line_number: line.number,
+                    location: line.span(range),
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self(values))
+    }
+}
+
+/// A history whose predictions, or the totals they're added to, don't
+/// fit in the integers they're worked out with.
+#[derive(thiserror::Error, Debug, Clone, Diagnostic)]
+#[error("Predicting the history on line {line_number} overflowed")]
+#[diagnostic(
+    code(aoc::day09::overflow),
+    help("The predictions are worked out with 128-bit integers, and they and their totals have to fit in 64 bits")
+)]
+pub struct PredictionOverflowError {
+    line_number: usize,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    histories: Vec<ValueHistory>,
+    /// Both parts' totals come out of the same walk over each history,
+    /// so whichever part runs first works them out for the other too.
+    totals: OnceLock<Result<(i64, i64), PredictionOverflowError>>,
+}
+
+/// Everything that can go wrong on day 9.
+#[derive(thiserror::Error, Debug, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day09ErrorKind), derive(EnumIter))]
+pub enum Day09Error {
+    #[error("Error parsing a line")]
+    #[diagnostic(transparent)]
+    ValueHistory(#[from] ValueHistoryParseError),
+
+    #[error("Couldn't read the histories")]
+    #[diagnostic(code(aoc::day09::read))]
+    Read(#[from] std::io::Error),
+
+    #[error("Error predicting a history")]
+    #[diagnostic(transparent)]
+    Overflow(#[from] PredictionOverflowError),
+}
+
+impl FromStr for Report {
+    type Err = Day09Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let histories = numbered_lines(s)
+            .map(|line| ValueHistory::parse(&line))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            histories,
+            totals: OnceLock::new(),
+        })
+    }
+}
+
+/// Add the next and previous values of `history`, which is on
+/// `line_number`, to their totals.
+fn add_predictions(
+    (next_total, previous_total): (i64, i64),
+    history: &ValueHistory,
+    line_number: usize,
+) -> Result<(i64, i64), PredictionOverflowError> {
+    history
+        .predictions()
+        .and_then(|(next, previous)| {
+            Some((
+                next_total.checked_add(next)?,
+                previous_total.checked_add(previous)?,
+            ))
+        })
+        .ok_or(PredictionOverflowError { line_number })
+}
+
+impl Report {
+    fn totals(&self) -> Result<(i64, i64), PredictionOverflowError> {
+        self.totals
+            .get_or_init(|| {
+                self.histories
+                    .iter()
+                    .zip(1..)
+                    .try_fold((0, 0), |totals, (history, line_number)| {
+                        add_predictions(totals, history, line_number)
+                    })
+            })
+            .clone()
+    }
+
+    fn predictions_total(&self) -> Result<i64, PredictionOverflowError> {
+        Ok(self.totals()?.0)
+    }
+
+    fn backwards_predictions_total(&self) -> Result<i64, PredictionOverflowError> {
+        Ok(self.totals()?.1)
+    }
+}
+
+/// The totals of the next values (part 1) and of the previous values
+/// (part 2) of the histories from `reader`, read a line at a time.
+///
+/// Each history is only gone through once and then dropped, so reports
+/// too big to read into memory (or piped into stdin) still work.
+///
+/// # Errors
+///
+/// Returns an error if `reader` fails, on the first line with
+/// something that isn't a number, pointing to it in that line on its
+/// own, or if the predictions overflow.
+pub fn stream_prediction_totals(mut reader: impl BufRead) -> Result<(i64, i64), Day09Error> {
+    let mut totals = (0, 0);
+    let mut text = String::new();
+    for line_number in 1.. {
+        text.clear();
+        if reader.read_line(&mut text)? == 0 {
+            break;
+        }
+        let line = Line::on_its_own(text.trim_end_matches(['\n', '\r']), line_number);
+        totals = add_predictions(totals, &ValueHistory::parse(&line)?, line_number)?;
+    }
+    Ok(totals)
+}
+
+pub struct Day09;
+
+impl Solver for Day09 {
+    const DAY: u8 = 9;
+
+    type Parsed<'input> = Report;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Report::from_str(input)?)
+    }
+
+    fn part_1(report: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(report.predictions_total()?.into())
+    }
+
+    fn part_2(report: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(report.backwards_predictions_total()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+    use itertools::Itertools;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../../../src/inputs/day_09_test.txt");
+        let report = Report::from_str(input).unwrap();
+        assert_eq!(report.predictions_total().unwrap(), 114);
+        assert_eq!(report.backwards_predictions_total().unwrap(), 2);
+    }
+
+    #[test]
+    fn predicts_each_history() {
+        let input = include_str!("../../../src/inputs/day_09_test.txt");
+        let report = Report::from_str(input).unwrap();
+        let predictions: Vec<_> = report
+            .histories
+            .iter()
+            .map(ValueHistory::predictions)
+            .collect();
+        assert_eq!(predictions, [Some((18, -3)), Some((28, 0)), Some((68, 5))]);
+    }
+
+    /// `x³ - 2x² + 7` for `x` from 0 up to (but not including) `len`.
+    fn cubic_history(len: i64) -> ValueHistory {
+        ValueHistory((0..len).map(|x| x * x * x - 2 * x * x + 7).collect())
+    }
+
+    #[test]
+    fn predicts_long_histories() {
+        let history = cubic_history(100);
+        assert_eq!(history.predictions(), Some((980_007, 4)));
+        assert_eq!(history.predict_recursively(), 980_007);
+        assert_eq!(history.predict_backwards_recursively(), 4);
+    }
+
+    #[test]
+    fn overflowing_predictions_are_errors() {
+        let input = format!("0 3 6\n{}\n", cubic_history(300).0.iter().join(" "));
+        let report = Report::from_str(&input).unwrap();
+        let error = report.predictions_total().unwrap_err();
+        assert_eq!(error.line_number, 2);
+        assert!(report.backwards_predictions_total().is_err());
+        assert!(matches!(
+            stream_prediction_totals(input.as_bytes()),
+            Err(Day09Error::Overflow(PredictionOverflowError {
+                line_number: 2
+            }))
+        ));
+    }
+
+    #[test]
+    fn streams_both_totals_at_once() {
+        let input = include_str!("../../../src/inputs/day_09_test.txt");
+        assert_eq!(
+            stream_prediction_totals(input.as_bytes()).unwrap(),
+            (114, 2)
+        );
+    }
+
+    #[test]
+    fn streaming_points_at_the_bad_number_in_its_line() {
+        let error = stream_prediction_totals(&b"0 3 6\n1 x 7\n"[..]).unwrap_err();
+        let Day09Error::ValueHistory(error) = error else {
+            panic!("Expected a parse error, got {error:?}");
+        };
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.location, SourceSpan::new(2.into(), 1));
+    }
+
+    #[test]
+    fn points_at_the_bad_number() {
+        let error = Report::from_str("0 3 6\n1 x 7\n").unwrap_err();
+        let Day09Error::ValueHistory(error) = error else {
+            panic!("Expected a parse error, got {error:?}");
+        };
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.value, "x");
+        assert_eq!(error.location, SourceSpan::new(8.into(), 1));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Report::from_str("x").unwrap_err(),
+            std::io::Error::other("disk on fire").into(),
+            PredictionOverflowError { line_number: 1 }.into(),
+        ];
+        check_codes::<_, Day09ErrorKind>(9, &examples);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn closed_form_matches_the_difference_table(
+            values in prop::collection::vec(-1000_i64..1000, 1..=21)
+        ) {
+            let history = ValueHistory(values);
+            let (next, previous) = history.predictions().unwrap();
+            prop_assert_eq!(next, history.predict_recursively());
+            prop_assert_eq!(previous, history.predict_backwards_recursively());
+        }
+    }
+}
@@ -0,0 +1,564 @@
+use aoc_core::{
+    errors::GridError,
+    grid::{CellParse, Grid, Position},
+    ipos::IPos,
+    polygon::{interior_points, polygon_area},
+    solver::{Answer, Solver},
+    viz::{Svg, Visualize},
+};
+use miette::{Diagnostic, SourceSpan};
+use std::fmt::Display;
+use std::iter::FusedIterator;
+use std::{
+    ops::{Add, BitOr},
+    str::FromStr,
+};
+use strum::{EnumDiscriminants, EnumIter, EnumString, FromRepr, IntoEnumIterator};
+
+#[derive(Debug, strum::Display, FromRepr, EnumIter, Clone, Copy)]
+#[repr(u8)]
+enum Connection {
+    North = 0b1000,
+    East = 0b0100,
+    South = 0b0010,
+    West = 0b0001,
+}
+
+impl Connection {
+    const fn reverse(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    /// Convert `bits` to a (single) `Connection` direction.
+    ///
+    /// # Error
+    ///
+    /// Return `Day10Error::TooManyBits` if `bits` doesn't represent
+    /// a (single) connection.
+    fn from_bits(bits: u8) -> Result<Self, Day10Error> {
+        Self::from_repr(bits).ok_or(Day10Error::TooManyBits(bits))
+    }
+}
+
+impl BitOr for Connection {
+    type Output = u8;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        self as u8 | rhs as u8
+    }
+}
+
+/*
+   | is a vertical pipe connecting north and south.
+   - is a horizontal pipe connecting east and west.
+   L is a 90-degree bend connecting north and east.
+   J is a 90-degree bend connecting north and west.
+   7 is a 90-degree bend connecting south and west.
+   F is a 90-degree bend connecting south and east.
+   . is ground; there is no pipe in this tile.
+   S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+*/
+#[derive(EnumString, FromRepr, Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum CellType {
+    #[strum(serialize = "|")]
+    NsPipe = b'|',
+    #[strum(serialize = "-")]
+    EwPipe = b'-',
+    #[strum(serialize = "L")]
+    NeBend = b'L',
+    #[strum(serialize = "J")]
+    NwBend = b'J',
+    #[strum(serialize = "7")]
+    SwBend = b'7',
+    #[strum(serialize = "F")]
+    SeBend = b'F',
+    #[strum(serialize = ".")]
+    Ground = b'.',
+    #[strum(serialize = "S")]
+    Start = b'S',
+}
+
+impl CellParse for CellType {
+    type Error = Day10Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
+        u8::try_from(c)
+            .ok()
+            .and_then(Self::from_repr)
+            .ok_or(Day10Error::IllegalCell(c))
+    }
+}
+
+impl Display for CellType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", char::from(*self as u8))
+    }
+}
+
+impl CellType {
+    /// All the directions (`Connection`s) reachable from this cell type,
+    /// represented with bit flags as a `u8`.
+    ///
+    /// `Ground` is 0 because starting from a `Ground` cell we can't reach
+    /// anything.
+    ///
+    /// `Start` is all four directions because we can go anywhere from the
+    /// starting position.
+    fn connections(self) -> u8 {
+        match self {
+            Self::NsPipe => Connection::North | Connection::South,
+            Self::EwPipe => Connection::West | Connection::East,
+            Self::NeBend => Connection::North | Connection::East,
+            Self::NwBend => Connection::North | Connection::West,
+            Self::SwBend => Connection::South | Connection::West,
+            Self::SeBend => Connection::South | Connection::East,
+            Self::Ground => 0,
+            Self::Start => {
+                // The grouping here is necessary to prevent the evaluation of either
+                // a `u8 | Connection` or `Connection | u8` expression, neither of
+                // which is current supported. We could implement `BitOr` for these
+                // combinations of types, but that seems like overkill at the moment.
+                (Connection::North | Connection::South) | (Connection::East | Connection::West)
+            }
+        }
+    }
+
+    fn connection_from(self, incoming: Connection) -> Result<Connection, Day10Error> {
+        // This should never be called with `Start` since it won't
+        // actually work in that case.
+        assert_ne!(
+            self,
+            Self::Start,
+            "`connection_from(CellType::Start) doesn't actually work",
+        );
+        // `self.connections()` is all the connections/directions reachable from this point. `Ground`
+        // returns no connections, and `Start` returns all four.
+        //
+        // `incoming.reverse()` is the reverse of the incoming direction, e.g., if we're coming
+        // here by traveling `East`, reversing that will give us `West`.
+        //
+        // The bitwise negation `!incoming.reverse()` gives us all the directions _except_ the
+        // reverse of our incoming direction. So in our example, this would give us north, south,
+        // and west.
+        //
+        // Bitwise & of these will give us anything that's in both. In most cases `self.connections()`
+        // will return two directions, one of which is the one direction not in `!incoming.reverse()`,
+        // so we just get the remaining option, which is the outgoing direction that doesn't take
+        // us back to where we came from. If we're at `Ground` we'll get nothing back since `self.connections()`
+        // will return the "empty set".
+        Connection::from_bits(self.connections() & !(incoming.reverse() as u8))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub struct Pos {
+    row: usize,
+    col: usize,
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl Pos {
+    const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+impl Add<Connection> for Pos {
+    type Output = Result<Self, Day10Error>;
+
+    fn add(self, rhs: Connection) -> Self::Output {
+        let Self { row, col } = self;
+        Ok(match rhs {
+            Connection::North => Self {
+                row: row.checked_sub(1).ok_or(Day10Error::IllegalPos(self))?,
+                col,
+            },
+            Connection::East => Self {
+                row,
+                col: col.checked_add(1).ok_or(Day10Error::IllegalPos(self))?,
+            },
+            Connection::South => Self {
+                row: row.checked_add(1).ok_or(Day10Error::IllegalPos(self))?,
+                col,
+            },
+            Connection::West => Self {
+                row,
+                col: col.checked_sub(1).ok_or(Day10Error::IllegalPos(self))?,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Cell {
+    cell_type: CellType,
+    pos: Pos,
+}
+
+impl Cell {
+    pub const fn new(cell_type: CellType, pos: Pos) -> Self {
+        Self { cell_type, pos }
+    }
+}
+
+#[derive(Debug)]
+pub struct PipeMap {
+    grid: Grid<CellType>,
+    start: Pos,
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("Illegal character in pipe map on row {row_number:?}")]
+#[diagnostic(
+    code(aoc::day10::illegal_character),
+    help("All pipe map characters have to be from set \"|-LJ7F.S\"")
+)]
+pub struct IllegalCharacterError {
+    #[source_code]
+    src: String,
+
+    row_number: usize,
+
+    #[label("Illegal character")]
+    location: SourceSpan,
+}
+
+/// Everything that can go wrong on day 10, both in parsing the pipe map
+/// and in following the loop around it.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day10ErrorKind), derive(EnumIter))]
+pub enum Day10Error {
+    #[error("Illegal character while parsing pipe map")]
+    #[diagnostic(transparent)]
+    IllegalCharacter(#[from] IllegalCharacterError),
+    #[error("Illegal pipe map character {0:?}")]
+    #[diagnostic(code(aoc::day10::illegal_cell))]
+    IllegalCell(char),
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day10::grid))]
+    Grid(#[from] GridError),
+    #[error("No start symbol was found in the pipe map")]
+    #[diagnostic(code(aoc::day10::no_start_symbol))]
+    NoStartSymbol,
+    #[error("Attempt to access an illegal `Pos` {0} in `PipeMap")]
+    #[diagnostic(code(aoc::day10::illegal_pos))]
+    IllegalPos(Pos),
+    #[error("The pipe map is too large to measure")]
+    #[diagnostic(code(aoc::day10::too_large))]
+    TooLarge,
+    #[error("Not two options from start: {0}")]
+    #[diagnostic(code(aoc::day10::not_two_options_from_start))]
+    NotTwoOptionsFromStart(IncorrectOptions),
+    #[error("Too many bits for a single connection: {0:b}")]
+    #[diagnostic(
+        code(aoc::day10::connection),
+        help("Binary pattern passed to `from_bits()` must have a single `1` bit")
+    )]
+    TooManyBits(u8),
+}
+
+/// Find the first character that isn't a legal cell type, so we can point
+/// to exactly where it is in the input.
+fn check_characters(s: &str) -> Result<(), IllegalCharacterError> {
+    for (row_number, line) in s.lines().enumerate() {
+        if let Some(col_number) = line.chars().position(|c| CellType::from_char(c).is_err()) {
+            return Err(IllegalCharacterError {
+                src: line.to_string(),
+                row_number,
+                location: SourceSpan::new(col_number.into(), 1),
+            });
+        }
+    }
+    Ok(())
+}
+
+impl FromStr for PipeMap {
+    type Err = Day10Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        check_characters(s)?;
+        let grid = Grid::<CellType>::from_str(s)?;
+        let (start_row, start_col) = grid
+            .positions()
+            .find(|&position| grid[position] == CellType::Start)
+            .ok_or(Day10Error::NoStartSymbol)?;
+        let start = Pos::new(start_row, start_col);
+        Ok(Self { grid, start })
+    }
+}
+
+/// A pipe map is serialized as where the animal starts and the
+/// positions around its loop, as `[row, column]` pairs, since that's
+/// the part of the map that matters.
+#[cfg(feature = "serde")]
+impl serde::Serialize for PipeMap {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::{Error, SerializeStruct};
+
+        let loop_positions = self.loop_positions().map_err(S::Error::custom)?;
+        let mut pipe_map = serializer.serialize_struct("PipeMap", 2)?;
+        pipe_map.serialize_field("start", &(self.start.row, self.start.col))?;
+        pipe_map.serialize_field("loop", &loop_positions)?;
+        pipe_map.end()
+    }
+}
+
+impl Display for PipeMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.grid, f)
+    }
+}
+
+#[derive(Debug)]
+pub struct IncorrectOptions(Vec<Connection>);
+
+impl Display for IncorrectOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#?}", self.0)
+    }
+}
+
+impl PipeMap {
+    fn start_cell(&self) -> Result<Cell, Day10Error> {
+        self.get(self.start)
+    }
+
+    fn starting_options(&self) -> Result<(Cell, Vec<Connection>), Day10Error> {
+        let start = self.start_cell()?;
+        let start_options = Connection::iter()
+            .filter(|c| {
+                {
+                    let this = &self;
+                    let current_direction = *c;
+                    this.move_to(start, current_direction)
+                }
+                .and_then(|cell| cell.cell_type.connection_from(*c))
+                .is_ok()
+            })
+            .collect::<Vec<_>>();
+        if start_options.len() != 2 {
+            return Err(Day10Error::NotTwoOptionsFromStart(IncorrectOptions(
+                start_options,
+            )));
+        }
+        Ok((start, start_options))
+    }
+
+    fn get(&self, pos: Pos) -> Result<Cell, Day10Error> {
+        self.grid
+            .get((pos.row, pos.col))
+            .map(|&cell_type| Cell::new(cell_type, pos))
+            .ok_or(Day10Error::IllegalPos(pos))
+    }
+
+    fn move_to(&self, cell: Cell, direction: Connection) -> Result<Cell, Day10Error> {
+        self.get((cell.pos + direction)?)
+    }
+
+    fn half_cycle_length(&self) -> Result<u64, Day10Error> {
+        let (start, start_options) = self.starting_options()?;
+
+        let mut current_direction = start_options[0];
+        let mut current_cell = {
+            let this = &self;
+            this.move_to(start, current_direction)
+        }?;
+        let mut num_steps = 1;
+
+        while current_cell.cell_type != CellType::Start {
+            current_direction = current_cell.cell_type.connection_from(current_direction)?;
+            current_cell = {
+                let this = &self;
+                this.move_to(current_cell, current_direction)
+            }?;
+            num_steps += 1;
+        }
+
+        Ok(num_steps / 2)
+    }
+
+    fn path_cells(&self) -> Result<PipeMapIterator<'_>, Day10Error> {
+        let (start, start_options) = self.starting_options()?;
+
+        Ok(PipeMapIterator {
+            pipe_map: self,
+            current_cell: start,
+            current_direction: start_options[0],
+            finished: false,
+        })
+    }
+
+    /// The positions around the loop, in order, ending at the start.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the start isn't on a proper loop.
+    pub fn loop_positions(&self) -> miette::Result<Vec<Position>> {
+        Ok(self
+            .path_cells()?
+            .map(|cell| (cell.pos.row, cell.pos.col))
+            .collect())
+    }
+
+    /// Every cell on the loop is a vertex of a polygon, so the number of
+    /// enclosed tiles is the number of interior points from Pick's theorem.
+    fn enclosed_area(&self) -> Result<u64, Day10Error> {
+        let vertices = self
+            .path_cells()?
+            .map(|cell| IPos::try_from((cell.pos.row, cell.pos.col)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| Day10Error::TooLarge)?;
+        let boundary_len = u64::try_from(vertices.len()).map_err(|_| Day10Error::TooLarge)?;
+        Ok(interior_points(polygon_area(vertices), boundary_len))
+    }
+}
+
+impl Visualize for PipeMap {
+    /// The pipes in gray, with the loop through the start drawn over them.
+    fn to_svg(&self) -> Svg {
+        let mut svg = Svg::for_grid(&self.grid, |&cell_type| {
+            (cell_type != CellType::Ground).then_some("lightgray")
+        });
+        // A map without a proper loop still gets drawn, just without the loop.
+        if let Ok(cells) = self.path_cells() {
+            svg.path(cells.map(|cell| (cell.pos.row, cell.pos.col)), "red", true);
+        }
+        svg.dot((self.start.row, self.start.col), "green");
+        svg
+    }
+}
+
+struct PipeMapIterator<'a> {
+    pipe_map: &'a PipeMap,
+    current_cell: Cell,
+    current_direction: Connection,
+    finished: bool,
+}
+
+impl Iterator for PipeMapIterator<'_> {
+    type Item = Cell;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let next_cell = self
+            .pipe_map
+            .move_to(self.current_cell, self.current_direction)
+            .ok()?;
+
+        if next_cell.cell_type == CellType::Start {
+            self.finished = true;
+            return Some(next_cell);
+        }
+
+        let next_direction = next_cell
+            .cell_type
+            .connection_from(self.current_direction)
+            .ok()?;
+
+        self.current_cell = next_cell;
+        self.current_direction = next_direction;
+        Some(next_cell)
+    }
+}
+
+impl FusedIterator for PipeMapIterator<'_> {}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+
+    type Parsed<'input> = PipeMap;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(PipeMap::from_str(input)?)
+    }
+
+    fn part_1(pipe_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(pipe_map.half_cycle_length()?.into())
+    }
+
+    fn part_2(pipe_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(pipe_map.enclosed_area()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            PipeMap::from_str("x").unwrap_err(),
+            CellType::from_char('x').unwrap_err(),
+            PipeMap::from_str("S.\n.").unwrap_err(),
+            PipeMap::from_str(".").unwrap_err(),
+            (Pos::new(0, 0) + Connection::North).unwrap_err(),
+            Day10Error::TooLarge,
+            PipeMap::from_str("S")
+                .unwrap()
+                .starting_options()
+                .unwrap_err(),
+            Connection::from_bits(0b11).unwrap_err(),
+        ];
+        check_codes::<_, Day10ErrorKind>(10, &examples);
+    }
+}
+
+#[cfg(test)]
+mod property_tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// A rectangular pipe map with a single `S` somewhere in it, along
+    /// with where that `S` is.
+    fn pipe_map() -> impl Strategy<Value = (String, (usize, usize))> {
+        (1..10usize, 1..10usize)
+            .prop_flat_map(|(num_rows, num_cols)| {
+                (
+                    prop::collection::vec(
+                        prop::collection::vec(
+                            prop::sample::select(b"|-LJ7F.".as_slice()),
+                            num_cols,
+                        ),
+                        num_rows,
+                    ),
+                    0..num_rows,
+                    0..num_cols,
+                )
+            })
+            .prop_map(|(mut rows, start_row, start_col)| {
+                rows[start_row][start_col] = b'S';
+                let input = rows
+                    .into_iter()
+                    .map(|row| String::from_utf8(row).unwrap() + "\n")
+                    .collect();
+                (input, (start_row, start_col))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn pipe_maps_round_trip((input, (start_row, start_col)) in pipe_map()) {
+            let pipe_map = PipeMap::from_str(&input).unwrap();
+            prop_assert_eq!((pipe_map.start.row, pipe_map.start.col), (start_row, start_col));
+            prop_assert_eq!(pipe_map.to_string(), input);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     str::FromStr,
 };
 
+use aoc_core::solver::{Answer, Solver};
 use itertools::Itertools;
 use miette::Diagnostic;
 
@@ -49,33 +50,34 @@ impl Galaxy {
 }
 
 #[derive(Debug)]
-struct GalaxyMap {
+pub struct GalaxyMap {
     galaxies: Vec<Galaxy>,
 }
 
-const EXPANSION_RATE: usize = 1_000_000;
+/// Each empty row or column is replaced by this many empty rows or columns.
+const PART_1_EXPANSION_RATE: usize = 2;
+const PART_2_EXPANSION_RATE: usize = 1_000_000;
 
 impl GalaxyMap {
-    fn parse_and_adjust(s: &str) -> Result<Self, GalaxyMapError> {
-        let galaxy_map: Self = s.parse()?;
-        let mut galaxies = galaxy_map.galaxies;
+    fn expand(&self, expansion_rate: usize) -> Self {
+        let mut galaxies = self.galaxies.clone();
 
         // Process blank rows
-        Self::offset_elements(&mut galaxies, Axis::Row);
+        Self::offset_elements(&mut galaxies, Axis::Row, expansion_rate);
         // Process blank columns
-        Self::offset_elements(&mut galaxies, Axis::Col);
+        Self::offset_elements(&mut galaxies, Axis::Col, expansion_rate);
 
-        Ok(Self { galaxies })
+        Self { galaxies }
     }
 
-    fn offset_elements(galaxies: &mut [Galaxy], axis: Axis) {
+    fn offset_elements(galaxies: &mut [Galaxy], axis: Axis, expansion_rate: usize) {
         galaxies.sort_unstable_by_key(|galaxy| galaxy[axis]);
         let mut offset = 0;
         for i in 1..galaxies.len() {
             galaxies[i][axis] += offset;
             let diff = galaxies[i][axis] - galaxies[i - 1][axis];
             if diff > 1 {
-                let additional_offset = (diff - 1) * (EXPANSION_RATE - 1);
+                let additional_offset = (diff - 1) * (expansion_rate - 1);
                 offset += additional_offset;
                 galaxies[i][axis] += additional_offset;
             }
@@ -91,11 +93,13 @@ impl GalaxyMap {
     }
 }
 
+/// Everything that can go wrong on day 11, which is nothing: every
+/// character other than `#` is just empty space.
 #[derive(Debug, thiserror::Error, Diagnostic)]
-enum GalaxyMapError {}
+pub enum Day11Error {}
 
 impl FromStr for GalaxyMap {
-    type Err = GalaxyMapError;
+    type Err = Day11Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let galaxies = s
@@ -111,14 +115,30 @@ impl FromStr for GalaxyMap {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let input = include_str!("../inputs/day_11.txt");
-    let galaxy_map = GalaxyMap::parse_and_adjust(input)?;
-    // println!("{galaxy_map:#?}");
-    let result = galaxy_map.pairwise_length_sum();
-    println!("Result: {result}");
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+
+    type Parsed<'input> = GalaxyMap;
 
-    Ok(())
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(GalaxyMap::from_str(input)?)
+    }
+
+    fn part_1(galaxy_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(galaxy_map
+            .expand(PART_1_EXPANSION_RATE)
+            .pairwise_length_sum()
+            .into())
+    }
+
+    fn part_2(galaxy_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(galaxy_map
+            .expand(PART_2_EXPANSION_RATE)
+            .pairwise_length_sum()
+            .into())
+    }
 }
 
 #[cfg(test)]
@@ -126,19 +146,17 @@ mod tests {
     use super::*;
 
     #[test]
-    fn check_test_input() -> Result<(), GalaxyMapError> {
-        let input = include_str!("../inputs/day_11_test.txt");
-        let galaxy_map = GalaxyMap::parse_and_adjust(input)?; // .unwrap();
-        let result = galaxy_map.pairwise_length_sum(); // .unwrap();
-        assert_eq!(result, 82_000_210);
+    fn check_test_input() -> Result<(), Day11Error> {
+        let input = include_str!("../../../src/inputs/day_11_test.txt");
+        let galaxy_map = GalaxyMap::from_str(input)?;
+        let part_1 = galaxy_map
+            .expand(PART_1_EXPANSION_RATE)
+            .pairwise_length_sum();
+        assert_eq!(part_1, 374);
+        let part_2 = galaxy_map
+            .expand(PART_2_EXPANSION_RATE)
+            .pairwise_length_sum();
+        assert_eq!(part_2, 82_000_210);
         Ok(())
     }
-
-    #[test]
-    fn check_full_input() {
-        let input = include_str!("../inputs/day_11.txt");
-        let galaxy_map = GalaxyMap::parse_and_adjust(input).unwrap();
-        let result = galaxy_map.pairwise_length_sum();
-        assert_eq!(result, 707_505_470_642);
-    }
 }
@@ -0,0 +1,317 @@
+use std::{iter::repeat_n, num::ParseIntError, str::FromStr};
+
+use aoc_core::{
+    memo::Memo,
+    parsing::{numbered_lines, Line},
+    progress,
+    solver::{Answer, Solver},
+};
+use indicatif::ParallelProgressIterator;
+use miette::{Diagnostic, SourceSpan};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use strum::{EnumDiscriminants, EnumIter};
+use tracing::instrument;
+
+/// Everything that can go wrong on day 12.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day12ErrorKind), derive(EnumIter))]
+pub enum Day12Error {
+    #[error("No space between the pattern and the counts on line {line_number}")]
+    #[diagnostic(code(aoc::day12::no_space))]
+    NoSpace {
+        #[source_code]
+        src: String,
+        line_number: usize,
+        #[label("There should be a space in this line")]
+        location: SourceSpan,
+    },
+
+    #[error("Illegal integer count on line {line_number}")]
+    #[diagnostic(code(aoc::day12::illegal_count))]
+    IllegalCount {
+        #[source_code]
+        src: String,
+        line_number: usize,
+        #[label("{source}")]
+        location: SourceSpan,
+        source: ParseIntError,
+    },
+
+    #[error("Illegal character in pattern on line {line_number}: {character:#?}")]
+    #[diagnostic(
+        code(aoc::day12::illegal_pattern_char),
+        help("Patterns can only have '#', '.', and '?'")
+    )]
+    IllegalPatternChar {
+        #[source_code]
+        src: String,
+        line_number: usize,
+        character: char,
+        #[label("Illegal character")]
+        location: SourceSpan,
+    },
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum Status {
+    Broken,
+    Working,
+    Unknown,
+}
+
+impl Status {
+    const fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '#' => Self::Broken,
+            '.' => Self::Working,
+            '?' => Self::Unknown,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+struct ConditionRecord {
+    pattern: Vec<Status>,
+    counts: Vec<usize>,
+}
+
+impl ConditionRecord {
+    /// Part 2 "unfolds" each record by replacing the pattern with five copies
+    /// of itself (separated by `?`) and the counts with five copies of themselves.
+    fn unfold(&self) -> Self {
+        let pattern =
+            itertools::Itertools::intersperse(repeat_n(&self.pattern, 5), &vec![Status::Unknown])
+                .flatten()
+                .copied()
+                .collect();
+        let counts = repeat_n(&self.counts, 5).flatten().copied().collect();
+        Self { pattern, counts }
+    }
+
+    #[instrument(ret)]
+    fn num_arrangements(&self) -> usize {
+        self.count_arrangements(0, 0, 0, &mut Memo::new())
+    }
+
+    fn count_arrangements(
+        &self,
+        pattern_pos: usize,
+        counts_pos: usize,
+        broken_count: usize,
+        memo: &mut Memo<(usize, usize, usize), usize>,
+    ) -> usize {
+        memo.get_or_compute((pattern_pos, counts_pos, broken_count), |memo| {
+            self.count_arrangements_uncached(pattern_pos, counts_pos, broken_count, memo)
+        })
+    }
+
+    fn count_arrangements_uncached(
+        &self,
+        pattern_pos: usize,
+        counts_pos: usize,
+        broken_count: usize,
+        memo: &mut Memo<(usize, usize, usize), usize>,
+    ) -> usize {
+        // We've reached the end of the counts, but possibly still have patterns to check.
+        // We'll set the current_count (the expected number of broken springs) to 0 since
+        // we've exhausted the counts in `self.counts`. If we see any more broken springs,
+        // that will cause this branch to "fail" and return 0.
+        let current_count = self.counts.get(counts_pos).copied().unwrap_or(0);
+        let status = match self.pattern.get(pattern_pos) {
+            Some(status) => status,
+            // We've exhausted the pattern, the number of broken springs in this block
+            // matches the expected number of broken springs, and we're at the last block,
+            // we have satisfied the pattern and can return 1.
+            None if current_count == broken_count && counts_pos >= self.counts.len() - 1 => {
+                return 1;
+            }
+            // We've exhausted the pattern, and either number of broken springs in this block
+            // doesn't match the expected number of broken springs, or we still have additional
+            // blocks to satisfy, so we return 0.
+            None => return 0,
+        };
+        let broken_path = match status {
+            // Adding this broken spring exceeds the expected number in this group,
+            // so this branch "fails" and we return 0.
+            Status::Broken | Status::Unknown if broken_count + 1 > current_count => 0,
+            Status::Broken | Status::Unknown => {
+                self.count_arrangements(pattern_pos + 1, counts_pos, broken_count + 1, memo)
+            }
+            Status::Working => 0,
+        };
+        let working_path = match status {
+            // If we see a working spring, and the current broken spring count doesn't match
+            // the expected broken spring count, then this branch fails and we return 0.
+            Status::Working | Status::Unknown
+                if broken_count > 0 && broken_count != current_count =>
+            {
+                0
+            }
+            Status::Working | Status::Unknown => self.count_arrangements(
+                pattern_pos + 1,
+                counts_pos + usize::from(broken_count > 0),
+                0,
+                memo,
+            ),
+            Status::Broken => 0,
+        };
+        broken_path + working_path
+    }
+}
+
+impl ConditionRecord {
+    fn parse(line: &Line) -> Result<Self, Day12Error> {
+        let src = || line.input.to_string();
+        let (pattern_chars, counts_chars) =
+            line.text
+                .split_once(' ')
+                .ok_or_else(|| Day12Error::NoSpace {
+                    src: src(),
+                    line_number: line.number,
+                    location: line.full_span(),
+                })?;
+        let pattern = pattern_chars
+            .char_indices()
+            .map(|(column, character)| {
+                Status::from_char(character).ok_or_else(|| Day12Error::IllegalPatternChar {
+                    src: src(),
+                    line_number: line.number,
+                    character,
+                    location: line.span(column..column + character.len_utf8()),
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        let mut start = pattern_chars.len() + 1;
+        let counts = counts_chars
+            .split(',')
+            .map(|count| {
+                let range = start..start + count.len();
+                start = range.end + 1;
+                count.parse().map_err(|source| Day12Error::IllegalCount {
+                    src: src(),
+                    line_number: line.number,
+                    location: line.span(range),
+                    source,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { pattern, counts })
+    }
+}
+
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ConditionRecords {
+    records: Vec<ConditionRecord>,
+}
+
+impl ConditionRecords {
+    fn unfold(&self) -> Self {
+        self.records.iter().map(ConditionRecord::unfold).collect()
+    }
+
+    fn num_arrangements(&self) -> usize {
+        self.records
+            .par_iter()
+            .progress_with(progress::bar(self.records.len()))
+            .map(|cr| {
+                let result = cr.num_arrangements();
+                tracing::debug!(result, "Counted arrangements");
+                result
+            })
+            .sum()
+    }
+}
+
+impl FromIterator<ConditionRecord> for ConditionRecords {
+    fn from_iter<T: IntoIterator<Item = ConditionRecord>>(iter: T) -> Self {
+        Self {
+            records: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl FromStr for ConditionRecords {
+    type Err = Day12Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        numbered_lines(s)
+            .map(|line| ConditionRecord::parse(&line))
+            .collect()
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+
+    type Parsed<'input> = ConditionRecords;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(input.parse()?)
+    }
+
+    fn part_1(condition_records: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(condition_records.num_arrangements().into())
+    }
+
+    fn part_2(condition_records: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(condition_records.unfold().num_arrangements().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+    use test_case::test_case;
+    use tracing_test::traced_test;
+
+    #[traced_test]
+    #[test_case(". 0", 1 ; "single dot")]
+    #[test_case("# 1",  1  ; "single hash")]
+    #[test_case("# 1,1", 0 ; "single hash with two counts")]
+    #[test_case("? 0", 1 ; "single question mark with zero")]
+    #[test_case("? 1", 1 ; "single question mark with one")]
+    fn base_cases(input: &'static str, expected: usize) -> Result<(), Day12Error> {
+        let condition_records: ConditionRecords = input.parse()?;
+        let result = condition_records.num_arrangements();
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[traced_test]
+    #[test]
+    fn check_test_input() -> Result<(), Day12Error> {
+        let input = include_str!("../../../src/inputs/day_12_test.txt");
+        let condition_records: ConditionRecords = input.parse()?;
+        assert_eq!(condition_records.num_arrangements(), 21);
+        assert_eq!(condition_records.unfold().num_arrangements(), 525_152);
+        Ok(())
+    }
+
+    #[test]
+    fn points_at_the_illegal_character() {
+        let error = "???.### 1,1,3\n.??x?? 1,1".parse::<ConditionRecords>();
+        assert!(matches!(
+            error,
+            Err(Day12Error::IllegalPatternChar {
+                line_number: 2,
+                character: 'x',
+                location,
+                ..
+            }) if location == SourceSpan::new(17.into(), 1)
+        ));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples =
+            ["#.#", "#.# x", "x 1"].map(|input| input.parse::<ConditionRecords>().unwrap_err());
+        check_codes::<_, Day12ErrorKind>(12, &examples);
+    }
+}
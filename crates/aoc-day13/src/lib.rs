@@ -1,20 +1,27 @@
+use aoc_core::{
+    errors::GridError,
+    grid::{CellParse, Grid},
+    solver::{Answer, Solver},
+};
 use miette::Diagnostic;
-use ndarray::{indices_of, Array, Array2, Axis, ShapeError};
+use ndarray::Axis;
 use std::{collections::HashSet, fmt::Write, str::FromStr};
+use strum::{EnumDiscriminants, EnumIter};
 
-#[derive(Debug, Diagnostic, thiserror::Error)]
-enum LavaIslandMapError {
-    #[error("Tried to parse a pattern with no lines")]
-    EmptyPattern,
-
+/// Everything that can go wrong on day 13.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day13ErrorKind), derive(EnumIter))]
+pub enum Day13Error {
     #[error(transparent)]
-    ArrayShape(#[from] ShapeError),
+    #[diagnostic(code(aoc::day13::grid))]
+    Grid(#[from] GridError),
 
     #[error("Illegal location character {0}")]
+    #[diagnostic(code(aoc::day13::illegal_location))]
     IllegalLocation(char),
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone)]
 enum Location {
     Ash,
     Rock,
@@ -29,16 +36,20 @@ impl std::fmt::Display for Location {
     }
 }
 
-impl Location {
-    const fn from_char(c: char) -> Result<Self, LavaIslandMapError> {
+impl CellParse for Location {
+    type Error = Day13Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
         Ok(match c {
             '.' => Self::Ash,
             '#' => Self::Rock,
-            c => return Err(LavaIslandMapError::IllegalLocation(c)),
+            c => return Err(Day13Error::IllegalLocation(c)),
         })
     }
+}
 
-    fn smudge_in_place(&mut self) {
+impl Location {
+    const fn smudge_in_place(&mut self) {
         *self = match self {
             Self::Ash => Self::Rock,
             Self::Rock => Self::Ash,
@@ -70,46 +81,37 @@ impl Reflection {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Pattern {
-    array: Array2<Location>,
+    grid: Grid<Location>,
 }
 
 impl std::fmt::Display for Pattern {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for row in self.array.rows() {
-            for location in row {
-                location.fmt(f)?;
-            }
-            f.write_char('\n')?;
-        }
-        Ok(())
+        std::fmt::Display::fmt(&self.grid, f)
     }
 }
 
 impl Pattern {
-    fn new(num_columns: usize, locations: Vec<Location>) -> Result<Self, LavaIslandMapError> {
-        debug_assert_eq!(locations.len() % num_columns, 0);
-        let num_rows = locations.len() / num_columns;
-        let array = Array::from_shape_vec((num_rows, num_columns), locations)?;
-        Ok(Self { array })
+    fn reflection_value(&self) -> Option<usize> {
+        [Axis(0), Axis(1)].into_iter().find_map(|axis| {
+            self.axis_reflection_position(axis)
+                .first()
+                .map(|&position| Reflection::new(axis, position).value())
+        })
     }
 
     fn reflection_value_with_smudges(&mut self) -> Option<usize> {
         let original_reflections = self.reflection_values();
-        for index in indices_of(&self.array) {
-            let location = self.array.get_mut(index).unwrap();
-            location.smudge_in_place();
-
+        for position in self.grid.positions() {
+            self.grid[position].smudge_in_place();
             let new_reflections = self.reflection_values();
-
-            let location = self.array.get_mut(index).unwrap();
-            location.smudge_in_place();
+            self.grid[position].smudge_in_place();
 
             let mut diff = new_reflections.difference(&original_reflections);
             if let Some(reflection) = diff.next() {
                 return Some(reflection.value());
-            };
+            }
         }
         None
     }
@@ -126,7 +128,7 @@ impl Pattern {
     }
 
     fn axis_reflection_position(&self, axis: Axis) -> Vec<usize> {
-        let num_lanes = self.array.lanes(axis).into_iter().len();
+        let num_lanes = self.grid.array().lanes(axis).into_iter().len();
         (1..num_lanes)
             // See if there is a reflection around lane `n`
             // along the given axis. `n` is the number of elements
@@ -140,7 +142,7 @@ impl Pattern {
     // then we're looking for a horizontal plane of reflection (row), and if
     // `axis` is `Axis(1)` the we're for a vertical plane of reflection (columns).
     fn check_axis_reflection(&self, axis: Axis, n: usize) -> bool {
-        let lanes = self.array.lanes(axis);
+        let lanes = self.grid.array().lanes(axis);
         lanes
             .clone()
             .into_iter()
@@ -157,29 +159,21 @@ impl Pattern {
 }
 
 impl FromStr for Pattern {
-    type Err = LavaIslandMapError;
+    type Err = Day13Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let num_columns = s
-            .lines()
-            .next()
-            .ok_or(LavaIslandMapError::EmptyPattern)?
-            .len();
-        let locations = s
-            .lines()
-            .flat_map(|s| s.chars().map(Location::from_char))
-            .collect::<Result<Vec<Location>, _>>()?;
-        Self::new(num_columns, locations)
+        let grid = Grid::from_str(s)?;
+        Ok(Self { grid })
     }
 }
 
-#[derive(Debug)]
-struct LavaIslandMap {
+#[derive(Debug, Clone)]
+pub struct LavaIslandMap {
     patterns: Vec<Pattern>,
 }
 
 impl FromStr for LavaIslandMap {
-    type Err = LavaIslandMapError;
+    type Err = Day13Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let patterns = s
@@ -191,7 +185,14 @@ impl FromStr for LavaIslandMap {
 }
 
 impl LavaIslandMap {
-    fn reflection_positions(&mut self) -> usize {
+    fn reflection_positions(&self) -> usize {
+        self.patterns
+            .iter()
+            .filter_map(Pattern::reflection_value)
+            .sum()
+    }
+
+    fn reflection_positions_with_smudges(&mut self) -> usize {
         self.patterns
             .iter_mut()
             .filter_map(Pattern::reflection_value_with_smudges)
@@ -199,34 +200,57 @@ impl LavaIslandMap {
     }
 }
 
-fn main() -> miette::Result<()> {
-    let input = include_str!("../inputs/day_13.txt");
-    let mut lava_island_map = LavaIslandMap::from_str(input)?;
-    // println!("{lava_island_map:#?}");
-    let result = lava_island_map.reflection_positions();
-    println!("Result: {result}");
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+
+    type Parsed<'input> = LavaIslandMap;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(LavaIslandMap::from_str(input)?)
+    }
 
-    Ok(())
+    fn part_1(lava_island_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(lava_island_map.reflection_positions().into())
+    }
+
+    fn part_2(lava_island_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        // Finding the smudges modifies the patterns, so work on a copy.
+        let mut lava_island_map = lava_island_map.clone();
+        Ok(lava_island_map.reflection_positions_with_smudges().into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::errors::check_codes;
 
     #[test]
-    fn check_test_input() -> Result<(), LavaIslandMapError> {
-        let input = include_str!("../inputs/day_13_test.txt");
+    fn check_test_input() -> Result<(), Day13Error> {
+        let input = include_str!("../../../src/inputs/day_13_test.txt");
         let mut lava_island_map = LavaIslandMap::from_str(input)?;
-        let result = lava_island_map.reflection_positions();
-        assert_eq!(result, 400);
+        assert_eq!(lava_island_map.reflection_positions(), 405);
+        assert_eq!(lava_island_map.reflection_positions_with_smudges(), 400);
         Ok(())
     }
 
     #[test]
-    fn check_full_input() {
-        let input = include_str!("../inputs/day_13.txt");
-        let mut lava_island_map = LavaIslandMap::from_str(input).unwrap();
-        let result = lava_island_map.reflection_positions();
-        assert_eq!(result, 32_728);
+    fn display_test_patterns() {
+        let input = include_str!("../../../src/inputs/day_13_test.txt");
+        let lava_island_map = LavaIslandMap::from_str(input).unwrap();
+        let patterns = lava_island_map
+            .patterns
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        insta::assert_snapshot!(patterns.join("\n"));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = ["", "x"].map(|input| LavaIslandMap::from_str(input).unwrap_err());
+        check_codes::<_, Day13ErrorKind>(13, &examples);
     }
 }
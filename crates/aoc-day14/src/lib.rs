@@ -0,0 +1,355 @@
+use aoc_core::{
+    animation::Animate,
+    cycle::{find_cycle, nth_with_cycle},
+    debug_dump::dump,
+    direction::CardinalDirection,
+    errors::GridError,
+    grid::{CellParse, Grid},
+    parsing::numbered_lines,
+    solver::{Answer, Solver},
+    viz::{Svg, Visualize},
+};
+use miette::{Diagnostic, SourceSpan};
+use ndarray::{Array, Axis};
+use std::{fmt::Write, hash::Hash, str::FromStr};
+use strum::{EnumDiscriminants, EnumIter};
+
+#[derive(Debug, Diagnostic, thiserror::Error)]
+#[error("Illegal location character {character:?} on line {line_number}")]
+#[diagnostic(
+    code(aoc::day14::illegal_character),
+    help("Locations have to be 'O' (round rock), '#' (cube rock), or '.' (empty)")
+)]
+pub struct IllegalCharacterError {
+    #[source_code]
+    src: String,
+
+    line_number: usize,
+
+    character: char,
+
+    #[label("Illegal character")]
+    location: SourceSpan,
+}
+
+/// Everything that can go wrong on day 14.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day14ErrorKind), derive(EnumIter))]
+pub enum Day14Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day14::grid))]
+    Grid(#[from] GridError),
+
+    #[error("Illegal character while parsing the platform")]
+    #[diagnostic(transparent)]
+    IllegalCharacter(#[from] IllegalCharacterError),
+
+    #[error("Illegal location character {0}")]
+    #[diagnostic(code(aoc::day14::illegal_location))]
+    IllegalLocation(char),
+}
+
+/// Find the first character that isn't a legal location, so we can point
+/// to exactly where it is in the input.
+fn check_characters(s: &str) -> Result<(), IllegalCharacterError> {
+    for line in numbered_lines(s) {
+        if let Some((column, character)) = line
+            .text
+            .char_indices()
+            .find(|&(_, c)| Location::from_char(c).is_err())
+        {
+            return Err(IllegalCharacterError {
+                src: s.to_string(),
+                line_number: line.number,
+                character,
+                location: line.span(column..column + character.len_utf8()),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// For this to work, Round must come be before Empty in this
+/// enum definition, since the sorting in `Platform::roll_lane_forwards()`
+/// requires that Round < Empty.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+enum Location {
+    Round,
+    Cube,
+    Empty,
+}
+
+impl std::fmt::Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Round => f.write_char('O'),
+            Self::Cube => f.write_char('#'),
+            Self::Empty => f.write_char('.'),
+        }
+    }
+}
+
+impl CellParse for Location {
+    type Error = Day14Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Self::Empty,
+            '#' => Self::Cube,
+            'O' => Self::Round,
+            c => return Err(Day14Error::IllegalLocation(c)),
+        })
+    }
+}
+
+/// How the direction we're rolling to maps onto the lanes of the array.
+trait Rolling {
+    fn axis(self) -> Axis;
+    fn lane_direction(self) -> LaneDirection;
+}
+
+impl Rolling for CardinalDirection {
+    fn axis(self) -> Axis {
+        match self {
+            Self::North | Self::South => Axis(0),
+            Self::East | Self::West => Axis(1),
+        }
+    }
+
+    fn lane_direction(self) -> LaneDirection {
+        match self {
+            Self::North | Self::West => LaneDirection::Forward,
+            Self::South | Self::East => LaneDirection::Reversed,
+        }
+    }
+}
+
+enum LaneDirection {
+    Forward,
+    Reversed,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Platform {
+    grid: Grid<Location>,
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.grid, f)
+    }
+}
+
+impl Platform {
+    /// Rolling just rearranges the locations, so `locations` always has
+    /// a multiple of `num_columns` entries.
+    fn new(num_columns: usize, locations: Vec<Location>) -> Self {
+        debug_assert_eq!(locations.len() % num_columns, 0);
+        let num_rows = locations.len() / num_columns;
+        let array = Array::from_shape_vec((num_rows, num_columns), locations)
+            .expect("rolling shouldn't change the number of locations");
+        Self {
+            grid: Grid::from(array),
+        }
+    }
+
+    fn total_load(&self, direction: CardinalDirection) -> usize {
+        self.roll(direction).compute_load()
+    }
+
+    fn spin_cycle(&self) -> Self {
+        self.roll(CardinalDirection::North)
+            .roll(CardinalDirection::West)
+            .roll(CardinalDirection::South)
+            .roll(CardinalDirection::East)
+    }
+
+    /// There are only so many ways to arrange the rocks, so the spin
+    /// cycles eventually repeat, and we can use that to skip ahead.
+    fn total_load_after_cycles(&self, num_cycles: usize) -> usize {
+        nth_with_cycle(self, Self::spin_cycle, num_cycles).compute_load()
+    }
+
+    fn compute_load(&self) -> usize {
+        self.grid.cols().map(Self::lane_load).sum()
+    }
+
+    fn lane_load<'a>(
+        lane: impl IntoIterator<Item = &'a Location, IntoIter: DoubleEndedIterator>,
+    ) -> usize {
+        lane.into_iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(position, location)| {
+                (location == &Location::Round).then_some(position + 1)
+            })
+            .sum()
+    }
+
+    fn roll(&self, direction: CardinalDirection) -> Self {
+        let locations: Vec<Location> = self
+            .grid
+            .array()
+            .lanes(direction.axis())
+            .into_iter()
+            .flat_map(|lane| Self::roll_lane(lane, &direction.lane_direction()))
+            .collect();
+        let mut result = Self::new(self.num_lanes_in_direction(direction), locations);
+        let array = result.grid.array_mut();
+        match direction {
+            CardinalDirection::North => array.swap_axes(0, 1),
+            CardinalDirection::South => {
+                array.swap_axes(0, 1);
+                array.invert_axis(Axis(0));
+            }
+            CardinalDirection::East => array.invert_axis(Axis(1)),
+            CardinalDirection::West => {}
+        }
+        dump("day_14/rolls.txt", || {
+            format!("After rolling {direction:?}:\n{result}\n")
+        });
+        result
+    }
+
+    fn roll_lane<'a>(
+        lane: impl IntoIterator<Item = &'a Location, IntoIter: DoubleEndedIterator>,
+        lane_direction: &LaneDirection,
+    ) -> Vec<Location> {
+        match lane_direction {
+            LaneDirection::Forward => Self::roll_lane_forwards(lane),
+            LaneDirection::Reversed => Self::roll_lane_forwards(lane.into_iter().rev()),
+        }
+    }
+
+    fn roll_lane_forwards<'a>(locations: impl IntoIterator<Item = &'a Location>) -> Vec<Location> {
+        let mut locations = locations.into_iter().copied().collect::<Vec<_>>();
+        locations
+            .split_mut(|location| location == &Location::Cube)
+            .for_each(<[Location]>::sort_unstable);
+        locations
+    }
+
+    fn num_lanes_in_direction(&self, direction: CardinalDirection) -> usize {
+        self.grid.array().lanes(direction.axis()).into_iter().len()
+    }
+}
+
+impl FromStr for Platform {
+    type Err = Day14Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        check_characters(s)?;
+        let grid = Grid::from_str(s)?;
+        Ok(Self { grid })
+    }
+}
+
+impl Visualize for Platform {
+    /// The platform after tilting it north, as in part 1, with the cube
+    /// rocks as squares and the round rocks as dots.
+    fn to_svg(&self) -> Svg {
+        let platform = self.roll(CardinalDirection::North);
+        let mut svg = Svg::for_grid(&platform.grid, |&location| {
+            (location == Location::Cube).then_some("dimgray")
+        });
+        for position in platform
+            .grid
+            .positions()
+            .filter(|&position| platform.grid[position] == Location::Round)
+        {
+            svg.dot(position, "saddlebrown");
+        }
+        svg
+    }
+}
+
+impl Animate for Platform {
+    /// The platform before and after each spin cycle, until it's back to
+    /// an arrangement it's already been in.
+    fn frames(&self) -> Vec<String> {
+        let cycle = find_cycle(self, Self::spin_cycle);
+        std::iter::successors(Some(self.clone()), |platform| Some(platform.spin_cycle()))
+            .take(cycle.start + cycle.length + 1)
+            .map(|platform| platform.to_string())
+            .collect()
+    }
+}
+
+const NUM_CYCLES: usize = 1_000_000_000;
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+
+    type Parsed<'input> = Platform;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Platform::from_str(input)?)
+    }
+
+    fn part_1(platform: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(platform.total_load(CardinalDirection::North).into())
+    }
+
+    fn part_2(platform: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(platform.total_load_after_cycles(NUM_CYCLES).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_day_14_test_input() {
+        let input = include_str!("../../../src/inputs/day_14_test.txt");
+        let platform = Platform::from_str(input).unwrap();
+        let part_1 = platform.total_load(CardinalDirection::North);
+        assert_eq!(part_1, 136);
+        let part_2 = platform.total_load_after_cycles(NUM_CYCLES);
+        assert_eq!(part_2, 64);
+    }
+
+    #[test]
+    fn frames_end_back_in_the_cycle() {
+        let input = include_str!("../../../src/inputs/day_14_test.txt");
+        let platform = Platform::from_str(input).unwrap();
+        let frames = platform.frames();
+        assert_eq!(frames[0], input);
+        let last = frames.last().unwrap();
+        assert_eq!(frames.iter().filter(|&frame| frame == last).count(), 2);
+    }
+
+    #[test]
+    fn display_test_platform() {
+        let input = include_str!("../../../src/inputs/day_14_test.txt");
+        let platform = Platform::from_str(input).unwrap();
+        assert_eq!(platform.to_string(), input);
+        insta::assert_snapshot!("rolled_north", platform.roll(CardinalDirection::North));
+        insta::assert_snapshot!("one_spin_cycle", platform.spin_cycle());
+    }
+
+    #[test]
+    fn points_at_the_illegal_character() {
+        let error = Platform::from_str("O.#\n.x.\n").unwrap_err();
+        let Day14Error::IllegalCharacter(error) = error else {
+            panic!("Expected an illegal character error, got {error:?}");
+        };
+        assert_eq!(error.line_number, 2);
+        assert_eq!(error.location, SourceSpan::new(5.into(), 1));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Platform::from_str("").unwrap_err(),
+            Platform::from_str("x").unwrap_err(),
+            Location::from_char('x').unwrap_err(),
+        ];
+        check_codes::<_, Day14ErrorKind>(14, &examples);
+    }
+}
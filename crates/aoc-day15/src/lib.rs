@@ -5,10 +5,12 @@ use std::{
     str::FromStr,
 };
 
-use strum::FromRepr;
+use aoc_core::solver::{Answer, Solver};
+use miette::{Diagnostic, SourceSpan};
+use strum::{EnumDiscriminants, EnumIter, FromRepr};
 
 #[derive(Debug)]
-struct InitializationSequence {
+pub struct InitializationSequence {
     steps: Vec<Step>,
 }
 
@@ -82,31 +84,73 @@ enum Operation {
 
 #[derive(Debug)]
 struct Step {
+    // Part 1 hashes the whole step, so we hang on to its text.
+    text: String,
     // The hash of this label tells us which box the operation
     // is applied to.
     label: Label,
     op: Operation,
 }
 
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error, Diagnostic)]
 pub enum ParseStepError {
+    #[error("Step isn't of the form `label=N` or `label-`: {0:?}")]
     InvalidRepresentation(String),
+    #[error("Illegal focal length {0:?}")]
     IllegalFocalLength(char),
 }
 
+impl ParseStepError {
+    /// The part of `step` that's wrong: just the focal length if that's
+    /// the problem, otherwise the whole step.
+    const fn span_in(&self, step: &str) -> std::ops::Range<usize> {
+        match self {
+            Self::IllegalFocalLength(_) => step.len() - 1..step.len(),
+            Self::InvalidRepresentation(_) => 0..step.len(),
+        }
+    }
+}
+
+/// Everything that can go wrong on day 15.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day15ErrorKind), derive(EnumIter))]
+pub enum Day15Error {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    IllegalStep(#[from] InitializationSequenceError),
+}
+
+#[derive(Debug, thiserror::Error, Diagnostic)]
+#[error("Couldn't parse step {step_number} of the initialization sequence")]
+#[diagnostic(code(aoc::day15::illegal_step))]
+pub struct InitializationSequenceError {
+    #[source_code]
+    src: String,
+
+    step_number: usize,
+
+    #[label("{source}")]
+    location: SourceSpan,
+
+    source: ParseStepError,
+}
+
 impl FromStr for Step {
     type Err = ParseStepError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.as_bytes() {
             [label @ .., b'=', f] => Self {
+                text: s.to_string(),
                 label: label.into(),
                 op: Operation::Insert(
-                    FocalLength::from_repr(*f - b'0')
+                    f.checked_sub(b'0')
+                        .and_then(FocalLength::from_repr)
                         .ok_or_else(|| ParseStepError::IllegalFocalLength(char::from(*f)))?,
                 ),
             },
             [label @ .., b'-'] => Self {
+                text: s.to_string(),
                 label: label.into(),
                 op: Operation::Delete,
             },
@@ -134,6 +178,17 @@ impl Hasher for LabelHasher {
 }
 
 impl InitializationSequence {
+    fn sum_of_hashes(&self) -> u64 {
+        self.steps
+            .iter()
+            .map(|step| {
+                let mut hasher = LabelHasher::default();
+                hasher.write(step.text.as_bytes());
+                hasher.finish()
+            })
+            .sum()
+    }
+
     fn focusing_power(&self) -> u64 {
         let hasher_builder = BuildHasherDefault::<LabelHasher>::default();
         let mut boxes: HashMap<&Label, Vec<Lens>, _> = HashMap::with_hasher(hasher_builder.clone());
@@ -172,7 +227,7 @@ impl InitializationSequence {
             }
         }
 
-        // dbg!(&boxes);
+        tracing::trace!(?boxes);
 
         // Loop over boxes (using the keys of the `HashMap`)
         //   *Make sure to add one to the box number*
@@ -195,43 +250,95 @@ impl InitializationSequence {
 }
 
 impl FromStr for InitializationSequence {
-    type Err = ParseStepError;
+    type Err = Day15Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut start = s.len() - s.trim_start().len();
         let steps = s
             .trim()
             .split(',')
-            .map(Step::from_str)
-            .collect::<Result<Vec<_>, ParseStepError>>()?;
+            .enumerate()
+            .map(|(index, step)| {
+                let offset = start;
+                start += step.len() + 1;
+                Step::from_str(step).map_err(|source| {
+                    let range = source.span_in(step);
+                    InitializationSequenceError {
+                        src: s.to_string(),
+                        step_number: index + 1,
+                        location: SourceSpan::new((offset + range.start).into(), range.len()),
+                        source,
+                    }
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(Self { steps })
     }
 }
 
-fn main() {
-    let input = include_str!("../inputs/day_15.txt");
-    let init_seq = InitializationSequence::from_str(input).unwrap();
-    // println!("{init_seq:#?}");
-    let result = init_seq.focusing_power();
-    println!("Result: {result}");
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+
+    type Parsed<'input> = InitializationSequence;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(InitializationSequence::from_str(input)?)
+    }
+
+    fn part_1(init_seq: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(init_seq.sum_of_hashes().into())
+    }
+
+    fn part_2(init_seq: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(init_seq.focusing_power().into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn hash_hash() {
+        let hasher_builder = BuildHasherDefault::<LabelHasher>::default();
+        let hash = hasher_builder.hash_one(Label::from(&b"HASH"[..]));
+        assert_eq!(hash, 52);
+    }
 
     #[test]
     fn check_day_15_test_input() {
-        let input = include_str!("../inputs/day_15_test.txt");
+        let input = include_str!("../../../src/inputs/day_15_test.txt");
         let init_seq = InitializationSequence::from_str(input).unwrap();
-        let result = init_seq.focusing_power();
-        assert_eq!(result, 145);
+        assert_eq!(init_seq.sum_of_hashes(), 1320);
+        assert_eq!(init_seq.focusing_power(), 145);
     }
 
     #[test]
-    fn check_day_15_full_input() {
-        let input = include_str!("../inputs/day_15.txt");
-        let init_seq = InitializationSequence::from_str(input).unwrap();
-        let result = init_seq.focusing_power();
-        assert_eq!(result, 269_410);
+    fn rejects_focal_length_below_zero() {
+        assert!(matches!(
+            Step::from_str("ab=)"),
+            Err(ParseStepError::IllegalFocalLength(')'))
+        ));
+    }
+
+    #[test]
+    fn points_at_the_bad_step() {
+        let Day15Error::IllegalStep(error) =
+            InitializationSequence::from_str("rn=1,cm-,qp=x,cm=2\n").unwrap_err();
+        assert_eq!(error.step_number, 3);
+        assert_eq!(error.location, SourceSpan::new(12.into(), 1));
+        assert!(matches!(
+            error.source,
+            ParseStepError::IllegalFocalLength('x')
+        ));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [InitializationSequence::from_str("rn").unwrap_err()];
+        check_codes::<_, Day15ErrorKind>(15, &examples);
     }
 }
@@ -0,0 +1,393 @@
+use aoc_core::{
+    animation::Animate,
+    direction::CardinalDirection,
+    errors::GridError,
+    grid::{CellParse, Grid, Position},
+    progress,
+    solver::{Answer, Solver},
+    viz::{Svg, Visualize},
+};
+use indicatif::ParallelProgressIterator;
+use itertools::Itertools;
+use miette::Diagnostic;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use std::{
+    fmt::{Display, Write},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 16.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day16ErrorKind), derive(EnumIter))]
+pub enum Day16Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day16::grid))]
+    Grid(#[from] GridError),
+
+    #[error("Illegal location character {0}")]
+    #[diagnostic(code(aoc::day16::illegal_location))]
+    IllegalLocation(char),
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Ord, PartialOrd)]
+enum Tile {
+    Slash,
+    Backslash,
+    Dash,
+    Pipe,
+    Empty,
+}
+
+impl Tile {
+    const fn perpendicular(self, direction: CardinalDirection) -> bool {
+        matches!(
+            (self, direction),
+            (
+                Self::Dash,
+                CardinalDirection::North | CardinalDirection::South
+            ) | (
+                Self::Pipe,
+                CardinalDirection::East | CardinalDirection::West
+            )
+        )
+    }
+
+    /// The directions a beam traveling in `direction` leaves this tile in.
+    fn outgoing(self, direction: CardinalDirection) -> impl Iterator<Item = CardinalDirection> {
+        let (first, second) = match self {
+            // If the tile is a mirror (`Slash` or `Backslash`), then rotate the direction of the beam
+            // and continue one step in the new direction.
+            Self::Slash => (direction.rotate_slash(), None),
+            Self::Backslash => (direction.rotate_backslash(), None),
+            // If the tile is a splitter (`Dash` or `Pipe`) and we strike it perpendicularly, then the beam
+            // splits into two beams, each going perpendicular to the original beam.
+            tile if tile.perpendicular(direction) => {
+                let [first, second] = direction.split();
+                (first, Some(second))
+            }
+            // If the tile is `Empty`, or it's `Dash` or `Pipe` but the beam is _not_ traveling in the perpendicular direction,
+            // then the beam just passes through this grid location continuing in the same direction.
+            _ => (direction, None),
+        };
+        std::iter::once(first).chain(second)
+    }
+}
+
+impl CellParse for Tile {
+    type Error = Day16Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '.' => Self::Empty,
+            '/' => Self::Slash,
+            '\\' => Self::Backslash,
+            '|' => Self::Pipe,
+            '-' => Self::Dash,
+            c => return Err(Day16Error::IllegalLocation(c)),
+        })
+    }
+}
+
+impl std::fmt::Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Slash => f.write_char('/'),
+            Self::Backslash => f.write_char('\\'),
+            Self::Dash => f.write_char('-'),
+            Self::Pipe => f.write_char('|'),
+            Self::Empty => f.write_char('.'),
+        }
+    }
+}
+
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "This is not a state machine like Clippy thinks"
+)]
+#[derive(Debug, Default, Copy, Clone)]
+struct EnteredFrom {
+    north: bool,
+    south: bool,
+    east: bool,
+    west: bool,
+}
+
+impl EnteredFrom {
+    pub const fn any(self) -> bool {
+        self.north || self.south || self.east || self.west
+    }
+}
+
+impl Index<CardinalDirection> for EnteredFrom {
+    type Output = bool;
+
+    fn index(&self, direction: CardinalDirection) -> &Self::Output {
+        match direction {
+            CardinalDirection::North => &self.north,
+            CardinalDirection::South => &self.south,
+            CardinalDirection::East => &self.east,
+            CardinalDirection::West => &self.west,
+        }
+    }
+}
+
+impl IndexMut<CardinalDirection> for EnteredFrom {
+    fn index_mut(&mut self, direction: CardinalDirection) -> &mut Self::Output {
+        match direction {
+            CardinalDirection::North => &mut self.north,
+            CardinalDirection::South => &mut self.south,
+            CardinalDirection::East => &mut self.east,
+            CardinalDirection::West => &mut self.west,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Location {
+    tile: Tile,
+    entered_from: EnteredFrom,
+}
+
+impl Location {
+    pub fn new(tile: Tile) -> Self {
+        Self {
+            tile,
+            entered_from: EnteredFrom::default(),
+        }
+    }
+
+    pub const fn energized(self) -> bool {
+        self.entered_from.any()
+    }
+}
+
+impl CellParse for Location {
+    type Error = Day16Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
+        Tile::from_char(c).map(Self::new)
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.tile, f)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Contraption {
+    grid: Grid<Location>,
+}
+
+impl Display for Contraption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.grid, f)
+    }
+}
+
+impl Contraption {
+    fn num_energized(&self) -> usize {
+        self.grid.iter().filter(|l| l.energized()).count()
+    }
+
+    fn maximize_energized(&self) -> usize {
+        // For each side:
+        //    Loop over all the entry points.
+        //    Clone the grid and call shine_beam
+        //    Get the `num_energized()` from the result grid
+        //    maximize over those
+        let nrows = self.grid.num_rows();
+        let ncols = self.grid.num_cols();
+        let entry_points = (0..nrows)
+            .flat_map(|row| {
+                [
+                    ((row, 0), CardinalDirection::East),
+                    ((row, ncols - 1), CardinalDirection::West),
+                ]
+            })
+            .chain((0..ncols).flat_map(|col| {
+                [
+                    ((0, col), CardinalDirection::South),
+                    ((nrows - 1, col), CardinalDirection::North),
+                ]
+            }))
+            .collect::<Vec<_>>();
+        entry_points
+            .par_iter()
+            .progress_with(progress::bar(entry_points.len()))
+            .map(|&(position, direction)| self.beam_energized(position, direction))
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn beam_energized(&self, position: Position, direction: CardinalDirection) -> usize {
+        let mut contraption = self.clone();
+        contraption.shine_beam(position, direction);
+        contraption.num_energized()
+    }
+
+    fn shine_beam(&mut self, position: Position, direction: CardinalDirection) {
+        let location = &mut self.grid[position];
+        if location.entered_from[direction.reverse()] {
+            return;
+        }
+        location.entered_from[direction.reverse()] = true;
+        // A splitter gives us two beams, so we have to call `shine_beam`
+        // on each of them.
+        for new_direction in location.tile.outgoing(direction) {
+            self.step_and_shine(position, new_direction);
+        }
+    }
+
+    /// Like `shine_beam()`, but moves every beam one step at a time and
+    /// draws the energized tiles after each step.
+    fn beam_frames(&self, position: Position, direction: CardinalDirection) -> Vec<String> {
+        let mut contraption = self.clone();
+        let mut beams = vec![(position, direction)];
+        let mut frames = Vec::new();
+        while !beams.is_empty() {
+            // Beams that have already been through here the same way
+            // won't find anything new.
+            beams.retain(|&(position, direction)| {
+                let entered = &mut contraption.grid[position].entered_from[direction.reverse()];
+                !std::mem::replace(entered, true)
+            });
+            let grid = &contraption.grid;
+            beams = beams
+                .into_iter()
+                .flat_map(|(position, direction)| {
+                    grid[position]
+                        .tile
+                        .outgoing(direction)
+                        .filter_map(move |new_direction| {
+                            grid.step(position, new_direction)
+                                .map(|new_position| (new_position, new_direction))
+                        })
+                })
+                .collect();
+            frames.push(contraption.energized_map());
+        }
+        frames
+    }
+
+    /// Draw the energized tiles as `#` and everything else as `.`, the
+    /// way the puzzle description does.
+    fn energized_map(&self) -> String {
+        self.grid
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|location| if location.energized() { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+
+    fn step_and_shine(&mut self, position: Position, direction: CardinalDirection) {
+        if let Some(pos) = self.grid.step(position, direction) {
+            self.shine_beam(pos, direction);
+        }
+    }
+}
+
+impl Animate for Contraption {
+    /// The part 1 beam spreading through the contraption.
+    fn frames(&self) -> Vec<String> {
+        self.beam_frames((0, 0), CardinalDirection::East)
+    }
+}
+
+impl Visualize for Contraption {
+    /// The tiles the part 1 beam energizes in yellow, with the mirrors
+    /// and splitters as dots.
+    fn to_svg(&self) -> Svg {
+        let mut contraption = self.clone();
+        contraption.shine_beam((0, 0), CardinalDirection::East);
+        let mut svg = Svg::for_grid(&contraption.grid, |location| {
+            location.energized().then_some("gold")
+        });
+        for position in self
+            .grid
+            .positions()
+            .filter(|&position| self.grid[position].tile != Tile::Empty)
+        {
+            svg.dot(position, "dimgray");
+        }
+        svg
+    }
+}
+
+impl FromStr for Contraption {
+    type Err = Day16Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::from_str(s)?;
+        Ok(Self { grid })
+    }
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+
+    type Parsed<'input> = Contraption;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Contraption::from_str(input)?)
+    }
+
+    fn part_1(contraption: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(contraption
+            .beam_energized((0, 0), CardinalDirection::East)
+            .into())
+    }
+
+    fn part_2(contraption: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(contraption.maximize_energized().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_day_16_test_input() {
+        let input = include_str!("../../../src/inputs/day_16_test.txt");
+        let contraption = Contraption::from_str(input).unwrap();
+        let part_1 = contraption.beam_energized((0, 0), CardinalDirection::East);
+        assert_eq!(part_1, 46);
+        let part_2 = contraption.maximize_energized();
+        assert_eq!(part_2, 51);
+    }
+
+    #[test]
+    fn beam_frames_end_with_everything_energized() {
+        let input = include_str!("../../../src/inputs/day_16_test.txt");
+        let contraption = Contraption::from_str(input).unwrap();
+        let frames = contraption.frames();
+        assert_eq!(frames[0].matches('#').count(), 1);
+        let last = frames.last().unwrap();
+        assert_eq!(last.matches('#').count(), 46);
+    }
+
+    #[test]
+    fn display_test_contraption() {
+        let input = include_str!("../../../src/inputs/day_16_test.txt");
+        let mut contraption = Contraption::from_str(input).unwrap();
+        assert_eq!(contraption.to_string(), input);
+        contraption.shine_beam((0, 0), CardinalDirection::East);
+        insta::assert_snapshot!("energized", contraption.energized_map());
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = ["", "x"].map(|input| Contraption::from_str(input).unwrap_err());
+        check_codes::<_, Day16ErrorKind>(16, &examples);
+    }
+}
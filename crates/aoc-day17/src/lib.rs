@@ -0,0 +1,326 @@
+use aoc_core::{
+    animation::Animate,
+    direction::CardinalDirection,
+    errors::GridError,
+    grid::{CellParse, Grid, Position},
+    solver::{Answer, Solver},
+    viz::{Svg, Visualize},
+};
+use miette::Diagnostic;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    str::FromStr,
+};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 17.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day17ErrorKind), derive(EnumIter))]
+pub enum Day17Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day17::grid))]
+    Grid(#[from] GridError),
+
+    #[error("Illegal heat loss character {0}")]
+    #[diagnostic(code(aoc::day17::illegal_heat_loss))]
+    IllegalHeatLoss(char),
+
+    #[error("No path to the target was found")]
+    #[diagnostic(code(aoc::day17::no_path))]
+    NoPath,
+}
+
+/// How much heat is lost entering a city block, from 1 to 9.
+#[derive(Debug, Clone, Copy)]
+struct HeatLoss(u8);
+
+impl CellParse for HeatLoss {
+    type Error = Day17Error;
+
+    fn from_char(c: char) -> Result<Self, Self::Error> {
+        c.to_digit(10)
+            .and_then(|d| u8::try_from(d).ok())
+            .map(Self)
+            .ok_or(Day17Error::IllegalHeatLoss(c))
+    }
+}
+
+/// The crucible can move at most this many steps in a single
+/// direction before it has to turn.
+const MAX_STRAIGHT_STEPS: u8 = 3;
+
+/// A state in the search: where the crucible is, which way it
+/// is facing, and how many steps it has just taken in that direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Node {
+    position: Position,
+    direction: CardinalDirection,
+    num_straight_steps: u8,
+}
+
+impl Node {
+    const fn new(position: Position, direction: CardinalDirection, num_straight_steps: u8) -> Self {
+        Self {
+            position,
+            direction,
+            num_straight_steps,
+        }
+    }
+
+    fn left(self, city_map: &CityMap) -> Option<Self> {
+        let direction = self.direction.turn_left();
+        let position = city_map.step(self.position, direction)?;
+        Some(Self::new(position, direction, 1))
+    }
+
+    fn right(self, city_map: &CityMap) -> Option<Self> {
+        let direction = self.direction.turn_right();
+        let position = city_map.step(self.position, direction)?;
+        Some(Self::new(position, direction, 1))
+    }
+
+    fn straight(self, city_map: &CityMap) -> Option<Self> {
+        if self.num_straight_steps >= MAX_STRAIGHT_STEPS {
+            return None;
+        }
+        let position = city_map.step(self.position, self.direction)?;
+        Some(Self::new(
+            position,
+            self.direction,
+            self.num_straight_steps + 1,
+        ))
+    }
+
+    /// All the nodes we can legally move to from this one. The crucible
+    /// can never reverse, so the only options are turning left, turning
+    /// right, or (if we haven't hit the limit) continuing straight.
+    fn successors(self, city_map: &CityMap) -> impl Iterator<Item = Self> {
+        [
+            self.left(city_map),
+            self.right(city_map),
+            self.straight(city_map),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+#[derive(Debug)]
+pub struct CityMap {
+    grid: Grid<HeatLoss>,
+}
+
+impl CityMap {
+    fn step(&self, position: Position, direction: CardinalDirection) -> Option<Position> {
+        self.grid.step(position, direction)
+    }
+
+    fn target(&self) -> Position {
+        (self.grid.num_rows() - 1, self.grid.num_cols() - 1)
+    }
+
+    /// The Manhattan distance to the target. Every block costs at least
+    /// one unit of heat loss, so this never overestimates the remaining
+    /// cost, which is what A* needs.
+    fn heuristic(&self, (row, col): Position) -> usize {
+        let (target_row, target_col) = self.target();
+        target_row.abs_diff(row) + target_col.abs_diff(col)
+    }
+
+    /// The map with its heat losses as digits, except for the `marked`
+    /// blocks, which are drawn as `mark`.
+    fn draw(&self, marked: &HashSet<Position>, mark: char) -> String {
+        let mut drawing = String::new();
+        for position in self.grid.positions() {
+            if marked.contains(&position) {
+                drawing.push(mark);
+            } else {
+                drawing.push(char::from(b'0' + self.grid[position].0));
+            }
+            if position.1 == self.grid.num_cols() - 1 {
+                drawing.push('\n');
+            }
+        }
+        drawing
+    }
+
+    fn minimum_heat_loss(&self) -> Option<usize> {
+        self.best_path().map(|(heat_loss, _)| heat_loss)
+    }
+
+    fn best_path(&self) -> Option<(usize, Vec<Position>)> {
+        self.search(|_| ())
+    }
+
+    /// Use A* search to find the path from the top left to the bottom
+    /// right with the least total heat loss, along with that heat loss.
+    /// `on_expand` is called with every node we expand, in order.
+    fn search(&self, mut on_expand: impl FnMut(Node)) -> Option<(usize, Vec<Position>)> {
+        let target = self.target();
+        // The starting block's heat loss doesn't count, and since we haven't
+        // moved yet we can turn right (South) or go straight (East).
+        let start = Node::new((0, 0), CardinalDirection::East, 0);
+
+        let mut best_costs: HashMap<Node, usize> = HashMap::from([(start, 0)]);
+        let mut came_from: HashMap<Node, Node> = HashMap::new();
+        let mut frontier = BinaryHeap::from([Reverse((self.heuristic(start.position), 0, start))]);
+
+        while let Some(Reverse((_, cost, node))) = frontier.pop() {
+            if node.position == target {
+                let mut path = vec![node.position];
+                let mut current = node;
+                while let Some(&previous) = came_from.get(&current) {
+                    path.push(previous.position);
+                    current = previous;
+                }
+                path.reverse();
+                return Some((cost, path));
+            }
+            // Skip stale entries where we've since found a cheaper way to this node.
+            if best_costs.get(&node).is_some_and(|&best| best < cost) {
+                continue;
+            }
+            on_expand(node);
+            for next in node.successors(self) {
+                let next_cost = cost + usize::from(self.grid[next.position].0);
+                if best_costs.get(&next).is_none_or(|&best| next_cost < best) {
+                    best_costs.insert(next, next_cost);
+                    came_from.insert(next, node);
+                    let estimate = next_cost + self.heuristic(next.position);
+                    frontier.push(Reverse((estimate, next_cost, next)));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Animate for CityMap {
+    /// The blocks the search has looked at so far as `#`, growing out
+    /// from the top left, and then the best path it found as `*`.
+    fn frames(&self) -> Vec<String> {
+        // Show about as many frames as the map is tall, however long
+        // the search takes.
+        let nodes_per_frame = self.grid.num_cols().max(1);
+        let mut explored = HashSet::new();
+        let mut frames = Vec::new();
+        let mut num_expanded = 0;
+        let best_path = self.search(|node| {
+            explored.insert(node.position);
+            num_expanded += 1;
+            if num_expanded % nodes_per_frame == 0 {
+                frames.push(self.draw(&explored, '#'));
+            }
+        });
+        if let Some((_, path)) = best_path {
+            frames.push(self.draw(&path.into_iter().collect(), '*'));
+        }
+        frames
+    }
+}
+
+/// Lighter blues for blocks that lose less heat, from 1 to 9.
+const HEAT_LOSS_COLORS: [&str; 9] = [
+    "#f7fbff", "#deebf7", "#c6dbef", "#9ecae1", "#6baed6", "#4292c6", "#2171b5", "#08519c",
+    "#08306b",
+];
+
+impl Visualize for CityMap {
+    /// The heat loss of each block, with the best path from part 1.
+    fn to_svg(&self) -> Svg {
+        let mut svg = Svg::for_grid(&self.grid, |&HeatLoss(heat_loss)| {
+            HEAT_LOSS_COLORS
+                .get(usize::from(heat_loss).checked_sub(1)?)
+                .copied()
+        });
+        if let Some((_, path)) = self.best_path() {
+            svg.path(path, "red", false);
+        }
+        svg
+    }
+}
+
+impl FromStr for CityMap {
+    type Err = Day17Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::from_str(s)?;
+        Ok(Self { grid })
+    }
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+
+    type Parsed<'input> = CityMap;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(CityMap::from_str(input)?)
+    }
+
+    fn part_1(city_map: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        city_map
+            .minimum_heat_loss()
+            .map(Answer::from)
+            .ok_or_else(|| Day17Error::NoPath.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn straight_limited_to_three_steps() {
+        let city_map = CityMap::from_str("11111\n11111").unwrap();
+        let node = Node::new((0, 0), CardinalDirection::East, 0);
+        let node = node.straight(&city_map).unwrap();
+        let node = node.straight(&city_map).unwrap();
+        let node = node.straight(&city_map).unwrap();
+        assert_eq!(node.position, (0, 3));
+        assert!(node.straight(&city_map).is_none());
+        // Turning left from the top row would leave the map, so
+        // turning right is the only option left.
+        let successors = node.successors(&city_map).collect::<Vec<_>>();
+        assert_eq!(successors, [Node::new((1, 3), CardinalDirection::South, 1)]);
+    }
+
+    #[test]
+    fn best_path_runs_corner_to_corner() {
+        let input = include_str!("../../../src/inputs/day_17_test.txt");
+        let city_map = CityMap::from_str(input).unwrap();
+        let (heat_loss, path) = city_map.best_path().unwrap();
+        assert_eq!(heat_loss, 102);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&city_map.target()));
+    }
+
+    #[test]
+    fn last_frame_shows_the_best_path() {
+        let city_map = CityMap::from_str("19\n11").unwrap();
+        let frames = city_map.frames();
+        assert_eq!(frames.last().unwrap(), "*9\n**\n");
+    }
+
+    #[test]
+    fn check_day_17_test_input() {
+        let input = include_str!("../../../src/inputs/day_17_test.txt");
+        let city_map = CityMap::from_str(input).unwrap();
+        let result = city_map.minimum_heat_loss().unwrap();
+        assert_eq!(result, 102);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            CityMap::from_str("").unwrap_err(),
+            CityMap::from_str("x").unwrap_err(),
+            Day17Error::NoPath,
+        ];
+        check_codes::<_, Day17ErrorKind>(17, &examples);
+    }
+}
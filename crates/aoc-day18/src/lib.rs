@@ -0,0 +1,147 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use aoc_core::{
+    direction::CardinalDirection,
+    ipos::IPos,
+    parsing::parse_lines,
+    polygon::{interior_points, polygon_area},
+    solver::{Answer, Solver},
+};
+use miette::Diagnostic;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 18.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day18ErrorKind), derive(EnumIter))]
+pub enum Day18Error {
+    #[error("Expected a direction, distance, and color in the line {0:?}")]
+    #[diagnostic(code(aoc::day18::missing_field))]
+    MissingField(String),
+    #[error("Illegal direction {0:?}")]
+    #[diagnostic(code(aoc::day18::illegal_direction))]
+    IllegalDirection(String),
+    #[error("Illegal integer in the dig plan")]
+    #[diagnostic(code(aoc::day18::parse_int))]
+    ParseInt(#[from] ParseIntError),
+    #[error("Illegal color {0:?}")]
+    #[diagnostic(code(aoc::day18::illegal_color))]
+    IllegalColor(String),
+}
+
+fn parse_direction(s: &str) -> Result<CardinalDirection, Day18Error> {
+    Ok(match s {
+        "U" => CardinalDirection::North,
+        "D" => CardinalDirection::South,
+        "R" => CardinalDirection::East,
+        "L" => CardinalDirection::West,
+        _ => return Err(Day18Error::IllegalDirection(s.to_string())),
+    })
+}
+
+#[derive(Debug)]
+struct Color(#[allow(dead_code)] u32);
+
+impl FromStr for Color {
+    type Err = Day18Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s
+            .strip_prefix("(#")
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or_else(|| Day18Error::IllegalColor(s.to_string()))?;
+        Ok(Self(u32::from_str_radix(hex, 16)?))
+    }
+}
+
+#[derive(Debug)]
+struct DigStep {
+    direction: CardinalDirection,
+    distance: u32,
+    #[allow(dead_code)]
+    color: Color,
+}
+
+impl FromStr for DigStep {
+    type Err = Day18Error;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.split_ascii_whitespace();
+        let (Some(direction), Some(distance), Some(color), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(Day18Error::MissingField(line.to_string()));
+        };
+        Ok(Self {
+            direction: parse_direction(direction)?,
+            distance: distance.parse()?,
+            color: color.parse()?,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DigPlan {
+    steps: Vec<DigStep>,
+}
+
+impl DigPlan {
+    /// The trench is a closed polygon, so we can use the shoelace formula to
+    /// get its area, and then Pick's theorem (`A = i + b/2 - 1`) to get the
+    /// number of interior points `i`. Each step of the trench digs out
+    /// `distance` cubes, so the boundary `b` is the total distance, and the
+    /// volume of the lagoon is `i + b`.
+    fn lagoon_volume(&self) -> u64 {
+        let corners = self.steps.iter().scan(IPos::ORIGIN, |position, step| {
+            *position = position.offset(step.direction, i64::from(step.distance));
+            Some(*position)
+        });
+        let boundary_len = self.steps.iter().map(|step| u64::from(step.distance)).sum();
+        interior_points(polygon_area(corners), boundary_len) + boundary_len
+    }
+}
+
+impl FromStr for DigPlan {
+    type Err = Day18Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let steps = parse_lines(s)?;
+        Ok(Self { steps })
+    }
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+
+    type Parsed<'input> = DigPlan;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(DigPlan::from_str(input)?)
+    }
+
+    fn part_1(dig_plan: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(dig_plan.lagoon_volume().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_day_18_test_input() {
+        let input = include_str!("../../../src/inputs/day_18_test.txt");
+        let dig_plan = DigPlan::from_str(input).unwrap();
+        let result = dig_plan.lagoon_volume();
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = ["R 6", "X 6 (#70c710)", "R x (#70c710)", "R 6 70c710"]
+            .map(|input| DigPlan::from_str(input).unwrap_err());
+        check_codes::<_, Day18ErrorKind>(18, &examples);
+    }
+}
@@ -0,0 +1,302 @@
+use std::{collections::HashMap, num::ParseIntError, ops::Range, str::FromStr};
+
+use aoc_core::solver::{Answer, Solver};
+use miette::Diagnostic;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 19.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day19ErrorKind), derive(EnumIter))]
+pub enum Day19Error {
+    #[error("Workflow line isn't of the form `name{{rules}}`: {0:?}")]
+    #[diagnostic(code(aoc::day19::illegal_workflow))]
+    IllegalWorkflow(String),
+    #[error("Workflow {0:?} has no fallback target")]
+    #[diagnostic(code(aoc::day19::missing_fallback))]
+    MissingFallback(String),
+    #[error("Rule isn't of the form `<category><comparison><value>:<target>`: {0:?}")]
+    #[diagnostic(code(aoc::day19::illegal_rule))]
+    IllegalRule(String),
+    #[error("Illegal rating category {0:?}")]
+    #[diagnostic(code(aoc::day19::illegal_category))]
+    IllegalCategory(char),
+    #[error("Illegal comparison {0:?}")]
+    #[diagnostic(code(aoc::day19::illegal_comparison))]
+    IllegalComparison(char),
+    #[error("Illegal integer in a rule")]
+    #[diagnostic(code(aoc::day19::parse_int))]
+    ParseInt(#[from] ParseIntError),
+    #[error("Reference to unknown workflow {0:?}")]
+    #[diagnostic(
+        code(aoc::day19::unknown_workflow),
+        help("Every target has to be `A`, `R`, or the name of a workflow")
+    )]
+    UnknownWorkflow(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Category {
+    type Error = Day19Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            'x' => Self::X,
+            'm' => Self::M,
+            'a' => Self::A,
+            's' => Self::S,
+            _ => return Err(Day19Error::IllegalCategory(c)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Comparison {
+    LessThan,
+    GreaterThan,
+}
+
+impl TryFrom<char> for Comparison {
+    type Error = Day19Error;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            '<' => Self::LessThan,
+            '>' => Self::GreaterThan,
+            _ => return Err(Day19Error::IllegalComparison(c)),
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Self::Accept,
+            "R" => Self::Reject,
+            name => Self::Workflow(name.to_string()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Rule {
+    category: Category,
+    comparison: Comparison,
+    value: u64,
+    target: Target,
+}
+
+impl FromStr for Rule {
+    type Err = Day19Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let illegal_rule = || Day19Error::IllegalRule(s.to_string());
+        let (condition, target) = s.split_once(':').ok_or_else(illegal_rule)?;
+        let mut chars = condition.chars();
+        let category = chars.next().ok_or_else(illegal_rule)?.try_into()?;
+        let comparison = chars.next().ok_or_else(illegal_rule)?.try_into()?;
+        let value = chars.as_str().parse()?;
+        Ok(Self {
+            category,
+            comparison,
+            value,
+            target: target.into(),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Workflow {
+    rules: Vec<Rule>,
+    fallback: Target,
+}
+
+/// A set of parts, represented by an (exclusive) range of ratings
+/// for each of the four categories.
+#[derive(Debug, Clone)]
+struct RatingRanges([Range<u64>; 4]);
+
+impl RatingRanges {
+    const fn all() -> Self {
+        Self([1..4001, 1..4001, 1..4001, 1..4001])
+    }
+
+    fn num_combinations(&self) -> u64 {
+        self.0.iter().map(|range| range.end - range.start).product()
+    }
+
+    /// Split these ranges into the part that matches `rule` and the part
+    /// that doesn't. Either of those can be empty, in which case we
+    /// return `None` for that half.
+    fn split(self, rule: &Rule) -> (Option<Self>, Option<Self>) {
+        let index = rule.category as usize;
+        let Range { start, end } = self.0[index];
+        let (matching, rest) = match rule.comparison {
+            Comparison::LessThan => (start..end.min(rule.value), start.max(rule.value)..end),
+            Comparison::GreaterThan => (
+                start.max(rule.value + 1)..end,
+                start..end.min(rule.value + 1),
+            ),
+        };
+        let with_range = |range: Range<u64>| {
+            (!range.is_empty()).then(|| {
+                let mut ranges = self.clone();
+                ranges.0[index] = range;
+                ranges
+            })
+        };
+        (with_range(matching), with_range(rest))
+    }
+}
+
+#[derive(Debug)]
+pub struct System {
+    workflows: HashMap<String, Workflow>,
+}
+
+impl System {
+    fn num_accepted_combinations(&self) -> Result<u64, Day19Error> {
+        self.accepted_combinations(&Target::Workflow("in".to_string()), RatingRanges::all())
+    }
+
+    /// Push `ranges` through the workflow graph starting at `target`,
+    /// splitting them at each rule, and count how many of the
+    /// combinations end up accepted.
+    fn accepted_combinations(
+        &self,
+        target: &Target,
+        ranges: RatingRanges,
+    ) -> Result<u64, Day19Error> {
+        let name = match target {
+            Target::Accept => return Ok(ranges.num_combinations()),
+            Target::Reject => return Ok(0),
+            Target::Workflow(name) => name,
+        };
+        let workflow = self
+            .workflows
+            .get(name)
+            .ok_or_else(|| Day19Error::UnknownWorkflow(name.clone()))?;
+
+        let mut total = 0;
+        let mut remaining = ranges;
+        for rule in &workflow.rules {
+            let (matching, rest) = remaining.split(rule);
+            if let Some(matching) = matching {
+                total += self.accepted_combinations(&rule.target, matching)?;
+            }
+            match rest {
+                Some(rest) => remaining = rest,
+                // Everything matched this rule, so none of the later rules apply.
+                None => return Ok(total),
+            }
+        }
+        Ok(total + self.accepted_combinations(&workflow.fallback, remaining)?)
+    }
+}
+
+fn parse_workflow(line: &str) -> Result<(String, Workflow), Day19Error> {
+    let (name, rules) = line
+        .strip_suffix('}')
+        .and_then(|line| line.split_once('{'))
+        .ok_or_else(|| Day19Error::IllegalWorkflow(line.to_string()))?;
+    let mut rules = rules.split(',').collect::<Vec<_>>();
+    let fallback = rules
+        .pop()
+        .filter(|fallback| !fallback.contains(':'))
+        .ok_or_else(|| Day19Error::MissingFallback(name.to_string()))?;
+    let rules = rules
+        .into_iter()
+        .map(Rule::from_str)
+        .collect::<Result<_, _>>()?;
+    Ok((
+        name.to_string(),
+        Workflow {
+            rules,
+            fallback: fallback.into(),
+        },
+    ))
+}
+
+impl FromStr for System {
+    type Err = Day19Error;
+
+    /// Part 2 doesn't care about the list of parts, so we only
+    /// parse the workflows that come before the blank line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let workflows = s
+            .lines()
+            .take_while(|line| !line.is_empty())
+            .map(parse_workflow)
+            .collect::<Result<_, _>>()?;
+        Ok(Self { workflows })
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+
+    type Parsed<'input> = System;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(System::from_str(input)?)
+    }
+
+    fn part_2(system: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(system.num_accepted_combinations()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn split_on_less_than() {
+        let rule = Rule::from_str("a<2006:qkq").unwrap();
+        let (matching, rest) = RatingRanges::all().split(&rule);
+        assert_eq!(matching.unwrap().0[Category::A as usize], 1..2006);
+        assert_eq!(rest.unwrap().0[Category::A as usize], 2006..4001);
+    }
+
+    #[test]
+    fn check_day_19_test_input() {
+        let input = include_str!("../../../src/inputs/day_19_test.txt");
+        let system = System::from_str(input).unwrap();
+        let result = system.num_accepted_combinations().unwrap();
+        assert_eq!(result, 167_409_079_868_000);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let mut examples = [
+            "in",
+            "in{a<1:A}",
+            "in{a<1,A}",
+            "in{q<1:A,A}",
+            "in{a=1:A,A}",
+            "in{a<x:A,A}",
+        ]
+        .into_iter()
+        .map(|input| System::from_str(input).unwrap_err())
+        .collect::<Vec<_>>();
+        let system = System::from_str("in{qq}").unwrap();
+        examples.push(system.num_accepted_combinations().unwrap_err());
+        check_codes::<_, Day19ErrorKind>(19, &examples);
+    }
+}
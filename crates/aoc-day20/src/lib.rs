@@ -0,0 +1,279 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+
+use aoc_core::solver::{Answer, Solver};
+use miette::Diagnostic;
+use num::Integer;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 20.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day20ErrorKind), derive(EnumIter))]
+pub enum Day20Error {
+    #[error("Module line isn't of the form `name -> outputs`: {0:?}")]
+    #[diagnostic(code(aoc::day20::illegal_module))]
+    IllegalModule(String),
+    #[error("Expected exactly one module sending to `rx`, but found {0:?}")]
+    #[diagnostic(
+        code(aoc::day20::not_single_feeder),
+        help("This solution assumes `rx` is fed by a single conjunction module")
+    )]
+    NotSingleFeeder(Vec<String>),
+    #[error("The module {0:?} feeding `rx` isn't a conjunction")]
+    #[diagnostic(code(aoc::day20::feeder_not_conjunction))]
+    FeederNotConjunction(String),
+    #[error("Not every input to {feeder:?} sent a high pulse within {num_presses} presses")]
+    #[diagnostic(code(aoc::day20::no_cycle_found))]
+    NoCycleFound { feeder: String, num_presses: usize },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pulse {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+}
+
+#[derive(Debug)]
+struct Module {
+    kind: ModuleKind,
+    outputs: Vec<String>,
+}
+
+/// The mutable part of the network: whether each flip-flop is on,
+/// and the most recent pulse each conjunction received from each
+/// of its inputs.
+#[derive(Debug)]
+struct State<'a> {
+    flip_flops: HashMap<&'a str, bool>,
+    conjunctions: HashMap<&'a str, HashMap<&'a str, Pulse>>,
+}
+
+#[derive(Debug)]
+pub struct Network {
+    modules: HashMap<String, Module>,
+}
+
+/// Every input in the real puzzle cycles within a few thousand presses,
+/// so this is just a guard against looping forever on a bad input.
+const MAX_PRESSES: usize = 1_000_000;
+
+impl Network {
+    fn inputs_to<'a>(&'a self, target: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.modules
+            .iter()
+            .filter(move |(_, module)| module.outputs.iter().any(|output| output == target))
+            .map(|(name, _)| name.as_str())
+    }
+
+    fn initial_state(&self) -> State<'_> {
+        let flip_flops = self
+            .modules
+            .iter()
+            .filter(|(_, module)| module.kind == ModuleKind::FlipFlop)
+            .map(|(name, _)| (name.as_str(), false))
+            .collect();
+        let conjunctions = self
+            .modules
+            .iter()
+            .filter(|(_, module)| module.kind == ModuleKind::Conjunction)
+            .map(|(name, _)| {
+                let memory = self.inputs_to(name).map(|input| (input, Pulse::Low));
+                (name.as_str(), memory.collect())
+            })
+            .collect();
+        State {
+            flip_flops,
+            conjunctions,
+        }
+    }
+
+    /// Press the button once, processing pulses in the order they are
+    /// sent until the network settles. `observe` is called with the
+    /// source, destination, and pulse for every pulse that's sent.
+    fn press_button<'a>(
+        &'a self,
+        state: &mut State<'a>,
+        mut observe: impl FnMut(&'a str, &'a str, Pulse),
+    ) {
+        let mut pulses = VecDeque::from([("button", "broadcaster", Pulse::Low)]);
+        while let Some((source, destination, pulse)) = pulses.pop_front() {
+            observe(source, destination, pulse);
+            let Some((name, module)) = self.modules.get_key_value(destination) else {
+                // Output-only modules like `rx` don't send anything.
+                continue;
+            };
+            let output = match module.kind {
+                ModuleKind::Broadcaster => pulse,
+                ModuleKind::FlipFlop => {
+                    if pulse == Pulse::High {
+                        continue;
+                    }
+                    let is_on = state.flip_flops.entry(name).or_default();
+                    *is_on = !*is_on;
+                    if *is_on {
+                        Pulse::High
+                    } else {
+                        Pulse::Low
+                    }
+                }
+                ModuleKind::Conjunction => {
+                    let memory = state.conjunctions.entry(name).or_default();
+                    memory.insert(source, pulse);
+                    if memory.values().all(|&p| p == Pulse::High) {
+                        Pulse::Low
+                    } else {
+                        Pulse::High
+                    }
+                }
+            };
+            pulses.extend(
+                module
+                    .outputs
+                    .iter()
+                    .map(|next| (name.as_str(), next.as_str(), output)),
+            );
+        }
+    }
+
+    /// `rx` is fed by a single conjunction, so it gets a low pulse when every
+    /// input to that conjunction has most recently sent it a high pulse. Each of
+    /// those inputs is driven by an independent counter that sends a single
+    /// high pulse every `n` presses, so the answer is the LCM of the number of
+    /// presses until each input first sends a high pulse.
+    fn presses_to_activate_rx(&self) -> Result<usize, Day20Error> {
+        let feeders = self.inputs_to("rx").collect::<Vec<_>>();
+        let [feeder] = feeders[..] else {
+            return Err(Day20Error::NotSingleFeeder(
+                feeders.into_iter().map(ToString::to_string).collect(),
+            ));
+        };
+        if self.modules[feeder].kind != ModuleKind::Conjunction {
+            return Err(Day20Error::FeederNotConjunction(feeder.to_string()));
+        }
+
+        let mut cycle_lengths: HashMap<&str, Option<usize>> =
+            self.inputs_to(feeder).map(|input| (input, None)).collect();
+        let mut state = self.initial_state();
+        for num_presses in 1..=MAX_PRESSES {
+            self.press_button(&mut state, |source, destination, pulse| {
+                if destination == feeder && pulse == Pulse::High {
+                    if let Some(cycle_length @ None) = cycle_lengths.get_mut(source) {
+                        *cycle_length = Some(num_presses);
+                    }
+                }
+            });
+            if let Some(lengths) = cycle_lengths.values().copied().collect::<Option<Vec<_>>>() {
+                return Ok(lengths.into_iter().fold(1, |acc, length| acc.lcm(&length)));
+            }
+        }
+        Err(Day20Error::NoCycleFound {
+            feeder: feeder.to_string(),
+            num_presses: MAX_PRESSES,
+        })
+    }
+}
+
+impl FromStr for Network {
+    type Err = Day20Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let modules = s
+            .lines()
+            .map(|line| {
+                let (name, outputs) = line
+                    .split_once(" -> ")
+                    .ok_or_else(|| Day20Error::IllegalModule(line.to_string()))?;
+                let (kind, name) = if let Some(name) = name.strip_prefix('%') {
+                    (ModuleKind::FlipFlop, name)
+                } else if let Some(name) = name.strip_prefix('&') {
+                    (ModuleKind::Conjunction, name)
+                } else if name == "broadcaster" {
+                    (ModuleKind::Broadcaster, name)
+                } else {
+                    return Err(Day20Error::IllegalModule(line.to_string()));
+                };
+                let outputs = outputs.split(", ").map(ToString::to_string).collect();
+                Ok((name.to_string(), Module { kind, outputs }))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { modules })
+    }
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+
+    type Parsed<'input> = Network;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Network::from_str(input)?)
+    }
+
+    fn part_2(network: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(network.presses_to_activate_rx()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    /// The test input has two counters, with cycles of 5 and 7 presses,
+    /// feeding the conjunction that sends to `rx`.
+    #[test]
+    fn check_day_20_test_input() {
+        let input = include_str!("../../../src/inputs/day_20_test.txt");
+        let network = Network::from_str(input).unwrap();
+        let result = network.presses_to_activate_rx().unwrap();
+        assert_eq!(result, 35);
+    }
+
+    #[test]
+    fn lcm_matches_simulation() {
+        let input = include_str!("../../../src/inputs/day_20_test.txt");
+        let network = Network::from_str(input).unwrap();
+        let mut state = network.initial_state();
+        let mut rx_activated = false;
+        let num_presses = (1..=MAX_PRESSES)
+            .find(|_| {
+                network.press_button(&mut state, |_, destination, pulse| {
+                    rx_activated |= destination == "rx" && pulse == Pulse::Low;
+                });
+                rx_activated
+            })
+            .unwrap();
+        assert_eq!(num_presses, 35);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let press = |input: &str| {
+            Network::from_str(input)
+                .unwrap()
+                .presses_to_activate_rx()
+                .unwrap_err()
+        };
+        let examples = [
+            Network::from_str("x").unwrap_err(),
+            press("broadcaster -> a"),
+            press("broadcaster -> rx"),
+            Day20Error::NoCycleFound {
+                feeder: "c".to_string(),
+                num_presses: MAX_PRESSES,
+            },
+        ];
+        check_codes::<_, Day20ErrorKind>(20, &examples);
+    }
+}
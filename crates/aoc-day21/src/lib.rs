@@ -0,0 +1,220 @@
+use aoc_core::{
+    direction::CardinalDirection,
+    errors::GridError,
+    grid::{find_all, parse_grid},
+    ipos::IPos,
+    solver::{Answer, Solver},
+};
+use miette::Diagnostic;
+use ndarray::Array2;
+use std::{
+    collections::{HashMap, VecDeque},
+    str::FromStr,
+};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 21.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day21ErrorKind), derive(EnumIter))]
+pub enum Day21Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day21::grid))]
+    Grid(#[from] GridError),
+
+    #[error("Illegal map character {0}")]
+    #[diagnostic(code(aoc::day21::illegal_tile))]
+    IllegalTile(char),
+
+    #[error("Expected exactly one starting position `S` but found {0}")]
+    #[diagnostic(code(aoc::day21::start_count))]
+    StartCount(usize),
+
+    #[error("The map is {num_rows}x{num_columns}, but it needs to be square")]
+    #[diagnostic(
+        code(aoc::day21::not_square),
+        help("The extrapolation relies on the map repeating every N steps in both directions")
+    )]
+    NotSquare { num_rows: usize, num_columns: usize },
+}
+
+/// Part 1 walks on just the map we're given, while part 2 walks
+/// on infinitely many copies of it tiled in every direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tiling {
+    Single,
+    Infinite,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Plot,
+    Rock,
+}
+
+#[derive(Debug)]
+pub struct Garden {
+    array: Array2<Tile>,
+    start: IPos,
+}
+
+impl Garden {
+    /// The (square) map's side length as an `i64` so it can be mixed with positions.
+    fn size(&self) -> i64 {
+        i64::try_from(self.array.nrows()).expect("the map should fit in an i64")
+    }
+
+    /// Positions on the infinitely tiled map can be negative and can run
+    /// off the edges of the original map, so we wrap them back onto it.
+    fn tile(&self, position: IPos, tiling: Tiling) -> Tile {
+        let size = self.size();
+        if tiling == Tiling::Single
+            && !((0..size).contains(&position.row()) && (0..size).contains(&position.col()))
+        {
+            // Off the edge of the map is as good as a rock.
+            return Tile::Rock;
+        }
+        self.array[position.wrap(size, size)]
+    }
+
+    fn neighbors(&self, position: IPos, tiling: Tiling) -> impl Iterator<Item = IPos> + '_ {
+        CardinalDirection::ALL
+            .into_iter()
+            .map(move |direction| position + direction)
+            .filter(move |&neighbor| self.tile(neighbor, tiling) == Tile::Plot)
+    }
+
+    /// The elf can always step back and forth between two plots, so a plot
+    /// can be reached in exactly `num_steps` steps if and only if its BFS
+    /// distance is at most `num_steps` and has the same parity. We stop the
+    /// search at `num_steps` since the infinitely tiled map never ends.
+    fn num_reachable_plots(&self, num_steps: usize, tiling: Tiling) -> usize {
+        let mut distances = HashMap::from([(self.start, 0)]);
+        let mut frontier = VecDeque::from([(self.start, 0)]);
+        while let Some((position, distance)) = frontier.pop_front() {
+            if distance == num_steps {
+                continue;
+            }
+            for neighbor in self.neighbors(position, tiling) {
+                distances.entry(neighbor).or_insert_with(|| {
+                    frontier.push_back((neighbor, distance + 1));
+                    distance + 1
+                });
+            }
+        }
+        distances
+            .values()
+            .filter(|&&distance| distance % 2 == num_steps % 2)
+            .count()
+    }
+
+    /// The real input has an empty border and an empty row and column
+    /// through the start, so the frontier expands at full speed across
+    /// whole copies of the map. Sampling the counts after `r`, `r + N`,
+    /// and `r + 2N` steps (where `N` is the map size and `r` is
+    /// `num_steps % N`) gives three points on a quadratic in the number
+    /// of maps crossed, which we extrapolate using Newton's forward
+    /// differences.
+    fn extrapolate_reachable_plots(&self, num_steps: usize) -> usize {
+        let size = self.array.nrows();
+        let remainder = num_steps % size;
+        let num_maps = num_steps / size;
+        let [y0, y1, y2] =
+            [0, 1, 2].map(|k| self.num_reachable_plots(remainder + k * size, Tiling::Infinite));
+        let first_difference = y1 - y0;
+        let second_difference = y2 + y0 - 2 * y1;
+        y0 + num_maps * first_difference
+            + num_maps * num_maps.saturating_sub(1) / 2 * second_difference
+    }
+}
+
+impl FromStr for Garden {
+    type Err = Day21Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let array = parse_grid(s, |c| match c {
+            '.' | 'S' => Ok(Tile::Plot),
+            '#' => Ok(Tile::Rock),
+            _ => Err(Day21Error::IllegalTile(c)),
+        })?;
+        let (num_rows, num_columns) = array.dim();
+        if num_rows != num_columns {
+            return Err(Day21Error::NotSquare {
+                num_rows,
+                num_columns,
+            });
+        }
+        let starts = find_all(s, 'S').collect::<Vec<_>>();
+        let [start] = starts[..] else {
+            return Err(Day21Error::StartCount(starts.len()));
+        };
+        let start = IPos::try_from(start).expect("the map should fit in an i64");
+        Ok(Self { array, start })
+    }
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    const DAY: u8 = 21;
+
+    type Parsed<'input> = Garden;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Garden::from_str(input)?)
+    }
+
+    fn part_1(garden: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(garden.num_reachable_plots(64, Tiling::Single).into())
+    }
+
+    fn part_2(garden: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(garden.extrapolate_reachable_plots(26_501_365).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+    use test_case::test_case;
+
+    #[test_case(6, Tiling::Single, 16)]
+    #[test_case(6, Tiling::Infinite, 16)]
+    #[test_case(10, Tiling::Infinite, 50)]
+    #[test_case(50, Tiling::Infinite, 1594)]
+    #[test_case(100, Tiling::Infinite, 6536)]
+    fn check_day_21_test_input(num_steps: usize, tiling: Tiling, expected: usize) {
+        let input = include_str!("../../../src/inputs/day_21_test.txt");
+        let garden = Garden::from_str(input).unwrap();
+        let result = garden.num_reachable_plots(num_steps, tiling);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn single_map_stops_at_the_edges() {
+        let garden = Garden::from_str("...\n.S.\n...").unwrap();
+        assert_eq!(garden.num_reachable_plots(10, Tiling::Single), 5);
+        assert_eq!(garden.num_reachable_plots(2, Tiling::Infinite), 9);
+    }
+
+    /// The puzzle's sample doesn't have the clear rows and columns that
+    /// the extrapolation depends on, so this uses a map that does.
+    #[test]
+    fn extrapolation_matches_simulation() {
+        let input = include_str!("../../../src/inputs/day_21_part_2_test.txt");
+        let garden = Garden::from_str(input).unwrap();
+        let num_steps = 5 + 7 * 11;
+        let result = garden.extrapolate_reachable_plots(num_steps);
+        assert_eq!(
+            result,
+            garden.num_reachable_plots(num_steps, Tiling::Infinite)
+        );
+        assert_eq!(result, 5290);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = ["", "x", ".", "S."].map(|input| Garden::from_str(input).unwrap_err());
+        check_codes::<_, Day21ErrorKind>(21, &examples);
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use aoc_core::{
+    parsing::parse_lines,
+    solver::{Answer, Solver},
+};
+use miette::Diagnostic;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 22.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day22ErrorKind), derive(EnumIter))]
+pub enum Day22Error {
+    #[error("Brick line isn't of the form `x,y,z~x,y,z`: {0:?}")]
+    #[diagnostic(code(aoc::day22::illegal_brick))]
+    IllegalBrick(String),
+    #[error("Illegal integer in a brick")]
+    #[diagnostic(code(aoc::day22::parse_int))]
+    ParseInt(#[from] ParseIntError),
+    #[error("Brick's end {end:?} comes before its start {start:?}")]
+    #[diagnostic(
+        code(aoc::day22::reversed),
+        help("This solution assumes each brick's first corner is its lowest one")
+    )]
+    Reversed { start: [u32; 3], end: [u32; 3] },
+}
+
+#[derive(Debug, Clone)]
+struct Brick {
+    start: [u32; 3],
+    end: [u32; 3],
+}
+
+impl Brick {
+    const fn bottom(&self) -> u32 {
+        self.start[2]
+    }
+
+    const fn height(&self) -> u32 {
+        self.end[2] - self.start[2] + 1
+    }
+
+    /// The `(x, y)` cells this brick covers when seen from above.
+    fn footprint(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        (self.start[0]..=self.end[0])
+            .flat_map(move |x| (self.start[1]..=self.end[1]).map(move |y| (x, y)))
+    }
+}
+
+fn parse_corner(s: &str) -> Result<[u32; 3], Day22Error> {
+    let coordinates = s
+        .split(',')
+        .map(u32::from_str)
+        .collect::<Result<Vec<_>, _>>()?;
+    coordinates
+        .try_into()
+        .map_err(|_| Day22Error::IllegalBrick(s.to_string()))
+}
+
+impl FromStr for Brick {
+    type Err = Day22Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end) = s
+            .split_once('~')
+            .ok_or_else(|| Day22Error::IllegalBrick(s.to_string()))?;
+        let (start, end) = (parse_corner(start)?, parse_corner(end)?);
+        if start.iter().zip(&end).any(|(s, e)| s > e) {
+            return Err(Day22Error::Reversed { start, end });
+        }
+        Ok(Self { start, end })
+    }
+}
+
+#[derive(Debug)]
+pub struct Snapshot {
+    bricks: Vec<Brick>,
+}
+
+impl Snapshot {
+    /// The indices of the bricks, sorted from the lowest to the highest.
+    /// A brick can only come to rest on bricks that are earlier in this
+    /// order.
+    fn settling_order(&self) -> Vec<usize> {
+        let mut order = (0..self.bricks.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| self.bricks[index].bottom());
+        order
+    }
+
+    /// Let every brick fall as far as it can, lowest bricks first, and
+    /// return, for each brick, the set of bricks it ends up resting on.
+    /// We only need to track the tallest brick in each `(x, y)` column,
+    /// since a falling brick stops on the highest thing below its
+    /// footprint.
+    fn settle(&self) -> Vec<BTreeSet<usize>> {
+        let mut tops: HashMap<(u32, u32), (u32, usize)> = HashMap::new();
+        let mut supported_by = vec![BTreeSet::new(); self.bricks.len()];
+        for index in self.settling_order() {
+            let brick = &self.bricks[index];
+            let resting_height = brick
+                .footprint()
+                .filter_map(|cell| tops.get(&cell).map(|&(height, _)| height))
+                .max()
+                .unwrap_or(0);
+            for cell in brick.footprint() {
+                if let Some(&(height, below)) = tops.get(&cell) {
+                    if height == resting_height {
+                        supported_by[index].insert(below);
+                    }
+                }
+                tops.insert(cell, (resting_height + brick.height(), index));
+            }
+        }
+        supported_by
+    }
+
+    /// A brick can be safely disintegrated if it isn't the only
+    /// support for any other brick.
+    fn num_safe_to_disintegrate(&self) -> usize {
+        let supported_by = self.settle();
+        (0..self.bricks.len())
+            .filter(|&index| {
+                !supported_by
+                    .iter()
+                    .any(|supports| supports.len() == 1 && supports.contains(&index))
+            })
+            .count()
+    }
+
+    /// Removing a brick makes every brick that's only supported by fallen
+    /// (or removed) bricks fall too. Going through the bricks in settling
+    /// order means we've decided whether each brick's supports fall
+    /// before we get to it.
+    fn num_falling_if_removed(
+        order: &[usize],
+        supported_by: &[BTreeSet<usize>],
+        removed: usize,
+    ) -> usize {
+        let mut fallen = BTreeSet::from([removed]);
+        for &index in order {
+            let supports = &supported_by[index];
+            if !supports.is_empty() && supports.is_subset(&fallen) {
+                fallen.insert(index);
+            }
+        }
+        // The removed brick doesn't count as falling.
+        fallen.len() - 1
+    }
+
+    fn total_falling(&self) -> usize {
+        let supported_by = self.settle();
+        let order = self.settling_order();
+        (0..self.bricks.len())
+            .map(|removed| Self::num_falling_if_removed(&order, &supported_by, removed))
+            .sum()
+    }
+}
+
+impl FromStr for Snapshot {
+    type Err = Day22Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bricks = parse_lines(s)?;
+        Ok(Self { bricks })
+    }
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY: u8 = 22;
+
+    type Parsed<'input> = Snapshot;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Snapshot::from_str(input)?)
+    }
+
+    fn part_1(snapshot: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(snapshot.num_safe_to_disintegrate().into())
+    }
+
+    fn part_2(snapshot: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(snapshot.total_falling().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn check_day_22_test_input() {
+        let input = include_str!("../../../src/inputs/day_22_test.txt");
+        let snapshot = Snapshot::from_str(input).unwrap();
+        assert_eq!(snapshot.num_safe_to_disintegrate(), 5);
+        assert_eq!(snapshot.total_falling(), 7);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = ["1,0,1", "1,0,x~1,2,1", "1,0,2~1,2,1"]
+            .map(|input| Snapshot::from_str(input).unwrap_err());
+        check_codes::<_, Day22ErrorKind>(22, &examples);
+    }
+}
@@ -0,0 +1,228 @@
+use aoc_core::{
+    direction::CardinalDirection,
+    errors::GridError,
+    grid::{parse_grid, Position},
+    solver::{Answer, Solver},
+};
+use miette::Diagnostic;
+use ndarray::Array2;
+use std::{collections::HashMap, str::FromStr};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 23.
+#[derive(Debug, Diagnostic, thiserror::Error, EnumDiscriminants)]
+#[strum_discriminants(name(Day23ErrorKind), derive(EnumIter))]
+pub enum Day23Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::day23::grid))]
+    Grid(#[from] GridError),
+
+    #[error("Illegal map character {0}")]
+    #[diagnostic(code(aoc::day23::illegal_tile))]
+    IllegalTile(char),
+
+    #[error("The {0} row should have exactly one path tile")]
+    #[diagnostic(code(aoc::day23::missing_endpoint))]
+    MissingEndpoint(&'static str),
+
+    #[error("The maze has {0} junctions, but at most 64 are supported")]
+    #[diagnostic(
+        code(aoc::day23::too_many_junctions),
+        help("The search tracks visited junctions in a `u64` bit set")
+    )]
+    TooManyJunctions(usize),
+
+    #[error("There's no path from the start to the end")]
+    #[diagnostic(code(aoc::day23::no_path))]
+    NoPath,
+}
+
+/// Part 2 ignores the slopes, so they're just more path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Path,
+    Forest,
+}
+
+/// The maze contracted down to its junctions (plus the start and end),
+/// with an edge for each corridor between two of them, weighted by the
+/// corridor's length.
+#[derive(Debug)]
+struct JunctionGraph {
+    edges: Vec<Vec<(usize, usize)>>,
+    start: usize,
+    end: usize,
+}
+
+impl JunctionGraph {
+    /// Exhaustive depth-first search over the (small) junction graph,
+    /// keeping track of the junctions on the current path in a bit set.
+    fn longest_path(&self, junction: usize, visited: u64) -> Option<usize> {
+        if junction == self.end {
+            return Some(0);
+        }
+        let visited = visited | 1 << junction;
+        self.edges[junction]
+            .iter()
+            .filter(|&&(next, _)| visited & 1 << next == 0)
+            .filter_map(|&(next, length)| Some(length + self.longest_path(next, visited)?))
+            .max()
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    array: Array2<Tile>,
+    start: Position,
+    end: Position,
+}
+
+impl Maze {
+    fn neighbors(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        CardinalDirection::ALL
+            .into_iter()
+            .filter_map(move |direction| position + direction)
+            .filter(|&neighbor| self.array.get(neighbor) == Some(&Tile::Path))
+    }
+
+    /// Follow the corridor that leaves `junction` through `first_step`
+    /// until it reaches another junction, returning that junction and the
+    /// corridor's length. Returns `None` for dead ends.
+    fn follow_corridor(
+        &self,
+        junction: Position,
+        first_step: Position,
+        junctions: &HashMap<Position, usize>,
+    ) -> Option<(usize, usize)> {
+        let mut previous = junction;
+        let mut current = first_step;
+        let mut length = 1;
+        loop {
+            if let Some(&index) = junctions.get(&current) {
+                return Some((index, length));
+            }
+            let next = self.neighbors(current).find(|&next| next != previous)?;
+            previous = current;
+            current = next;
+            length += 1;
+        }
+    }
+
+    fn junction_graph(&self) -> Result<JunctionGraph, Day23Error> {
+        let junctions = [self.start, self.end]
+            .into_iter()
+            .chain(
+                self.array
+                    .indexed_iter()
+                    .filter(|&(position, &tile)| {
+                        tile == Tile::Path && self.neighbors(position).count() > 2
+                    })
+                    .map(|(position, _)| position),
+            )
+            .enumerate()
+            .map(|(index, position)| (position, index))
+            .collect::<HashMap<_, _>>();
+        if junctions.len() > 64 {
+            return Err(Day23Error::TooManyJunctions(junctions.len()));
+        }
+
+        let mut edges = vec![Vec::new(); junctions.len()];
+        for (&position, &index) in &junctions {
+            edges[index] = self
+                .neighbors(position)
+                .filter_map(|first_step| self.follow_corridor(position, first_step, &junctions))
+                .collect();
+        }
+        Ok(JunctionGraph {
+            edges,
+            start: junctions[&self.start],
+            end: junctions[&self.end],
+        })
+    }
+
+    fn longest_hike(&self) -> Result<Option<usize>, Day23Error> {
+        let graph = self.junction_graph()?;
+        Ok(graph.longest_path(graph.start, 0))
+    }
+}
+
+impl FromStr for Maze {
+    type Err = Day23Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let array = parse_grid(s, |c| match c {
+            '.' | '^' | 'v' | '<' | '>' => Ok(Tile::Path),
+            '#' => Ok(Tile::Forest),
+            _ => Err(Day23Error::IllegalTile(c)),
+        })?;
+        let num_rows = array.nrows();
+
+        let only_path = |row: usize, name| {
+            let cols = array
+                .row(row)
+                .indexed_iter()
+                .filter(|&(_, &tile)| tile == Tile::Path)
+                .map(|(col, _)| col)
+                .collect::<Vec<_>>();
+            match cols[..] {
+                [col] => Ok((row, col)),
+                _ => Err(Day23Error::MissingEndpoint(name)),
+            }
+        };
+        let start = only_path(0, "first")?;
+        let end = only_path(num_rows - 1, "last")?;
+        Ok(Self { array, start, end })
+    }
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY: u8 = 23;
+
+    type Parsed<'input> = Maze;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Maze::from_str(input)?)
+    }
+
+    fn part_2(maze: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        maze.longest_hike()?
+            .map(Answer::from)
+            .ok_or_else(|| Day23Error::NoPath.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn corridors_contract_to_single_edge() {
+        let maze = Maze::from_str("#.###\n#...#\n###.#").unwrap();
+        let graph = maze.junction_graph().unwrap();
+        assert_eq!(graph.edges[graph.start], [(graph.end, 4)]);
+        assert_eq!(maze.longest_hike().unwrap(), Some(4));
+    }
+
+    #[test]
+    fn check_day_23_test_input() {
+        let input = include_str!("../../../src/inputs/day_23_test.txt");
+        let maze = Maze::from_str(input).unwrap();
+        let result = maze.longest_hike().unwrap();
+        assert_eq!(result, Some(154));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Maze::from_str("").unwrap_err(),
+            Maze::from_str("x").unwrap_err(),
+            Maze::from_str("#").unwrap_err(),
+            Day23Error::TooManyJunctions(65),
+            Day23Error::NoPath,
+        ];
+        check_codes::<_, Day23ErrorKind>(23, &examples);
+    }
+}
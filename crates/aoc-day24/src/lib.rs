@@ -0,0 +1,225 @@
+use std::{num::ParseIntError, str::FromStr};
+
+use aoc_core::{
+    parsing::parse_lines,
+    solver::{Answer, Solver},
+};
+use itertools::Itertools;
+use miette::Diagnostic;
+use num::{BigInt, BigRational, Zero};
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 24.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day24ErrorKind), derive(EnumIter))]
+pub enum Day24Error {
+    #[error("Hailstone line isn't of the form `px, py, pz @ vx, vy, vz`: {0:?}")]
+    #[diagnostic(code(aoc::day24::illegal_hailstone))]
+    IllegalHailstone(String),
+    #[error("Illegal integer in a hailstone")]
+    #[diagnostic(code(aoc::day24::parse_int))]
+    ParseInt(#[from] ParseIntError),
+    #[error("No three hailstones gave a system with a unique solution")]
+    #[diagnostic(code(aoc::day24::no_unique_solution))]
+    NoUniqueSolution,
+    #[error("The rock's position {0:?} isn't a whole number")]
+    #[diagnostic(code(aoc::day24::non_integer_position))]
+    NonIntegerPosition(Vec<BigRational>),
+}
+
+type Vector = [i64; 3];
+
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    position: Vector,
+    velocity: Vector,
+}
+
+fn parse_vector(s: &str) -> Result<Vector, Day24Error> {
+    let components = s
+        .split(',')
+        .map(|component| component.trim().parse())
+        .collect::<Result<Vec<_>, _>>()?;
+    components
+        .try_into()
+        .map_err(|_| Day24Error::IllegalHailstone(s.to_string()))
+}
+
+impl FromStr for Hailstone {
+    type Err = Day24Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s
+            .split_once('@')
+            .ok_or_else(|| Day24Error::IllegalHailstone(s.to_string()))?;
+        Ok(Self {
+            position: parse_vector(position)?,
+            velocity: parse_vector(velocity)?,
+        })
+    }
+}
+
+fn sub(a: Vector, b: Vector) -> [i128; 3] {
+    [0, 1, 2].map(|i| i128::from(a[i]) - i128::from(b[i]))
+}
+
+fn cross(a: Vector, b: Vector) -> [i128; 3] {
+    let [a0, a1, a2] = a.map(i128::from);
+    let [b0, b1, b2] = b.map(i128::from);
+    [a1 * b2 - a2 * b1, a2 * b0 - a0 * b2, a0 * b1 - a1 * b0]
+}
+
+/// The matrix `[a]×` such that `[a]× b = a × b` for every vector `b`.
+const fn cross_matrix([a0, a1, a2]: [i128; 3]) -> [[i128; 3]; 3] {
+    [[0, -a2, a1], [a2, 0, -a0], [-a1, a0, 0]]
+}
+
+/// Solve a square linear system, given as the rows of its augmented matrix
+/// `[A | b]`, exactly using Gauss-Jordan elimination over the rationals.
+/// Returns `None` if `A` is singular.
+fn solve(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&row| !rows[row][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (row_index, row) in rows.iter_mut().enumerate() {
+            if row_index != col && !row[col].is_zero() {
+                let factor = &row[col] / &pivot_row[col];
+                for (entry, pivot_entry) in row.iter_mut().zip(&pivot_row) {
+                    *entry -= &factor * pivot_entry;
+                }
+            }
+        }
+    }
+    Some(
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, row)| &row[n] / &row[i])
+            .collect(),
+    )
+}
+
+#[derive(Debug)]
+pub struct Hailstorm {
+    hailstones: Vec<Hailstone>,
+}
+
+impl Hailstorm {
+    /// The rock at `P` with velocity `V` hits hailstone `i` exactly when
+    /// `(P - p_i) × (V - v_i) = 0`. Expanding that, the only nonlinear term
+    /// is `P × V`, which is the same for every hailstone, so subtracting the
+    /// equations for hailstones `i` and `j` gives three linear equations:
+    ///
+    /// `P × (v_j - v_i) + (p_j - p_i) × V = p_j × v_j - p_i × v_i`
+    ///
+    /// Two such pairs give six equations in the six unknowns, which we
+    /// solve exactly with rational arithmetic since the real input's
+    /// coordinates are far too big for floating point.
+    fn rock_position([a, b, c]: [Hailstone; 3]) -> Option<Vec<BigRational>> {
+        let mut rows = Vec::new();
+        for other in [b, c] {
+            let position_matrix = cross_matrix(sub(a.velocity, other.velocity));
+            let velocity_matrix = cross_matrix(sub(other.position, a.position));
+            let rhs = {
+                let (lhs, rhs) = (
+                    cross(other.position, other.velocity),
+                    cross(a.position, a.velocity),
+                );
+                [0, 1, 2].map(|i| lhs[i] - rhs[i])
+            };
+            for i in 0..3 {
+                let row = position_matrix[i]
+                    .iter()
+                    .chain(&velocity_matrix[i])
+                    .chain([&rhs[i]])
+                    .map(|&value| BigRational::from_integer(BigInt::from(value)))
+                    .collect();
+                rows.push(row);
+            }
+        }
+        let mut solution = solve(rows)?;
+        solution.truncate(3);
+        Some(solution)
+    }
+
+    fn sum_of_rock_coordinates(&self) -> Result<BigInt, Day24Error> {
+        let position = self
+            .hailstones
+            .iter()
+            .copied()
+            .tuple_combinations()
+            .find_map(|hailstones: (_, _, _)| Self::rock_position(hailstones.into()))
+            .ok_or(Day24Error::NoUniqueSolution)?;
+        if !position.iter().all(BigRational::is_integer) {
+            return Err(Day24Error::NonIntegerPosition(position));
+        }
+        Ok(position.iter().map(BigRational::to_integer).sum())
+    }
+}
+
+impl FromStr for Hailstorm {
+    type Err = Day24Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hailstones = parse_lines(s)?;
+        Ok(Self { hailstones })
+    }
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    const DAY: u8 = 24;
+
+    type Parsed<'input> = Hailstorm;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Hailstorm::from_str(input)?)
+    }
+
+    fn part_2(hailstorm: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(hailstorm.sum_of_rock_coordinates()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn rock_position_in_test_input() {
+        let input = include_str!("../../../src/inputs/day_24_test.txt");
+        let hailstorm = Hailstorm::from_str(input).unwrap();
+        let hailstones = hailstorm.hailstones[..3].try_into().unwrap();
+        let position = Hailstorm::rock_position(hailstones).unwrap();
+        let position = position
+            .iter()
+            .map(BigRational::to_integer)
+            .collect::<Vec<_>>();
+        assert_eq!(position, [24, 13, 10].map(BigInt::from));
+    }
+
+    #[test]
+    fn check_day_24_test_input() {
+        let input = include_str!("../../../src/inputs/day_24_test.txt");
+        let hailstorm = Hailstorm::from_str(input).unwrap();
+        let result = hailstorm.sum_of_rock_coordinates().unwrap();
+        assert_eq!(result, BigInt::from(47));
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let examples = [
+            Hailstorm::from_str("1, 2, 3").unwrap_err(),
+            Hailstorm::from_str("1, 2, x @ 1, 1, 1").unwrap_err(),
+            Hailstorm::from_str("1, 2, 3 @ 1, 1, 1")
+                .unwrap()
+                .sum_of_rock_coordinates()
+                .unwrap_err(),
+            Day24Error::NonIntegerPosition(vec![BigRational::new(1.into(), 2.into())]),
+        ];
+        check_codes::<_, Day24ErrorKind>(24, &examples);
+    }
+}
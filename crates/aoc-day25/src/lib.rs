@@ -0,0 +1,211 @@
+use std::{
+    collections::{BinaryHeap, HashMap},
+    str::FromStr,
+};
+
+use aoc_core::solver::{Answer, Solver};
+use miette::Diagnostic;
+use strum::{EnumDiscriminants, EnumIter};
+
+/// Everything that can go wrong on day 25.
+#[derive(Debug, thiserror::Error, Diagnostic, EnumDiscriminants)]
+#[strum_discriminants(name(Day25ErrorKind), derive(EnumIter))]
+pub enum Day25Error {
+    #[error("Wiring line isn't of the form `component: other other ...`: {0:?}")]
+    #[diagnostic(code(aoc::day25::illegal_line))]
+    IllegalLine(String),
+    #[error("The wiring diagram has fewer than two components")]
+    #[diagnostic(code(aoc::day25::too_few_components))]
+    TooFewComponents,
+    #[error("The minimum cut has {0} wires, but the puzzle promises exactly 3")]
+    #[diagnostic(
+        code(aoc::day25::unexpected_cut_size),
+        help("Check that the input is a single connected wiring diagram")
+    )]
+    UnexpectedCutSize(u64),
+}
+
+/// A minimum cut of a graph: the total weight of the edges crossing the cut,
+/// and the vertices on one side of it.
+#[derive(Debug)]
+struct MinimumCut {
+    weight: u64,
+    side: Vec<usize>,
+}
+
+/// An undirected, weighted graph on the vertices `0..n`.
+#[derive(Debug, Clone)]
+struct Graph {
+    adjacency: Vec<HashMap<usize, u64>>,
+}
+
+impl Graph {
+    const fn num_vertices(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    /// The Stoer–Wagner algorithm. Each phase does a "maximum adjacency"
+    /// search, repeatedly adding the vertex most tightly connected to the
+    /// ones added so far. The weight connecting the last vertex `t` to the
+    /// rest is a minimum cut between `t` and the second-to-last vertex `s`,
+    /// so we record it, merge `t` into `s`, and repeat until there's one
+    /// vertex left. The best cut over all the phases is a global minimum.
+    fn minimum_cut(&self) -> Option<MinimumCut> {
+        let n = self.num_vertices();
+        let mut adjacency = self.adjacency.clone();
+        let mut merged_into: Vec<Vec<usize>> = (0..n).map(|vertex| vec![vertex]).collect();
+        let mut active = vec![true; n];
+        let mut best: Option<MinimumCut> = None;
+
+        for _ in 1..n {
+            let first = active.iter().position(|&is_active| is_active)?;
+            let mut connectivity = vec![0; n];
+            let mut added = vec![false; n];
+            let mut frontier = BinaryHeap::from([(0, first)]);
+            let (mut s, mut t) = (first, first);
+            while let Some((weight, vertex)) = frontier.pop() {
+                // Skip stale heap entries and vertices we've already added.
+                if added[vertex] || weight < connectivity[vertex] {
+                    continue;
+                }
+                added[vertex] = true;
+                (s, t) = (t, vertex);
+                for (&neighbor, &edge_weight) in &adjacency[vertex] {
+                    if !added[neighbor] {
+                        connectivity[neighbor] += edge_weight;
+                        frontier.push((connectivity[neighbor], neighbor));
+                    }
+                }
+            }
+
+            if best
+                .as_ref()
+                .is_none_or(|best| connectivity[t] < best.weight)
+            {
+                best = Some(MinimumCut {
+                    weight: connectivity[t],
+                    side: merged_into[t].clone(),
+                });
+            }
+
+            // Merge `t` into `s`.
+            for (neighbor, edge_weight) in std::mem::take(&mut adjacency[t]) {
+                adjacency[neighbor].remove(&t);
+                if neighbor != s {
+                    *adjacency[s].entry(neighbor).or_default() += edge_weight;
+                    *adjacency[neighbor].entry(s).or_default() += edge_weight;
+                }
+            }
+            let vertices = std::mem::take(&mut merged_into[t]);
+            merged_into[s].extend(vertices);
+            active[t] = false;
+        }
+        best
+    }
+}
+
+#[derive(Debug)]
+pub struct Wiring {
+    graph: Graph,
+}
+
+impl Wiring {
+    fn product_of_group_sizes(&self) -> Result<usize, Day25Error> {
+        let cut = self
+            .graph
+            .minimum_cut()
+            .ok_or(Day25Error::TooFewComponents)?;
+        if cut.weight != 3 {
+            return Err(Day25Error::UnexpectedCutSize(cut.weight));
+        }
+        let group_size = cut.side.len();
+        Ok(group_size * (self.graph.num_vertices() - group_size))
+    }
+}
+
+impl FromStr for Wiring {
+    type Err = Day25Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut indices: HashMap<&str, usize> = HashMap::new();
+        let mut adjacency: Vec<HashMap<usize, u64>> = Vec::new();
+        let mut index_of = |name| {
+            *indices.entry(name).or_insert_with(|| {
+                adjacency.push(HashMap::new());
+                adjacency.len() - 1
+            })
+        };
+        let mut edges = Vec::new();
+        for line in s.lines() {
+            let (component, others) = line
+                .split_once(": ")
+                .ok_or_else(|| Day25Error::IllegalLine(line.to_string()))?;
+            let component = index_of(component);
+            for other in others.split_ascii_whitespace() {
+                edges.push((component, index_of(other)));
+            }
+        }
+        for (a, b) in edges {
+            adjacency[a].insert(b, 1);
+            adjacency[b].insert(a, 1);
+        }
+        Ok(Self {
+            graph: Graph { adjacency },
+        })
+    }
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u8 = 25;
+
+    type Parsed<'input> = Wiring;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(Wiring::from_str(input)?)
+    }
+
+    fn part_1(wiring: &Self::Parsed<'_>) -> miette::Result<Answer> {
+        Ok(wiring.product_of_group_sizes()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::errors::check_codes;
+
+    #[test]
+    fn minimum_cut_of_two_triangles() {
+        // Two triangles joined by a single edge from 2 to 3.
+        let wiring = Wiring::from_str("0: 1 2\n1: 2\n2: 3\n3: 4 5\n4: 5").unwrap();
+        let cut = wiring.graph.minimum_cut().unwrap();
+        assert_eq!(cut.weight, 1);
+        assert_eq!(cut.side.len(), 3);
+    }
+
+    #[test]
+    fn check_day_25_test_input() {
+        let input = include_str!("../../../src/inputs/day_25_test.txt");
+        let wiring = Wiring::from_str(input).unwrap();
+        let result = wiring.product_of_group_sizes().unwrap();
+        assert_eq!(result, 54);
+    }
+
+    #[test]
+    fn error_codes_follow_the_convention() {
+        let product = |input: &str| {
+            Wiring::from_str(input)
+                .unwrap()
+                .product_of_group_sizes()
+                .unwrap_err()
+        };
+        let examples = [
+            Wiring::from_str("x").unwrap_err(),
+            product(""),
+            product("a: b"),
+        ];
+        check_codes::<_, Day25ErrorKind>(25, &examples);
+    }
+}
@@ -0,0 +1,13 @@
+#![no_main]
+
+use advent_of_code_2023::{days::day_05::Day05, solver::Solver};
+use libfuzzer_sys::fuzz_target;
+
+// Bad input should come back as an error, never a panic, and anything
+// that parses should be solvable (or say why not) without panicking too.
+fuzz_target!(|input: &str| {
+    if let Ok(almanac) = Day05::parse(input) {
+        let _ = Day05::part_1(&almanac);
+        let _ = Day05::part_2(&almanac);
+    }
+});
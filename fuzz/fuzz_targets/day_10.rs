@@ -0,0 +1,9 @@
+#![no_main]
+
+use advent_of_code_2023::{days::day_10::Day10, solver::Solver};
+use libfuzzer_sys::fuzz_target;
+
+// Bad input should come back as an error, never a panic.
+fuzz_target!(|input: &str| {
+    let _ = Day10::parse(input);
+});
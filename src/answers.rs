@@ -0,0 +1,185 @@
+//! The known answers for the puzzle inputs and sample inputs in
+//! `src/inputs/`, kept in `answers.toml` so the tests and `aoc verify`
+//! check against the same list.
+
+use std::{collections::BTreeMap, str::FromStr};
+
+use miette::IntoDiagnostic;
+use serde::Deserialize;
+
+use crate::solver::{Answer, DayRun, Part, PartRun};
+
+const ANSWERS: &str = include_str!("../answers.toml");
+
+#[derive(Debug, Default, Deserialize)]
+struct InputAnswers {
+    part_1: Option<u64>,
+    part_2: Option<u64>,
+}
+
+/// The known answers for each input and part.
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    /// Keyed by the name of the input file without the `.txt`, e.g.,
+    /// `day_08` for the full input or `day_08_test_2` for a sample.
+    #[serde(flatten)]
+    inputs: BTreeMap<String, InputAnswers>,
+}
+
+impl Answers {
+    /// The answers from the `answers.toml` that was built in.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `answers.toml` isn't in the right format.
+    pub fn load() -> miette::Result<Self> {
+        ANSWERS.parse()
+    }
+
+    /// The names of every input with a known answer, in order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.inputs.keys().map(String::as_str)
+    }
+
+    /// The days whose full input has at least one known answer, in order.
+    pub fn days(&self) -> impl Iterator<Item = u8> + '_ {
+        self.inputs
+            .keys()
+            .filter_map(|name| name.strip_prefix("day_")?.parse().ok())
+    }
+
+    /// The known answer for the given day's full input.
+    #[must_use]
+    pub fn get(&self, day: u8, part: Part) -> Option<Answer> {
+        self.get_for_input(&format!("day_{day:02}"), part)
+    }
+
+    /// The known answer for the input file called `name` (without the
+    /// `.txt`).
+    #[must_use]
+    pub fn get_for_input(&self, name: &str, part: Part) -> Option<Answer> {
+        let answers = self.inputs.get(name)?;
+        match part {
+            Part::One => answers.part_1,
+            Part::Two => answers.part_2,
+        }
+        .map(Answer::from)
+    }
+
+    /// The parts of `day_run` that didn't get the known answer. Parts
+    /// we don't know the answer to are never wrong.
+    #[must_use]
+    pub fn wrong_parts<'a>(&self, day_run: &'a DayRun) -> Vec<&'a PartRun> {
+        day_run
+            .parts
+            .iter()
+            .filter(|part_run| {
+                self.get(day_run.day, part_run.part)
+                    .is_some_and(|expected| part_run.answer.as_ref().ok() != Some(&expected))
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Answers {
+    type Err = miette::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        toml::from_str(s).into_diagnostic()
+    }
+}
+
+/// The day that a sample input called `name` (like `day_08_test_2`
+/// or `day_01_part_2_test`) goes with, or `None` if it isn't a sample.
+#[must_use]
+pub fn sample_day(name: &str) -> Option<u8> {
+    let (day, rest) = name.strip_prefix("day_")?.split_once('_')?;
+    if rest.contains("test") {
+        day.parse().ok()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        days,
+        parsing::{input_path, read_input, samples_dir},
+    };
+
+    #[test]
+    fn looks_up_answers_by_day_and_part() {
+        let answers: Answers = "[day_07]\npart_2 = 1_234".parse().unwrap();
+        assert_eq!(answers.days().collect::<Vec<_>>(), [7]);
+        assert_eq!(answers.get(7, Part::One), None);
+        assert_eq!(answers.get(7, Part::Two), Some(Answer::from(1234_u64)));
+        assert_eq!(answers.get(8, Part::Two), None);
+    }
+
+    #[test]
+    fn recognizes_sample_inputs() {
+        assert_eq!(sample_day("day_08_test_2"), Some(8));
+        assert_eq!(sample_day("day_01_part_2_test"), Some(1));
+        assert_eq!(sample_day("day_08"), None);
+        assert_eq!(sample_day("answers"), None);
+    }
+
+    /// Every `day_NN_test*.txt` file in `src/inputs/` is checked against
+    /// whatever answers `answers.toml` has for it, so a new sample only
+    /// needs the file and its answers, not a new test.
+    #[test]
+    fn sample_inputs_get_the_known_answers() {
+        let answers = Answers::load().unwrap();
+        let mut samples = std::fs::read_dir(samples_dir())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .filter_map(|path| {
+                let name = path.file_stem()?.to_str()?.to_string();
+                Some((sample_day(&name)?, name, path))
+            })
+            .collect::<Vec<_>>();
+        samples.sort();
+
+        let mut failures = Vec::new();
+        let mut num_checked = 0;
+        for (day, name, path) in samples {
+            let input = std::fs::read_to_string(path).unwrap();
+            for part in Part::BOTH {
+                let Some(expected) = answers.get_for_input(&name, part) else {
+                    continue;
+                };
+                num_checked += 1;
+                match days::solve(day, &input, &[part]) {
+                    Ok(answer) if answer == [expected.clone()] => {}
+                    Ok(answer) => failures.push(format!(
+                        "{name} part {part}: got {}, expected {expected}",
+                        answer[0]
+                    )),
+                    Err(error) => failures.push(format!("{name} part {part}: {error}")),
+                }
+            }
+        }
+        assert!(num_checked > 0, "Didn't find any sample inputs to check");
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+
+    #[test]
+    fn full_inputs_get_the_known_answers() {
+        let answers = Answers::load().unwrap();
+        for day in answers.days() {
+            // The full inputs can't be shared, so a fresh clone won't
+            // have them.
+            if !input_path(day).exists() {
+                eprintln!("Skipping day {day}: no input");
+                continue;
+            }
+            let input = read_input(day).unwrap();
+            let day_run = days::run(day, &input, &Part::BOTH).unwrap();
+            let wrong = answers.wrong_parts(&day_run);
+            assert!(wrong.is_empty(), "Day {day} got {wrong:?}");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Counting heap allocations with `dhat`. With the `dhat-heap` feature,
+//! every allocation goes through `dhat`'s allocator, each day's
+//! allocations are reported on stderr, and the whole run is saved to
+//! `dhat-heap.json` for `dhat`'s viewer. Without it, these do nothing.
+
+#[cfg(feature = "dhat-heap")]
+#[global_allocator]
+static ALLOC: dhat::Alloc = dhat::Alloc;
+
+/// Allocations are recorded until this is dropped, and then saved to
+/// `dhat-heap.json`.
+pub struct Profiler {
+    #[cfg(feature = "dhat-heap")]
+    _profiler: dhat::Profiler,
+}
+
+/// Start recording allocations, if the `dhat-heap` feature is on.
+// This could only be `const` without the feature.
+#[allow(clippy::missing_const_for_fn)]
+#[must_use]
+pub fn start() -> Profiler {
+    Profiler {
+        #[cfg(feature = "dhat-heap")]
+        _profiler: dhat::Profiler::new_heap(),
+    }
+}
+
+/// Whether allocations are being counted, in which case days should be
+/// run one at a time so their counts don't get mixed up.
+pub const ENABLED: bool = cfg!(feature = "dhat-heap");
+
+/// Run `solve` and report how many allocations it made and how many
+/// bytes they added up to, labelled with `day`. Needs `start()` to have
+/// been called first.
+#[cfg(feature = "dhat-heap")]
+pub fn measure<T>(day: u8, solve: impl FnOnce() -> T) -> T {
+    let before = dhat::HeapStats::get();
+    let result = solve();
+    let after = dhat::HeapStats::get();
+    eprintln!(
+        "Day {day}: {} allocations, {} bytes (peak for the run so far: {} bytes)",
+        after.total_blocks - before.total_blocks,
+        after.total_bytes - before.total_bytes,
+        after.max_bytes
+    );
+    result
+}
+
+#[cfg(not(feature = "dhat-heap"))]
+pub fn measure<T>(_day: u8, solve: impl FnOnce() -> T) -> T {
+    solve()
+}
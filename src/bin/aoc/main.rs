@@ -0,0 +1,658 @@
+mod heap;
+mod profile;
+mod server;
+mod tui;
+
+use std::{
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2023::{
+    animation,
+    answers::Answers,
+    client::{Client, Fetched, Submission},
+    days::{self, solve},
+    doctor,
+    generate::generate,
+    logging,
+    parsing::{input_path, read_input, set_inputs_dir},
+    progress,
+    scaffold::new_day,
+    solver::{DayRun, Part, PartRun},
+};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use miette::{Context, IntoDiagnostic};
+use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use serde::Serialize;
+
+/// Run the solution for one day of Advent of Code 2023.
+#[derive(Debug, Parser)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Where to find the full puzzle inputs, instead of `src/inputs/`.
+    /// Setting `AOC_INPUT_DIR` does the same thing.
+    #[arg(long, global = true, value_name = "DIR")]
+    input_dir: Option<PathBuf>,
+
+    /// Running a day is the default, so `aoc --day 14` is the
+    /// same as `aoc run --day 14`.
+    #[command(flatten)]
+    run: Option<RunArgs>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run the solution for one day.
+    Run(RunArgs),
+
+    /// Run every day that has an input and print a table of the answers
+    /// and how long each took.
+    RunAll {
+        /// Check that the whole year runs in under this many seconds.
+        #[arg(long, visible_alias = "year-budget")]
+        target: Option<f64>,
+
+        /// Flag every part that takes longer than this many seconds,
+        /// counting its share of parsing, and list them slowest first.
+        #[arg(long)]
+        budget: Option<f64>,
+
+        /// Run one day at a time instead of all at once, so the days
+        /// don't slow each other down and skew their timings.
+        #[arg(long)]
+        sequential: bool,
+
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+    },
+
+    /// Check every day (or just one) against the known answers in
+    /// `answers.toml`.
+    Verify {
+        /// The day to check, from 1 to 25.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+    },
+
+    /// Check the setup: which inputs are missing, whether `AOC_SESSION`
+    /// works, whether the sample answers in `answers.toml` are right,
+    /// and which days haven't been solved yet.
+    Doctor {
+        /// Don't check `AOC_SESSION` with adventofcode.com, just that
+        /// it's set.
+        #[arg(long)]
+        offline: bool,
+    },
+
+    /// Show every day in an interactive dashboard where you can run them
+    /// and see their answers, timings, and errors.
+    Tui,
+
+    /// Start an HTTP server where `POST /solve/{day}/{part}` with the
+    /// input as the body responds with the answer and timings as JSON.
+    Serve {
+        /// The address to listen on.
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        address: SocketAddr,
+    },
+
+    /// Download a day's puzzle input into the inputs directory.
+    Fetch {
+        /// The day to download, from 1 to 25.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Download the input even if we already have it.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Write a big synthetic input for a day, for seeing how its
+    /// solution scales beyond the real input.
+    GenInput {
+        /// The day to make an input for.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The number of lines (or steps, for day 15), or the side of
+        /// the grid for days with a grid.
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+
+        /// Different seeds give different inputs of the same size.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Where to write the input; it goes to stdout if this is left out.
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+
+    /// Create the solver module and empty input files for a new day.
+    NewDay {
+        /// The day to create, from 1 to 25.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+
+    /// Run one part of a day's solution and submit the answer.
+    Submit {
+        /// The day to submit, from 1 to 25.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// The part to submit.
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// The day to run, from 1 to 25.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to run. Runs both parts if this is left out.
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// The puzzle input to use instead of `day_NN.txt` in the inputs
+    /// directory.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Show progress bars for the days that take a while.
+    #[arg(long)]
+    progress: bool,
+
+    /// Also draw a picture of the day to this file, as SVG, or as PNG
+    /// if it ends in `.png` (which needs the `png` feature). Only days
+    /// 3, 10, 14, 16, and 17 can be drawn.
+    #[arg(long, value_name = "FILE")]
+    viz: Option<PathBuf>,
+
+    /// Save the day's network to this file as a Graphviz DOT graph,
+    /// with the start nodes in green and the end nodes in red. Only day
+    /// 8 has one.
+    #[arg(long, value_name = "FILE")]
+    dot: Option<PathBuf>,
+
+    /// Play the day's simulation in the terminal before printing the
+    /// answers. Only days 14, 16, and 17 have one.
+    #[arg(long)]
+    animate: bool,
+
+    /// How long to show each frame of `--animate` for, in milliseconds.
+    #[arg(long, default_value_t = 100, requires = "animate")]
+    frame_delay: u64,
+
+    /// Save the day's parsed input to this file as JSON. Needs the
+    /// `serde` feature, and only works for days 2, 3, 5, 10, 12, and 14.
+    #[arg(long, value_name = "FILE")]
+    dump_parsed: Option<PathBuf>,
+
+    /// Also work out part 2 by brute force and make sure it agrees with
+    /// the real answer. This is slow, and only day 5 has one.
+    #[arg(long)]
+    brute_force: bool,
+
+    /// Sample where the day spends its time and save that as a
+    /// flamegraph SVG in this file. Needs the `profile` feature.
+    #[arg(long, value_name = "FILE")]
+    profile_out: Option<PathBuf>,
+}
+
+/// How to print the results.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+enum Format {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// One JSON object per line for each part, with the answer and
+    /// timings in milliseconds.
+    Json,
+}
+
+/// One line of `--format json` output. The answer is a string because
+/// some answers are too big to be safely read as JSON numbers.
+#[derive(Debug, Serialize)]
+struct PartRecord {
+    day: u8,
+    part: u8,
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    parse_ms: f64,
+    solve_ms: f64,
+}
+
+impl PartRecord {
+    fn new(day_run: &DayRun, part_run: &PartRun) -> Self {
+        let (answer, error) = match &part_run.answer {
+            Ok(answer) => (Some(answer.to_string()), None),
+            Err(error) => (None, Some(error.to_string())),
+        };
+        Self {
+            day: day_run.day,
+            part: match part_run.part {
+                Part::One => 1,
+                Part::Two => 2,
+            },
+            answer,
+            error,
+            parse_ms: day_run.parse_time.as_secs_f64() * 1000.0,
+            solve_ms: part_run.solve_time.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+fn print_json(day_run: &DayRun) -> miette::Result<()> {
+    for part_run in day_run
+        .parts
+        .iter()
+        .filter(|part_run| !part_run.is_unsolved())
+    {
+        let record = PartRecord::new(day_run, part_run);
+        println!("{}", serde_json::to_string(&record).into_diagnostic()?);
+    }
+    Ok(())
+}
+
+/// Clap has already checked that `part` is 1 or 2.
+const fn to_part(part: u8) -> Part {
+    if part == 1 {
+        Part::One
+    } else {
+        Part::Two
+    }
+}
+
+fn run(args: &RunArgs) -> miette::Result<()> {
+    let input = match &args.input {
+        Some(path) => std::fs::read_to_string(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't read the input from {}", path.display()))?,
+        None => read_input(args.day)?,
+    };
+    if args.progress {
+        progress::enable();
+    }
+    if let Some(path) = &args.viz {
+        days::visualize(args.day, &input)?.save(path)?;
+        eprintln!("Saved a picture of day {} to {}", args.day, path.display());
+    }
+    if let Some(path) = &args.dot {
+        std::fs::write(path, days::dot(args.day, &input)?)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't write the graph to {}", path.display()))?;
+        eprintln!("Saved day {}'s network to {}", args.day, path.display());
+    }
+    if let Some(path) = &args.dump_parsed {
+        std::fs::write(path, days::parsed_json(args.day, &input)?)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't write the parsed input to {}", path.display()))?;
+        eprintln!(
+            "Saved day {}'s parsed input to {}",
+            args.day,
+            path.display()
+        );
+    }
+    if args.animate {
+        let frames = days::animate(args.day, &input)?;
+        animation::play(frames, Duration::from_millis(args.frame_delay))?;
+    }
+    let parts: &[Part] = match args.part {
+        Some(part) => &[to_part(part)],
+        None => &Part::BOTH,
+    };
+    let day_run = match &args.profile_out {
+        Some(path) => {
+            let day_run = profile::profile(path, || days::run(args.day, &input, parts))?;
+            eprintln!(
+                "Saved a flamegraph of day {} to {}",
+                args.day,
+                path.display()
+            );
+            day_run
+        }
+        None => heap::measure(args.day, || days::run(args.day, &input, parts))?,
+    };
+    if args.brute_force {
+        check_brute_force(args.day, &input, &day_run)?;
+    }
+    match args.format {
+        Format::Text => {
+            for part_run in day_run.parts {
+                println!("Part {}: {}", part_run.part, part_run.answer?);
+            }
+        }
+        Format::Json => print_json(&day_run)?,
+    }
+
+    Ok(())
+}
+
+/// Compare `day_run`'s part 2 answer with the brute-force one, if we
+/// ran part 2 and it got an answer.
+fn check_brute_force(day: u8, input: &str, day_run: &DayRun) -> miette::Result<()> {
+    let brute_force = days::brute_force(day, input)?;
+    eprintln!("Part 2 by brute force: {brute_force}");
+    let part_2 = day_run
+        .parts
+        .iter()
+        .find(|part_run| part_run.part == Part::Two);
+    match part_2.map(|part_run| &part_run.answer) {
+        Some(Ok(answer)) if *answer != brute_force => Err(miette::miette!(
+            "Part 2's answer is {answer}, but brute force says it's {brute_force}"
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Days without an input and parts that haven't been solved are
+/// skipped; anything else that fails is reported in the table, and we
+/// carry on with the other days.
+///
+/// Unless `sequential` is set, the days all run at once and the target
+/// is checked against the wall-clock time, since a few slow days take
+/// most of the time and the rest can overlap with them.
+fn run_all(
+    target: Option<f64>,
+    budget: Option<f64>,
+    sequential: bool,
+    format: Format,
+) -> miette::Result<()> {
+    let target = target
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .into_diagnostic()
+        .wrap_err("The target has to be a non-negative number of seconds")?;
+    let budget = budget
+        .map(Duration::try_from_secs_f64)
+        .transpose()
+        .into_diagnostic()
+        .wrap_err("The budget has to be a non-negative number of seconds")?;
+
+    if matches!(format, Format::Text) {
+        println!(
+            "{:>3}  {:>4}  {:>16}  {:>10}  {:>10}",
+            "Day", "Part", "Answer", "Parse", "Solve"
+        );
+    }
+    let run_day = |day| {
+        read_input(day).map(|input| heap::measure(day, || days::run(day, &input, &Part::BOTH)))
+    };
+    let start = Instant::now();
+    // Allocation counts are for the whole program, so they'd get mixed
+    // up if the days ran at the same time.
+    let results: Vec<_> = if sequential || heap::ENABLED {
+        (1..=25).map(run_day).collect()
+    } else {
+        (1..=25).into_par_iter().map(run_day).collect()
+    };
+    let elapsed = start.elapsed();
+
+    let mut total = Duration::ZERO;
+    // How long each part took, including parsing, for the budget.
+    let mut part_times = Vec::new();
+    // The results are still in day order, however they were run.
+    for (day, result) in (1..=25).zip(results) {
+        let Ok(result) = result else {
+            eprintln!("Skipping day {day}: no input");
+            continue;
+        };
+        let day_run = match result {
+            Ok(day_run) => day_run,
+            Err(error) if matches!(format, Format::Text) => {
+                println!("{day:>3}  {:>4}  {error}", "-");
+                continue;
+            }
+            Err(error) => {
+                eprintln!("Day {day} failed: {error}");
+                continue;
+            }
+        };
+        total += day_run.parse_time;
+        part_times.extend(
+            day_run
+                .parts
+                .iter()
+                .filter(|part_run| part_run.answer.is_ok())
+                .map(|part_run| (day, part_run.part, day_run.parse_time + part_run.solve_time)),
+        );
+        if matches!(format, Format::Json) {
+            total += day_run
+                .parts
+                .iter()
+                .map(|part_run| part_run.solve_time)
+                .sum::<Duration>();
+            print_json(&day_run)?;
+            continue;
+        }
+        let mut parse_time = Some(day_run.parse_time);
+        for part_run in day_run
+            .parts
+            .iter()
+            .filter(|part_run| !part_run.is_unsolved())
+        {
+            total += part_run.solve_time;
+            let answer = match &part_run.answer {
+                Ok(answer) => answer.to_string(),
+                Err(error) => format!("error: {error}"),
+            };
+            // Only show the parse time once per day, since both parts share it.
+            let parse_time = parse_time
+                .take()
+                .map_or_else(String::new, |time| format!("{time:.2?}"));
+            println!(
+                "{day:>3}  {:>4}  {answer:>16}  {parse_time:>10}  {:>10}",
+                part_run.part,
+                format!("{:.2?}", part_run.solve_time)
+            );
+        }
+    }
+    if matches!(format, Format::Text) {
+        println!("Total: {total:.2?} ({elapsed:.2?} wall clock)");
+    }
+    if let Some(budget) = budget {
+        report_budget(part_times, budget);
+    }
+
+    match target {
+        Some(target) if elapsed > target => Err(miette::miette!(
+            "Took {elapsed:.2?}, which is over the target of {target:.2?}"
+        )),
+        Some(target) => {
+            eprintln!("Took {elapsed:.2?}, which is under the target of {target:.2?}");
+            Ok(())
+        }
+        None => Ok(()),
+    }
+}
+
+/// List the parts that took longer than `budget`, slowest first, so
+/// it's clear where optimizing would help the most.
+fn report_budget(mut part_times: Vec<(u8, Part, Duration)>, budget: Duration) {
+    part_times.retain(|&(_, _, time)| time > budget);
+    if part_times.is_empty() {
+        eprintln!("Every part ran in under the budget of {budget:.2?}");
+        return;
+    }
+    part_times.sort_by_key(|&(_, _, time)| std::cmp::Reverse(time));
+    eprintln!(
+        "{} parts went over the budget of {budget:.2?}:",
+        part_times.len()
+    );
+    for (day, part, time) in part_times {
+        eprintln!(
+            "  Day {day:>2} part {part}: {time:.2?} ({:.1}× the budget)",
+            time.as_secs_f64() / budget.as_secs_f64()
+        );
+    }
+}
+
+/// Days run all at once like `run_all()`. A part that fails counts as
+/// wrong, but parts without a known answer aren't checked.
+fn verify(day: Option<u8>) -> miette::Result<()> {
+    let answers = Answers::load()?;
+    let day_numbers = day.map_or_else(|| answers.days().collect(), |day| vec![day]);
+    let results: Vec<_> = day_numbers
+        .par_iter()
+        .map(|&day| read_input(day).and_then(|input| days::run(day, &input, &Part::BOTH)))
+        .collect();
+
+    let mut num_wrong = 0;
+    for (&day, result) in day_numbers.iter().zip(results) {
+        let day_run = match result {
+            Ok(day_run) => day_run,
+            Err(error) => {
+                println!("Day {day:>2}: {error}");
+                num_wrong += 1;
+                continue;
+            }
+        };
+        for part_run in &day_run.parts {
+            let Some(expected) = answers.get(day, part_run.part) else {
+                continue;
+            };
+            let part = part_run.part;
+            match &part_run.answer {
+                Ok(answer) if *answer == expected => println!("Day {day:>2} part {part}: {answer}"),
+                Ok(answer) => {
+                    println!("Day {day:>2} part {part}: got {answer}, expected {expected}");
+                    num_wrong += 1;
+                }
+                Err(error) => {
+                    println!("Day {day:>2} part {part}: {error}");
+                    num_wrong += 1;
+                }
+            }
+        }
+    }
+
+    if num_wrong == 0 {
+        Ok(())
+    } else {
+        Err(miette::miette!(
+            "{num_wrong} of the known answers didn't match"
+        ))
+    }
+}
+
+/// Warnings (days that haven't been solved yet) are reported, but only
+/// errors make this fail.
+fn doctor(offline: bool) -> miette::Result<()> {
+    let problems = doctor::check_everything(offline);
+    let num_errors = problems.iter().filter(|problem| problem.is_error()).count();
+    for problem in problems {
+        eprintln!("{:?}", miette::Report::new(problem));
+    }
+    if num_errors == 0 {
+        println!("Everything looks good");
+        Ok(())
+    } else {
+        Err(miette::miette!(
+            "Found {num_errors} problems with the setup"
+        ))
+    }
+}
+
+fn fetch(day: u8, force: bool) -> miette::Result<()> {
+    let client = Client::from_env()?;
+    let path = input_path(day);
+    match client.fetch_input(day, force)? {
+        Fetched::Downloaded => println!("Saved the input for day {day} to {}", path.display()),
+        Fetched::Cached => println!(
+            "Already have the input for day {day} in {}; use --force to download it again",
+            path.display()
+        ),
+    }
+
+    Ok(())
+}
+
+fn gen_input(day: u8, size: usize, seed: u64, output: Option<&Path>) -> miette::Result<()> {
+    let input = generate(day, size, seed)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, input)
+                .into_diagnostic()
+                .wrap_err_with(|| format!("Couldn't write the input to {}", path.display()))?;
+            eprintln!(
+                "Saved a size {size} input for day {day} to {}",
+                path.display()
+            );
+        }
+        None => print!("{input}"),
+    }
+    Ok(())
+}
+
+fn submit(day: u8, part: Part) -> miette::Result<()> {
+    let client = Client::from_env()?;
+    let input = read_input(day)?;
+    let [answer] = &solve(day, &input, &[part])?[..] else {
+        unreachable!("We asked for exactly one part");
+    };
+    println!("Submitting {answer} for day {day} part {part}");
+    let submission = client.submit_answer(day, part, answer)?;
+    println!("{submission}");
+    if submission == Submission::Correct {
+        Ok(())
+    } else {
+        Err(miette::miette!("The answer wasn't accepted"))
+    }
+}
+
+fn main() -> miette::Result<()> {
+    let _profiler = heap::start();
+    logging::init();
+    let cli = Cli::parse();
+    if let Some(dir) = cli.input_dir {
+        set_inputs_dir(dir);
+    }
+    match (cli.command, cli.run) {
+        (Some(Command::Run(args)), _) | (None, Some(args)) => run(&args),
+        (
+            Some(Command::RunAll {
+                target,
+                budget,
+                sequential,
+                format,
+            }),
+            _,
+        ) => run_all(target, budget, sequential, format),
+        (Some(Command::Verify { day }), _) => verify(day),
+        (Some(Command::Doctor { offline }), _) => doctor(offline),
+        (Some(Command::Tui), _) => tui::run(),
+        (Some(Command::Serve { address }), _) => server::run(address),
+        (Some(Command::Fetch { day, force }), _) => fetch(day, force),
+        (
+            Some(Command::GenInput {
+                day,
+                size,
+                seed,
+                output,
+            }),
+            _,
+        ) => gen_input(day, size, seed, output.as_deref()),
+        (Some(Command::NewDay { day }), _) => {
+            for path in new_day(day)? {
+                println!("Created {}", path.display());
+            }
+            Ok(())
+        }
+        (Some(Command::Submit { day, part }), _) => submit(day, to_part(part)),
+        // Clap requires `--day` when there's no subcommand, so we can't get here.
+        (None, None) => unreachable!("Clap should have required a subcommand or `--day`"),
+    }
+}
@@ -0,0 +1,46 @@
+//! Flamegraphs of a day's run, sampled with `pprof`. This needs the
+//! `profile` feature, which only builds on Unix-like systems.
+
+use std::path::Path;
+
+/// How many times a second to sample the stack.
+#[cfg(feature = "profile")]
+const SAMPLE_FREQUENCY: i32 = 1000;
+
+/// Run `solve`, sampling where it spends its time, and then draw
+/// those samples as a flamegraph SVG at `path`.
+///
+/// # Errors
+///
+/// Returns an error if `solve` does, or if the profiler can't be
+/// started or the flamegraph can't be written.
+#[cfg(feature = "profile")]
+pub fn profile<T>(path: &Path, solve: impl FnOnce() -> miette::Result<T>) -> miette::Result<T> {
+    use miette::{Context, IntoDiagnostic};
+
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(SAMPLE_FREQUENCY)
+        // Sampling inside these can deadlock.
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .into_diagnostic()
+        .wrap_err("Couldn't start the profiler")?;
+    let result = solve()?;
+    let report = guard.report().build().into_diagnostic()?;
+    let file = std::fs::File::create(path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Couldn't create {}", path.display()))?;
+    report
+        .flamegraph(file)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Couldn't write the flamegraph to {}", path.display()))?;
+    Ok(result)
+}
+
+#[cfg(not(feature = "profile"))]
+pub fn profile<T>(_path: &Path, _solve: impl FnOnce() -> miette::Result<T>) -> miette::Result<T> {
+    Err(miette::miette!(
+        help = "Rebuild with `--features profile`",
+        "Profiling needs the `profile` feature"
+    ))
+}
@@ -0,0 +1,66 @@
+use std::net::SocketAddr;
+
+use advent_of_code_2023::{days, errors::render_plain, solver::Part};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use miette::{Context, IntoDiagnostic};
+use serde::Serialize;
+
+use crate::PartRecord;
+
+/// The body of an error response.
+#[derive(Debug, Serialize)]
+struct Problem {
+    error: String,
+}
+
+fn problem(status: StatusCode, error: String) -> Response {
+    (status, Json(Problem { error })).into_response()
+}
+
+/// Serve `POST /solve/{day}/{part}`, which solves that part for the
+/// input in the request body and responds with the same JSON as
+/// `--format json`.
+///
+/// # Errors
+///
+/// Returns an error if we can't listen on `address`.
+pub fn run(address: SocketAddr) -> miette::Result<()> {
+    let runtime = tokio::runtime::Runtime::new().into_diagnostic()?;
+    runtime.block_on(async {
+        let app = Router::new().route("/solve/{day}/{part}", post(solve));
+        let listener = tokio::net::TcpListener::bind(address)
+            .await
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't listen on {address}"))?;
+        eprintln!("Listening on http://{address}");
+        axum::serve(listener, app).await.into_diagnostic()
+    })
+}
+
+async fn solve(Path((day, part)): Path<(u8, u8)>, input: String) -> Response {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return problem(StatusCode::NOT_FOUND, format!("There's no part {part}")),
+    };
+    // Solving can take a while and doesn't await anything, so it
+    // shouldn't hold up the threads that handle requests.
+    let day_run = match tokio::task::spawn_blocking(move || days::run(day, &input, &[part])).await {
+        Ok(Ok(day_run)) => day_run,
+        Ok(Err(report)) => return problem(StatusCode::UNPROCESSABLE_ENTITY, render_plain(&report)),
+        Err(error) => return problem(StatusCode::INTERNAL_SERVER_ERROR, error.to_string()),
+    };
+    let record = PartRecord::new(&day_run, &day_run.parts[0]);
+    let status = if record.error.is_some() {
+        StatusCode::UNPROCESSABLE_ENTITY
+    } else {
+        StatusCode::OK
+    };
+    (status, Json(record)).into_response()
+}
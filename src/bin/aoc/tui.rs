@@ -0,0 +1,285 @@
+use std::{
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use advent_of_code_2023::{
+    days,
+    parsing::read_input,
+    solver::{DayRun, Part, PartRun},
+};
+use miette::{GraphicalReportHandler, GraphicalTheme, IntoDiagnostic, Report};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Paragraph, Row, Table, TableState, Wrap},
+    DefaultTerminal, Frame,
+};
+
+const NUM_DAYS: u8 = 25;
+
+/// How long to wait for a key press before checking for finished days.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Where each day is at. Days run one at a time on a background thread
+/// (so their timings don't interfere with each other), which lets us
+/// keep drawing while a slow day runs.
+#[derive(Debug)]
+enum DayStatus {
+    NotRun,
+    Queued,
+    Running,
+    NoInput(String),
+    Failed(String),
+    Finished(DayRun),
+}
+
+struct App {
+    days: Vec<DayStatus>,
+    table: TableState,
+    jobs: Sender<u8>,
+    results: Receiver<(u8, DayStatus)>,
+}
+
+/// Show every day in a table, with keys to run the selected day (or all
+/// of them) and a pane with the answers and any errors for the selected
+/// day.
+///
+/// # Errors
+///
+/// Returns an error if we can't draw to or read from the terminal, or if
+/// a solver panics.
+pub fn run() -> miette::Result<()> {
+    ratatui::run(|terminal| App::new().run(terminal))
+}
+
+impl App {
+    fn new() -> Self {
+        let (jobs, job_receiver) = mpsc::channel();
+        let (result_sender, results) = mpsc::channel();
+        thread::spawn(move || work(&job_receiver, &result_sender));
+        Self {
+            days: (0..NUM_DAYS).map(|_| DayStatus::NotRun).collect(),
+            table: TableState::default().with_selected(0),
+            jobs,
+            results,
+        }
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> miette::Result<()> {
+        loop {
+            self.receive_results()?;
+            terminal.draw(|frame| self.draw(frame)).into_diagnostic()?;
+            if !event::poll(POLL_INTERVAL).into_diagnostic()? {
+                continue;
+            }
+            if let Event::Key(key) = event::read().into_diagnostic()? {
+                if key.kind == KeyEventKind::Press && !self.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn receive_results(&mut self) -> miette::Result<()> {
+        loop {
+            match self.results.try_recv() {
+                Ok((day, status)) => self.days[usize::from(day - 1)] = status,
+                Err(TryRecvError::Empty) => return Ok(()),
+                Err(TryRecvError::Disconnected) => {
+                    return Err(miette::miette!("A solver panicked"));
+                }
+            }
+        }
+    }
+
+    /// Returns `false` when it's time to quit.
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Down | KeyCode::Char('j') => self.table.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+            KeyCode::Enter | KeyCode::Char('r') => self.queue(self.selected_day()),
+            KeyCode::Char('a') => (1..=NUM_DAYS).for_each(|day| self.queue(day)),
+            _ => {}
+        }
+        true
+    }
+
+    fn selected_day(&self) -> u8 {
+        self.table
+            .selected()
+            .and_then(|index| u8::try_from(index + 1).ok())
+            .unwrap_or(1)
+            .min(NUM_DAYS)
+    }
+
+    fn queue(&mut self, day: u8) {
+        let status = &mut self.days[usize::from(day - 1)];
+        if matches!(status, DayStatus::Queued | DayStatus::Running) {
+            return;
+        }
+        // If the worker is gone, `receive_results()` reports it on the
+        // next pass through the loop.
+        if self.jobs.send(day).is_ok() {
+            *status = DayStatus::Queued;
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main_area, help_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [table_area, details_area] =
+            Layout::horizontal([Constraint::Length(66), Constraint::Min(20)]).areas(main_area);
+
+        let header = Row::new(["Day", "Part 1", "Part 2", "Parse", "Solve"])
+            .style(Style::new().add_modifier(Modifier::BOLD));
+        let rows = (1..=NUM_DAYS)
+            .zip(&self.days)
+            .map(|(day, status)| day_row(day, status));
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(16),
+            Constraint::Length(16),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title("Advent of Code 2023"))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+
+        let day = self.selected_day();
+        let details = Paragraph::new(details(&self.days[usize::from(day - 1)]))
+            .block(Block::bordered().title(format!("Day {day}")))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(details, details_area);
+
+        let help = "↑/↓ select · r run day · a run all · q quit";
+        frame.render_widget(Line::from(help), help_area);
+    }
+}
+
+/// Run each day we're sent, one at a time, until the app goes away.
+fn work(jobs: &Receiver<u8>, results: &Sender<(u8, DayStatus)>) {
+    for day in jobs {
+        let sent = results
+            .send((day, DayStatus::Running))
+            .and_then(|()| results.send((day, run_day(day))));
+        if sent.is_err() {
+            return;
+        }
+    }
+}
+
+fn run_day(day: u8) -> DayStatus {
+    let input = match read_input(day) {
+        Ok(input) => input,
+        Err(report) => return DayStatus::NoInput(render(&report)),
+    };
+    match days::run(day, &input, &Part::BOTH) {
+        Ok(day_run) => DayStatus::Finished(day_run),
+        Err(report) => DayStatus::Failed(render(&report)),
+    }
+}
+
+/// Render a report the way the command line does, minus the colors,
+/// which the pane would show as escape codes.
+fn render(report: &Report) -> String {
+    let mut rendered = String::new();
+    let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+    match handler.render_report(&mut rendered, report.as_ref()) {
+        Ok(()) => rendered,
+        Err(_) => report.to_string(),
+    }
+}
+
+fn day_row(day: u8, status: &DayStatus) -> Row<'static> {
+    let status = match status {
+        DayStatus::NotRun => "",
+        DayStatus::Queued => "queued",
+        DayStatus::Running => "running…",
+        DayStatus::NoInput(_) => "no input",
+        DayStatus::Failed(_) => "failed",
+        DayStatus::Finished(day_run) => {
+            let solve_time = day_run
+                .parts
+                .iter()
+                .map(|part_run| part_run.solve_time)
+                .sum::<Duration>();
+            return Row::new([
+                day.to_string(),
+                part_summary(day_run, Part::One),
+                part_summary(day_run, Part::Two),
+                format!("{:.2?}", day_run.parse_time),
+                format!("{solve_time:.2?}"),
+            ]);
+        }
+    };
+    Row::new([day.to_string(), status.to_string()])
+}
+
+fn part_summary(day_run: &DayRun, part: Part) -> String {
+    match day_run.parts.iter().find(|part_run| part_run.part == part) {
+        Some(part_run) if part_run.is_unsolved() => "-".into(),
+        Some(PartRun {
+            answer: Ok(answer), ..
+        }) => answer.to_string(),
+        Some(_) => "error".into(),
+        None => String::new(),
+    }
+}
+
+fn details(status: &DayStatus) -> String {
+    match status {
+        DayStatus::NotRun => "Press r to run this day.".into(),
+        DayStatus::Queued => "Waiting for the days before this one to finish.".into(),
+        DayStatus::Running => "Running…".into(),
+        DayStatus::NoInput(error) | DayStatus::Failed(error) => error.clone(),
+        DayStatus::Finished(day_run) => day_run
+            .parts
+            .iter()
+            .map(|part_run| match &part_run.answer {
+                _ if part_run.is_unsolved() => format!("Part {}: not solved yet", part_run.part),
+                Ok(answer) => format!(
+                    "Part {}: {answer} ({:.2?})",
+                    part_run.part, part_run.solve_time
+                ),
+                Err(report) => format!("Part {} failed:\n{}", part_run.part, render(report)),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn keys_move_the_selection_and_quit() {
+        let mut app = App::new();
+        assert!(app.handle_key(KeyCode::Char('j')));
+        assert!(app.handle_key(KeyCode::Down));
+        assert_eq!(app.selected_day(), 3);
+        assert!(app.handle_key(KeyCode::Up));
+        assert_eq!(app.selected_day(), 2);
+        assert!(!app.handle_key(KeyCode::Char('q')));
+    }
+
+    #[test]
+    fn finished_days_show_their_answers() {
+        let mut app = App::new();
+        app.days[0] = run_day(1);
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let screen = format!("{:?}", terminal.backend().buffer());
+        assert!(screen.contains("54644"), "{screen}");
+        assert!(screen.contains("Part 2: 53348"), "{screen}");
+    }
+}
@@ -0,0 +1,60 @@
+//! Day 1 read a line at a time, from a file or from stdin, so
+//! calibration documents of any size can be added up without reading
+//! them into memory first:
+//!
+//! ```text
+//! cat huge.txt | cargo run --bin day_01_stream -- --part 2
+//! ```
+
+use std::{
+    fs::File,
+    io::{stdin, BufReader},
+    path::PathBuf,
+    str::FromStr,
+};
+
+use advent_of_code_2023::days::day_01::{digits_swar, stream_calibration_total, DigitWords};
+use clap::Parser;
+use miette::IntoDiagnostic;
+
+/// Add up the calibration values in a document, a line at a time.
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Cli {
+    /// The part to run.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The words that count as digits in part 2, one `word=value` per
+    /// line, instead of "one" to "nine".
+    #[arg(long, value_name = "FILE")]
+    words: Option<PathBuf>,
+
+    /// The calibration document; it's read from stdin if this is left out.
+    input: Option<PathBuf>,
+}
+
+fn main() -> miette::Result<()> {
+    advent_of_code_2023::logging::init();
+    let cli = Cli::parse();
+    let words = match cli.words {
+        Some(path) => DigitWords::from_str(&std::fs::read_to_string(path).into_diagnostic()?)?,
+        None => DigitWords::english().clone(),
+    };
+    let find_digits = |line: &str| {
+        if cli.part == 1 {
+            digits_swar(line)
+        } else {
+            words.first_and_last(line)
+        }
+    };
+    let total = match cli.input {
+        Some(path) => {
+            let file = File::open(path).into_diagnostic()?;
+            stream_calibration_total(BufReader::new(file), find_digits)?
+        }
+        None => stream_calibration_total(stdin().lock(), find_digits)?,
+    };
+    println!("Result: {total}");
+    Ok(())
+}
@@ -1,122 +1,55 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::{newline, space1, u32},
-    combinator::all_consuming,
-    multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
-};
-
-#[derive(Debug, Clone)]
-enum Color {
-    Red,
-    Green,
-    Blue,
-}
-
-#[derive(Debug)]
-struct Reveal {
+//! Day 2 part 1 with the `nom` parser. The bag holds 12 red, 13 green,
+//! and 14 blue cubes unless the flags say otherwise:
+//!
+//! ```text
+//! cargo run --bin day_02_part_1_nom -- --red 20
+//! ```
+
+use advent_of_code_2023::days::day_02::{parse_games_nom, sum_of_legal_game_ids, Bag};
+use clap::Parser;
+
+/// Add up the numbers of the games that could have been played with
+/// the cubes in the bag.
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Cli {
+    /// How many red cubes are in the bag.
+    #[arg(long, default_value_t = Bag::PART_1.red)]
     red: u32,
-    green: u32,
-    blue: u32,
-}
-
-impl Reveal {
-    const fn within(&self, max_count: &Self) -> bool {
-        self.red <= max_count.red && self.green <= max_count.green && self.blue <= max_count.blue
-    }
-}
-
-type CubeCount = (u32, Color);
-
-impl FromIterator<CubeCount> for Reveal {
-    fn from_iter<I: IntoIterator<Item = CubeCount>>(iter: I) -> Self {
-        let mut red = 0;
-        let mut green = 0;
-        let mut blue = 0;
-        for (count, color) in iter {
-            match color {
-                Color::Red => red += count,
-                Color::Green => green += count,
-                Color::Blue => blue += count,
-            }
-        }
-        Self { red, green, blue }
-    }
-}
 
-#[derive(Debug)]
-struct Game {
-    number: u32,
-    reveals: Vec<Reveal>,
-}
-
-fn parse_color(input: &str) -> IResult<&str, Color> {
-    nom::branch::alt((
-        nom::combinator::value(Color::Red, tag("red")),
-        nom::combinator::value(Color::Green, tag("green")),
-        nom::combinator::value(Color::Blue, tag("blue")),
-    ))(input)
-}
-
-fn parse_cube_count(input: &str) -> IResult<&str, CubeCount> {
-    separated_pair(u32, space1, parse_color)(input)
-}
-
-fn parse_reveal(input: &str) -> IResult<&str, Reveal> {
-    separated_list1(tag(", "), parse_cube_count)(input).map(|(input, counts)| {
-        let reveal = counts.into_iter().collect();
-        (input, reveal)
-    })
-}
-
-fn parse_reveals(input: &str) -> IResult<&str, Vec<Reveal>> {
-    separated_list1(tag("; "), parse_reveal)(input)
-}
+    /// How many green cubes are in the bag.
+    #[arg(long, default_value_t = Bag::PART_1.green)]
+    green: u32,
 
-fn parse_game_header(input: &str) -> IResult<&str, u32> {
-    separated_pair(tag("Game"), space1, u32)(input).map(|(input, (_, number))| (input, number))
+    /// How many blue cubes are in the bag.
+    #[arg(long, default_value_t = Bag::PART_1.blue)]
+    blue: u32,
 }
 
-fn parse_game(input: &str) -> IResult<&str, Game> {
-    let (input, (game_number, reveals)) =
-        nom::sequence::separated_pair(parse_game_header, tag(": "), parse_reveals)(input)?;
-    Ok((
-        input,
-        Game {
-            number: game_number,
-            reveals,
-        },
-    ))
+/// The full input, built into the binary with the `embedded-inputs`
+/// feature, and read from the inputs directory when it's run otherwise.
+#[cfg(feature = "embedded-inputs")]
+#[allow(clippy::unnecessary_wraps)]
+fn full_input() -> miette::Result<String> {
+    Ok(include_str!("../inputs/day_02.txt").to_string())
 }
 
-fn parse_games(input: &str) -> IResult<&str, Vec<Game>> {
-    separated_list1(newline, parse_game)(input)
+#[cfg(not(feature = "embedded-inputs"))]
+fn full_input() -> miette::Result<String> {
+    advent_of_code_2023::parsing::read_input(2)
 }
 
-fn sum_of_legal_game_ids(input: &str) -> anyhow::Result<u32> {
-    let max_count = Reveal {
-        red: 12,
-        green: 13,
-        blue: 14,
+fn main() -> miette::Result<()> {
+    advent_of_code_2023::logging::init();
+    let cli = Cli::parse();
+    let bag = Bag {
+        red: cli.red,
+        green: cli.green,
+        blue: cli.blue,
     };
-    let (_, games) =
-        all_consuming(parse_games)(input).map_err(nom::Err::<nom::error::Error<&str>>::to_owned)?;
-    Ok(games
-        .into_iter()
-        .filter_map(|game| {
-            game.reveals
-                .iter()
-                .all(|reveal| reveal.within(&max_count))
-                .then_some(game.number)
-        })
-        .sum())
-}
-
-fn main() -> anyhow::Result<()> {
-    let input = include_str!("../inputs/day_02.txt").trim();
-    let result = sum_of_legal_game_ids(input);
-    println!("Result: {}", result?);
+    let input = full_input()?;
+    let games = parse_games_nom(&input)?;
+    println!("Result: {}", sum_of_legal_game_ids(&games, &bag));
 
     Ok(())
 }
@@ -127,15 +60,20 @@ mod tests {
 
     #[test]
     fn check_test_input() {
-        let input = include_str!("../inputs/day_02_test.txt").trim();
-        let result = sum_of_legal_game_ids(input).unwrap();
-        assert_eq!(result, 8);
+        let input = include_str!("../inputs/day_02_test.txt");
+        let games = parse_games_nom(input).unwrap();
+        assert_eq!(sum_of_legal_game_ids(&games, &Bag::PART_1), 8);
     }
 
     #[test]
     fn check_full_input() {
-        let input = include_str!("../inputs/day_02.txt").trim();
-        let result = sum_of_legal_game_ids(input).unwrap();
-        assert_eq!(result, 2285);
+        if !cfg!(feature = "embedded-inputs")
+            && !advent_of_code_2023::parsing::input_path(2).exists()
+        {
+            eprintln!("Skipping: no input for day 2");
+            return;
+        }
+        let games = parse_games_nom(&full_input().unwrap()).unwrap();
+        assert_eq!(sum_of_legal_game_ids(&games, &Bag::PART_1), 2285);
     }
 }
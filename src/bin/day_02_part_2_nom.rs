@@ -0,0 +1,47 @@
+use advent_of_code_2023::days::day_02::{parse_games_nom, sum_of_game_powers};
+
+/// The full input, built into the binary with the `embedded-inputs`
+/// feature, and read from the inputs directory when it's run otherwise.
+#[cfg(feature = "embedded-inputs")]
+#[allow(clippy::unnecessary_wraps)]
+fn full_input() -> miette::Result<String> {
+    Ok(include_str!("../inputs/day_02.txt").to_string())
+}
+
+#[cfg(not(feature = "embedded-inputs"))]
+fn full_input() -> miette::Result<String> {
+    advent_of_code_2023::parsing::read_input(2)
+}
+
+fn main() -> miette::Result<()> {
+    advent_of_code_2023::logging::init();
+    let input = full_input()?;
+    let games = parse_games_nom(&input)?;
+    println!("Result: {}", sum_of_game_powers(&games));
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_test_input() {
+        let input = include_str!("../inputs/day_02_test.txt");
+        let games = parse_games_nom(input).unwrap();
+        assert_eq!(sum_of_game_powers(&games), 2286);
+    }
+
+    #[test]
+    fn check_full_input() {
+        if !cfg!(feature = "embedded-inputs")
+            && !advent_of_code_2023::parsing::input_path(2).exists()
+        {
+            eprintln!("Skipping: no input for day 2");
+            return;
+        }
+        let games = parse_games_nom(&full_input().unwrap()).unwrap();
+        assert_eq!(sum_of_game_powers(&games), 77021);
+    }
+}
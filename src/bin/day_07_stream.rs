@@ -0,0 +1,49 @@
+//! Day 7 read a round at a time, from a file or from stdin, so games
+//! with millions of hands can be ranked without reading them into
+//! memory first:
+//!
+//! ```text
+//! cargo run -- gen-input --day 7 --size 5000000 | cargo run --release --bin day_07_stream -- --part 2
+//! ```
+
+use std::{
+    fs::File,
+    io::{stdin, BufReader},
+    path::PathBuf,
+};
+
+use advent_of_code_2023::days::day_07::{stream_total_winnings, RuleSet};
+use clap::Parser;
+use miette::IntoDiagnostic;
+
+/// Rank the hands in a game of Camel Cards, a round at a time.
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Cli {
+    /// The part to run; part 2 plays every `J` as a joker.
+    #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// The rounds, one per line; they're read from stdin if this is left
+    /// out.
+    input: Option<PathBuf>,
+}
+
+fn main() -> miette::Result<()> {
+    advent_of_code_2023::logging::init();
+    let cli = Cli::parse();
+    let rules = if cli.part == 1 {
+        RuleSet::Standard
+    } else {
+        RuleSet::Jokers
+    };
+    let total = match cli.input {
+        Some(path) => {
+            let file = File::open(path).into_diagnostic()?;
+            stream_total_winnings(BufReader::new(file), rules)?
+        }
+        None => stream_total_winnings(stdin().lock(), rules)?,
+    };
+    println!("Result: {total}");
+    Ok(())
+}
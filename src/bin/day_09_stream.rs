@@ -0,0 +1,42 @@
+//! Day 9 read a history at a time, from a file or from stdin, working
+//! out both parts in the same pass, so reports with millions of
+//! histories don't have to be read into memory (or read twice):
+//!
+//! ```text
+//! cargo run -- gen-input --day 9 --size 5000000 | cargo run --release --bin day_09_stream
+//! ```
+
+use std::{
+    fs::File,
+    io::{stdin, BufReader},
+    path::PathBuf,
+};
+
+use advent_of_code_2023::days::day_09::stream_prediction_totals;
+use clap::Parser;
+use miette::IntoDiagnostic;
+
+/// Add up the next and previous values of every history in an OASIS
+/// report, a history at a time.
+#[derive(Debug, Parser)]
+#[command(about)]
+struct Cli {
+    /// The histories, one per line; they're read from stdin if this is
+    /// left out.
+    input: Option<PathBuf>,
+}
+
+fn main() -> miette::Result<()> {
+    advent_of_code_2023::logging::init();
+    let cli = Cli::parse();
+    let (next_total, previous_total) = match cli.input {
+        Some(path) => {
+            let file = File::open(path).into_diagnostic()?;
+            stream_prediction_totals(BufReader::new(file))?
+        }
+        None => stream_prediction_totals(stdin().lock())?,
+    };
+    println!("Part 1: {next_total}");
+    println!("Part 2: {previous_total}");
+    Ok(())
+}
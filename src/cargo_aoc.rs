@@ -0,0 +1,273 @@
+//! Hooks each day's `Solver` up to `cargo-aoc`'s solver attributes.
+//!
+//! That way `cargo aoc` can fetch inputs for, run, and benchmark the
+//! days as they are. `cargo aoc` keeps its own copies of the inputs in
+//! `input/2023/dayN.txt` rather than using `src/inputs/`.
+
+use aoc_runner_derive::aoc;
+
+use crate::{
+    days,
+    solver::{Answer, Part, Solver},
+};
+
+fn solve<S: Solver>(input: &str, part: Part) -> miette::Result<Answer> {
+    S::solve_part(&S::parse(input)?, part)
+}
+
+// `cargo-aoc` wants a function per part. Our days parse and solve in
+// one go, since the parsed input can borrow from the input, which
+// `cargo-aoc`'s generators can't do. These are written out rather than
+// made by a macro because `#[aoc]` has to register each one before
+// `aoc_lib!` at the end of `lib.rs` reads the list, and attributes that
+// come out of a macro get expanded too late for that.
+
+#[aoc(day1, part1)]
+fn day_01_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_01::Day01>(input, Part::One)
+}
+
+#[aoc(day1, part2)]
+fn day_01_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_01::Day01>(input, Part::Two)
+}
+
+#[aoc(day2, part1)]
+fn day_02_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_02::Day02>(input, Part::One)
+}
+
+#[aoc(day2, part2)]
+fn day_02_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_02::Day02>(input, Part::Two)
+}
+
+#[aoc(day3, part1)]
+fn day_03_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_03::Day03>(input, Part::One)
+}
+
+#[aoc(day3, part2)]
+fn day_03_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_03::Day03>(input, Part::Two)
+}
+
+#[aoc(day4, part1)]
+fn day_04_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_04::Day04>(input, Part::One)
+}
+
+#[aoc(day4, part2)]
+fn day_04_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_04::Day04>(input, Part::Two)
+}
+
+#[aoc(day5, part1)]
+fn day_05_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_05::Day05>(input, Part::One)
+}
+
+#[aoc(day5, part2)]
+fn day_05_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_05::Day05>(input, Part::Two)
+}
+
+#[aoc(day6, part1)]
+fn day_06_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_06::Day06>(input, Part::One)
+}
+
+#[aoc(day6, part2)]
+fn day_06_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_06::Day06>(input, Part::Two)
+}
+
+#[aoc(day7, part1)]
+fn day_07_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_07::Day07>(input, Part::One)
+}
+
+#[aoc(day7, part2)]
+fn day_07_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_07::Day07>(input, Part::Two)
+}
+
+#[aoc(day8, part1)]
+fn day_08_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_08::Day08>(input, Part::One)
+}
+
+#[aoc(day8, part2)]
+fn day_08_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_08::Day08>(input, Part::Two)
+}
+
+#[aoc(day9, part1)]
+fn day_09_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_09::Day09>(input, Part::One)
+}
+
+#[aoc(day9, part2)]
+fn day_09_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_09::Day09>(input, Part::Two)
+}
+
+#[aoc(day10, part1)]
+fn day_10_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_10::Day10>(input, Part::One)
+}
+
+#[aoc(day10, part2)]
+fn day_10_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_10::Day10>(input, Part::Two)
+}
+
+#[aoc(day11, part1)]
+fn day_11_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_11::Day11>(input, Part::One)
+}
+
+#[aoc(day11, part2)]
+fn day_11_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_11::Day11>(input, Part::Two)
+}
+
+#[aoc(day12, part1)]
+fn day_12_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_12::Day12>(input, Part::One)
+}
+
+#[aoc(day12, part2)]
+fn day_12_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_12::Day12>(input, Part::Two)
+}
+
+#[aoc(day13, part1)]
+fn day_13_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_13::Day13>(input, Part::One)
+}
+
+#[aoc(day13, part2)]
+fn day_13_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_13::Day13>(input, Part::Two)
+}
+
+#[aoc(day14, part1)]
+fn day_14_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_14::Day14>(input, Part::One)
+}
+
+#[aoc(day14, part2)]
+fn day_14_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_14::Day14>(input, Part::Two)
+}
+
+#[aoc(day15, part1)]
+fn day_15_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_15::Day15>(input, Part::One)
+}
+
+#[aoc(day15, part2)]
+fn day_15_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_15::Day15>(input, Part::Two)
+}
+
+#[aoc(day16, part1)]
+fn day_16_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_16::Day16>(input, Part::One)
+}
+
+#[aoc(day16, part2)]
+fn day_16_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_16::Day16>(input, Part::Two)
+}
+
+#[aoc(day17, part1)]
+fn day_17_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_17::Day17>(input, Part::One)
+}
+
+#[aoc(day17, part2)]
+fn day_17_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_17::Day17>(input, Part::Two)
+}
+
+#[aoc(day18, part1)]
+fn day_18_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_18::Day18>(input, Part::One)
+}
+
+#[aoc(day18, part2)]
+fn day_18_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_18::Day18>(input, Part::Two)
+}
+
+#[aoc(day19, part1)]
+fn day_19_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_19::Day19>(input, Part::One)
+}
+
+#[aoc(day19, part2)]
+fn day_19_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_19::Day19>(input, Part::Two)
+}
+
+#[aoc(day20, part1)]
+fn day_20_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_20::Day20>(input, Part::One)
+}
+
+#[aoc(day20, part2)]
+fn day_20_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_20::Day20>(input, Part::Two)
+}
+
+#[aoc(day21, part1)]
+fn day_21_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_21::Day21>(input, Part::One)
+}
+
+#[aoc(day21, part2)]
+fn day_21_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_21::Day21>(input, Part::Two)
+}
+
+#[aoc(day22, part1)]
+fn day_22_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_22::Day22>(input, Part::One)
+}
+
+#[aoc(day22, part2)]
+fn day_22_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_22::Day22>(input, Part::Two)
+}
+
+#[aoc(day23, part1)]
+fn day_23_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_23::Day23>(input, Part::One)
+}
+
+#[aoc(day23, part2)]
+fn day_23_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_23::Day23>(input, Part::Two)
+}
+
+#[aoc(day24, part1)]
+fn day_24_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_24::Day24>(input, Part::One)
+}
+
+#[aoc(day24, part2)]
+fn day_24_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_24::Day24>(input, Part::Two)
+}
+
+#[aoc(day25, part1)]
+fn day_25_part_1(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_25::Day25>(input, Part::One)
+}
+
+#[aoc(day25, part2)]
+fn day_25_part_2(input: &str) -> miette::Result<Answer> {
+    solve::<days::day_25::Day25>(input, Part::Two)
+}
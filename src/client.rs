@@ -0,0 +1,341 @@
+//! Talking to adventofcode.com, which needs the `session` cookie from a
+//! logged-in browser in the `AOC_SESSION` environment variable.
+
+use std::{
+    fmt::Display,
+    path::PathBuf,
+    time::{Duration, SystemTime},
+};
+
+use miette::Diagnostic;
+use ureq::Agent;
+
+use crate::{
+    parsing::input_path,
+    solver::{Answer, Part},
+};
+
+const BASE_URL: &str = "https://adventofcode.com/2023";
+
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The site's maintainers ask that automated tools identify themselves
+/// in their user agent.
+const USER_AGENT: &str = "github.com/NicMcPhee/advent-of-code-2023";
+
+/// We never send requests closer together than this, even across runs.
+const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Diagnostic, thiserror::Error)]
+pub enum ClientError {
+    #[error("The {SESSION_VAR} environment variable isn't set")]
+    #[diagnostic(help(
+        "Set it to the value of the `session` cookie from a logged-in adventofcode.com session"
+    ))]
+    MissingSession,
+
+    #[error("Request to {url} failed")]
+    Http {
+        url: String,
+        #[source]
+        source: ureq::Error,
+    },
+
+    #[error("Couldn't read or write {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Whether `fetch_input()` had to go to the site or found the input
+/// already cached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+/// The hint the site gives along with a wrong answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Submission {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+    },
+    /// We submitted too recently and have to wait this long first.
+    TooSoon {
+        wait: Duration,
+    },
+    /// The part was already solved (or part 1 hasn't been yet).
+    WrongLevel,
+    /// A response we don't recognize, with its text.
+    Unrecognized(String),
+}
+
+impl Display for Submission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "That's the right answer!"),
+            Self::Incorrect { hint: None } => write!(f, "That's not the right answer"),
+            Self::Incorrect {
+                hint: Some(Hint::TooHigh),
+            } => write!(f, "That's not the right answer; it's too high"),
+            Self::Incorrect {
+                hint: Some(Hint::TooLow),
+            } => write!(f, "That's not the right answer; it's too low"),
+            Self::TooSoon { wait } => write!(
+                f,
+                "Submitted too recently; wait {}s before trying again",
+                wait.as_secs()
+            ),
+            Self::WrongLevel => write!(
+                f,
+                "That's not the current level; was this part already solved?"
+            ),
+            Self::Unrecognized(text) => write!(f, "Unrecognized response: {text}"),
+        }
+    }
+}
+
+/// Parse a wait like `1h 4m 30s` into a `Duration`.
+fn parse_wait(s: &str) -> Option<Duration> {
+    s.split_ascii_whitespace()
+        .map(|token| {
+            let unit_start = token.find(|c: char| !c.is_ascii_digit())?;
+            let (amount, unit) = token.split_at(unit_start);
+            let amount = amount.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(Duration::from_hours(amount)),
+                "m" => Some(Duration::from_mins(amount)),
+                "s" => Some(Duration::from_secs(amount)),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+/// The answer page is HTML, but everything we care about is in the
+/// text of its `<article>`, so we just look for the telltale phrases.
+fn parse_submission_response(body: &str) -> Submission {
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+    if article.contains("That's the right answer") {
+        Submission::Correct
+    } else if article.contains("That's not the right answer") {
+        let hint = if article.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if article.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Submission::Incorrect { hint }
+    } else if article.contains("You gave an answer too recently") {
+        article
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .map_or_else(
+                || Submission::Unrecognized(article.to_string()),
+                |wait| Submission::TooSoon { wait },
+            )
+    } else if article.contains("You don't seem to be solving the right level") {
+        Submission::WrongLevel
+    } else {
+        Submission::Unrecognized(article.to_string())
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    session: String,
+}
+
+impl Client {
+    /// # Errors
+    ///
+    /// Returns an error if `AOC_SESSION` isn't set.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = std::env::var(SESSION_VAR).map_err(|_| ClientError::MissingSession)?;
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Ok(Self { agent, session })
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        throttle()?;
+        self.agent
+            .get(url)
+            .header("Cookie", self.cookie())
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|source| ClientError::Http {
+                url: url.to_string(),
+                source,
+            })
+    }
+
+    /// Download the input for `day` into `input_path(day)`, unless
+    /// it's already there and `force` is false. Inputs never change, so
+    /// there's normally no reason to download one twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the input can't be saved.
+    pub fn fetch_input(&self, day: u8, force: bool) -> Result<Fetched, ClientError> {
+        let path = input_path(day);
+        if !force && path.exists() {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.get(&format!("{BASE_URL}/day/{day}/input"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|source| ClientError::Io {
+                path: dir.to_path_buf(),
+                source,
+            })?;
+        }
+        std::fs::write(&path, input).map_err(|source| ClientError::Io { path, source })?;
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Whether the site accepts our session cookie, which we find out by
+    /// asking for day 1's input; without a valid session the site
+    /// refuses to send it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails for some other reason,
+    /// e.g., there's no network.
+    pub fn session_is_valid(&self) -> Result<bool, ClientError> {
+        match self.get(&format!("{BASE_URL}/day/1/input")) {
+            Ok(_) => Ok(true),
+            Err(ClientError::Http {
+                source: ureq::Error::StatusCode(400 | 401 | 403 | 500),
+                ..
+            }) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Submit `answer` for the given day and part.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails. A wrong answer isn't an
+    /// error, and is reported in the returned `Submission`.
+    pub fn submit_answer(
+        &self,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+    ) -> Result<Submission, ClientError> {
+        throttle()?;
+        let url = format!("{BASE_URL}/day/{day}/answer");
+        let body = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|source| ClientError::Http { url, source })?;
+        Ok(parse_submission_response(&body))
+    }
+}
+
+/// How long to wait before the next request, given how long ago the
+/// last one was (if we know).
+fn wait_time(since_last_request: Option<Duration>) -> Duration {
+    since_last_request.map_or(Duration::ZERO, |elapsed| {
+        MIN_REQUEST_INTERVAL.saturating_sub(elapsed)
+    })
+}
+
+/// Sleep until we're far enough from the last request, using the
+/// modification time of a marker file to remember when that was.
+fn throttle() -> Result<(), ClientError> {
+    let path = std::env::temp_dir().join("advent-of-code-2023-last-request");
+    let since_last_request = std::fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    std::thread::sleep(wait_time(since_last_request));
+    std::fs::write(&path, "").map_err(|source| ClientError::Io { path, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(
+        "<main><article><p>That's the right answer! You are one gold star closer.</p></article></main>",
+        &Submission::Correct ;
+        "correct"
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>",
+        &Submission::Incorrect { hint: Some(Hint::TooHigh) } ;
+        "too high"
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        &Submission::Incorrect { hint: Some(Hint::TooLow) } ;
+        "too low"
+    )]
+    #[test_case(
+        "<article><p>That's not the right answer. If you're stuck, try the subreddit.</p></article>",
+        &Submission::Incorrect { hint: None } ;
+        "no hint"
+    )]
+    #[test_case(
+        "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 4m 30s left to wait.</p></article>",
+        &Submission::TooSoon { wait: Duration::from_secs(270) } ;
+        "too soon"
+    )]
+    #[test_case(
+        "<article><p>You don't seem to be solving the right level. Did you already complete it?</p></article>",
+        &Submission::WrongLevel ;
+        "wrong level"
+    )]
+    fn parses_submission_responses(body: &str, expected: &Submission) {
+        assert_eq!(&parse_submission_response(body), expected);
+    }
+
+    #[test]
+    fn unrecognized_response_keeps_article_text() {
+        let result = parse_submission_response("<article>Something new</article>");
+        assert_eq!(
+            result,
+            Submission::Unrecognized("Something new".to_string())
+        );
+    }
+
+    #[test]
+    fn waits_out_the_rest_of_the_interval() {
+        assert_eq!(MIN_REQUEST_INTERVAL, Duration::from_secs(5));
+        assert_eq!(wait_time(None), Duration::ZERO);
+        assert_eq!(
+            wait_time(Some(Duration::from_secs(2))),
+            Duration::from_secs(3)
+        );
+        assert_eq!(wait_time(Some(Duration::from_mins(1))), Duration::ZERO);
+    }
+}
@@ -0,0 +1,255 @@
+use crate::{
+    animation::Animate,
+    solver::{Answer, DayRun, Part, Solver},
+    viz::{Svg, Visualize},
+};
+
+pub use aoc_day01 as day_01;
+pub use aoc_day02 as day_02;
+pub use aoc_day03 as day_03;
+pub use aoc_day04 as day_04;
+pub use aoc_day05 as day_05;
+pub use aoc_day06 as day_06;
+pub use aoc_day07 as day_07;
+pub use aoc_day08 as day_08;
+pub use aoc_day09 as day_09;
+pub use aoc_day10 as day_10;
+pub use aoc_day11 as day_11;
+pub use aoc_day12 as day_12;
+pub use aoc_day13 as day_13;
+pub use aoc_day14 as day_14;
+pub use aoc_day15 as day_15;
+pub use aoc_day16 as day_16;
+pub use aoc_day17 as day_17;
+pub use aoc_day18 as day_18;
+pub use aoc_day19 as day_19;
+pub use aoc_day20 as day_20;
+pub use aoc_day21 as day_21;
+pub use aoc_day22 as day_22;
+pub use aoc_day23 as day_23;
+pub use aoc_day24 as day_24;
+pub use aoc_day25 as day_25;
+
+/// Parse `input` once and solve each of `parts` for the given day.
+///
+/// # Errors
+///
+/// Returns an error if there's no such day, if a part hasn't been
+/// solved, or if the solver itself fails (typically because the input
+/// doesn't parse).
+pub fn solve(day: u8, input: &str, parts: &[Part]) -> miette::Result<Vec<Answer>> {
+    run(day, input, parts)?
+        .parts
+        .into_iter()
+        .map(|part_run| part_run.answer)
+        .collect()
+}
+
+/// Parse `input` once and run each of `parts` for the given day,
+/// timing each phase. Unlike `solve()`, a failing part doesn't stop
+/// the others.
+///
+/// # Errors
+///
+/// Returns an error if there's no such day or the input doesn't parse.
+pub fn run(day: u8, input: &str, parts: &[Part]) -> miette::Result<DayRun> {
+    match day {
+        1 => day_01::Day01::run(input, parts),
+        2 => day_02::Day02::run(input, parts),
+        3 => day_03::Day03::run(input, parts),
+        4 => day_04::Day04::run(input, parts),
+        5 => day_05::Day05::run(input, parts),
+        6 => day_06::Day06::run(input, parts),
+        7 => day_07::Day07::run(input, parts),
+        8 => day_08::Day08::run(input, parts),
+        9 => day_09::Day09::run(input, parts),
+        10 => day_10::Day10::run(input, parts),
+        11 => day_11::Day11::run(input, parts),
+        12 => day_12::Day12::run(input, parts),
+        13 => day_13::Day13::run(input, parts),
+        14 => day_14::Day14::run(input, parts),
+        15 => day_15::Day15::run(input, parts),
+        16 => day_16::Day16::run(input, parts),
+        17 => day_17::Day17::run(input, parts),
+        18 => day_18::Day18::run(input, parts),
+        19 => day_19::Day19::run(input, parts),
+        20 => day_20::Day20::run(input, parts),
+        21 => day_21::Day21::run(input, parts),
+        22 => day_22::Day22::run(input, parts),
+        23 => day_23::Day23::run(input, parts),
+        24 => day_24::Day24::run(input, parts),
+        25 => day_25::Day25::run(input, parts),
+        _ => Err(miette::miette!("There's no day {day}")),
+    }
+}
+
+/// Draw a picture of the given day's input, usually with its solution
+/// drawn on top.
+///
+/// # Errors
+///
+/// Returns an error if the day doesn't have a visualization or the
+/// input doesn't parse.
+pub fn visualize(day: u8, input: &str) -> miette::Result<Svg> {
+    match day {
+        3 => Ok(day_03::Day03::parse(input)?.to_svg()),
+        10 => Ok(day_10::Day10::parse(input)?.to_svg()),
+        14 => Ok(day_14::Day14::parse(input)?.to_svg()),
+        16 => Ok(day_16::Day16::parse(input)?.to_svg()),
+        17 => Ok(day_17::Day17::parse(input)?.to_svg()),
+        _ => Err(miette::miette!(
+            help = "Days 3, 10, 14, 16, and 17 can be visualized",
+            "Day {day} doesn't have a visualization"
+        )),
+    }
+}
+
+/// The given day's network as a Graphviz DOT graph.
+///
+/// # Errors
+///
+/// Returns an error if the day doesn't have a network or the input
+/// doesn't parse.
+pub fn dot(day: u8, input: &str) -> miette::Result<String> {
+    match day {
+        8 => Ok(day_08::Day08::parse(input)?.to_dot()),
+        _ => Err(miette::miette!(
+            help = "Only day 8 can be saved as a graph",
+            "Day {day} doesn't have a network to draw"
+        )),
+    }
+}
+
+/// The given day's part 2 answer worked out the slow, obvious way, to
+/// check the real solution against.
+///
+/// # Errors
+///
+/// Returns an error if the day doesn't have a brute-force check or the
+/// input doesn't parse.
+pub fn brute_force(day: u8, input: &str) -> miette::Result<Answer> {
+    match day {
+        5 => day_05::Day05::parse(input)?
+            .brute_force_lowest_seed_range_location()
+            .map(Answer::from)
+            .ok_or_else(|| day_05::Day05Error::NoLocation.into()),
+        _ => Err(miette::miette!(
+            help = "Only day 5 can be brute-forced",
+            "Day {day} doesn't have a brute-force check"
+        )),
+    }
+}
+
+/// Each intermediate state of the given day's simulation, drawn as
+/// text.
+///
+/// # Errors
+///
+/// Returns an error if the day doesn't have an animation or the input
+/// doesn't parse.
+pub fn animate(day: u8, input: &str) -> miette::Result<Vec<String>> {
+    match day {
+        14 => Ok(day_14::Day14::parse(input)?.frames()),
+        16 => Ok(day_16::Day16::parse(input)?.frames()),
+        17 => Ok(day_17::Day17::parse(input)?.frames()),
+        _ => Err(miette::miette!(
+            help = "Days 14, 16, and 17 can be animated",
+            "Day {day} doesn't have an animation"
+        )),
+    }
+}
+
+/// The given day's parsed input as pretty-printed JSON, for digging
+/// into it with other tools.
+///
+/// # Errors
+///
+/// Returns an error if the day's parsed input can't be serialized or
+/// the input doesn't parse.
+#[cfg(feature = "serde")]
+pub fn parsed_json(day: u8, input: &str) -> miette::Result<String> {
+    use miette::IntoDiagnostic;
+
+    match day {
+        2 => serde_json::to_string_pretty(&day_02::Day02::parse(input)?),
+        3 => serde_json::to_string_pretty(&day_03::Day03::parse(input)?),
+        5 => serde_json::to_string_pretty(&day_05::Day05::parse(input)?),
+        10 => serde_json::to_string_pretty(&day_10::Day10::parse(input)?),
+        12 => serde_json::to_string_pretty(&day_12::Day12::parse(input)?),
+        14 => serde_json::to_string_pretty(&day_14::Day14::parse(input)?),
+        _ => {
+            return Err(miette::miette!(
+                help = "Days 2, 3, 5, 10, 12, and 14 can be saved as JSON",
+                "Day {day}'s parsed input can't be saved as JSON"
+            ))
+        }
+    }
+    .into_diagnostic()
+}
+
+/// Parsed inputs can only be saved as JSON with the `serde` feature.
+///
+/// # Errors
+///
+/// Always returns an error saying to rebuild with `--features serde`.
+#[cfg(not(feature = "serde"))]
+pub fn parsed_json(_day: u8, _input: &str) -> miette::Result<String> {
+    Err(miette::miette!(
+        help = "Rebuild with `--features serde`",
+        "Saving parsed inputs as JSON needs the `serde` feature"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(4, include_str!("../inputs/day_04_test.txt"), [13, 30] ; "day 4")]
+    #[test_case(6, include_str!("../inputs/day_06_test.txt"), [288, 71503] ; "day 6")]
+    #[test_case(9, include_str!("../inputs/day_09_test.txt"), [114, 2] ; "day 9")]
+    #[test_case(14, include_str!("../inputs/day_14_test.txt"), [136, 64] ; "day 14")]
+    #[test_case(22, include_str!("../inputs/day_22_test.txt"), [5, 7] ; "day 22")]
+    fn solves_both_parts(day: u8, input: &str, expected: [u64; 2]) {
+        let answers = solve(day, input, &Part::BOTH).unwrap();
+        assert_eq!(answers, expected.map(Answer::from));
+    }
+
+    #[test]
+    fn unsolved_part_is_an_error() {
+        let input = include_str!("../inputs/day_19_test.txt");
+        assert!(solve(19, input, &[Part::One]).is_err());
+        assert!(solve(19, input, &[Part::Two]).is_ok());
+    }
+
+    #[test]
+    fn visualizes_grid_days() {
+        let input = include_str!("../inputs/day_16_test.txt");
+        let svg = visualize(16, input).unwrap();
+        assert_eq!((svg.width(), svg.height()), (100, 100));
+        assert!(visualize(4, include_str!("../inputs/day_04_test.txt")).is_err());
+    }
+
+    #[test]
+    fn run_marks_unsolved_parts() {
+        let input = include_str!("../inputs/day_19_test.txt");
+        let day_run = run(19, input, &Part::BOTH).unwrap();
+        assert!(day_run.parts[0].is_unsolved());
+        assert!(!day_run.parts[1].is_unsolved());
+        assert!(day_run.parts[1].answer.is_ok());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn saves_parsed_inputs_as_json() {
+        let json = parsed_json(14, include_str!("../inputs/day_14_test.txt")).unwrap();
+        let platform: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(platform["grid"][0][0], "Round");
+        assert_eq!(platform["grid"].as_array().unwrap().len(), 10);
+
+        let json = parsed_json(10, include_str!("../inputs/day_10_test_1.txt")).unwrap();
+        let pipe_map: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(pipe_map["start"], serde_json::json!([1, 1]));
+        assert!(parsed_json(4, "").is_err());
+    }
+}
@@ -0,0 +1,260 @@
+//! Checking that everything is set up to run, fetch, and verify the
+//! days: the inputs, the `AOC_SESSION` cookie, and `answers.toml`, and
+//! which days still need solving.
+
+use std::path::PathBuf;
+
+use miette::{Diagnostic, Severity};
+
+use crate::{
+    answers::{sample_day, Answers},
+    client::{Client, ClientError},
+    days,
+    parsing::{input_path, inputs_dir, samples_dir},
+    solver::{Part, PartRun},
+};
+
+/// Something `aoc doctor` found wrong with the setup. Most of these are
+/// errors, but a day that hasn't been solved yet is only a warning.
+#[derive(Debug, Diagnostic, thiserror::Error)]
+pub enum Problem {
+    #[error("There's no input for day {day} in {}", path.display())]
+    #[diagnostic(
+        code(aoc::doctor::missing_input),
+        help("Download it with `aoc fetch --day {day}`")
+    )]
+    MissingInput { day: u8, path: PathBuf },
+
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Client(#[from] ClientError),
+
+    #[error("adventofcode.com didn't accept the session in AOC_SESSION")]
+    #[diagnostic(
+        code(aoc::doctor::invalid_session),
+        help("It may have expired; copy the `session` cookie from a freshly logged-in browser")
+    )]
+    InvalidSession,
+
+    #[error("Couldn't read answers.toml: {message}")]
+    #[diagnostic(code(aoc::doctor::unreadable_answers))]
+    UnreadableAnswers { message: String },
+
+    #[error("answers.toml has answers for {name}, but there's no {name}.txt")]
+    #[diagnostic(
+        code(aoc::doctor::unknown_input),
+        help("Answers are keyed by the input's file name without the `.txt`")
+    )]
+    UnknownInput { name: String },
+
+    #[error("answers.toml says {name} part {part} is {expected}, but it's {found}")]
+    #[diagnostic(code(aoc::doctor::wrong_sample_answer))]
+    WrongSampleAnswer {
+        name: String,
+        part: Part,
+        expected: String,
+        found: String,
+    },
+
+    #[error("Day {day} part {part} hasn't been solved yet")]
+    #[diagnostic(code(aoc::doctor::unsolved), severity(Warning))]
+    Unsolved { day: u8, part: Part },
+}
+
+impl Problem {
+    /// Whether this should make `aoc doctor` fail, rather than just be
+    /// pointed out.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.severity().unwrap_or(Severity::Error) == Severity::Error
+    }
+}
+
+/// Everything that looks like an input, in the inputs directory or
+/// with the samples, as the file name without the `.txt`, in order.
+fn input_names() -> Vec<String> {
+    let mut names: Vec<String> = [inputs_dir(), samples_dir()]
+        .into_iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_str()?.to_string())
+        })
+        .collect();
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// The sample input called `name`, if there is one.
+fn read_sample(name: &str) -> Option<String> {
+    std::fs::read_to_string(samples_dir().join(format!("{name}.txt"))).ok()
+}
+
+/// The days that are missing their full input, skipping days that
+/// haven't been solved at all since there's no hurry to fetch those.
+#[must_use]
+pub fn check_inputs(unsolved_days: &[u8]) -> Vec<Problem> {
+    (1..=25)
+        .filter(|day| !unsolved_days.contains(day))
+        .map(|day| (day, input_path(day)))
+        .filter(|(_, path)| !path.exists())
+        .map(|(day, path)| Problem::MissingInput { day, path })
+        .collect()
+}
+
+/// Whether `AOC_SESSION` is set and, unless we're `offline`, whether
+/// the site accepts it.
+#[must_use]
+pub fn check_session(offline: bool) -> Option<Problem> {
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(error) => return Some(error.into()),
+    };
+    if offline {
+        return None;
+    }
+    match client.session_is_valid() {
+        Ok(true) => None,
+        Ok(false) => Some(Problem::InvalidSession),
+        Err(error) => Some(error.into()),
+    }
+}
+
+/// Whether every entry in `answers` is for an input we have, and
+/// whether the sample inputs get the answers it says they should, as
+/// the tests expect.
+#[must_use]
+pub fn check_answers(answers: &Answers, input_names: &[String]) -> Vec<Problem> {
+    let mut problems: Vec<Problem> = answers
+        .names()
+        .filter(|name| !input_names.iter().any(|input_name| input_name == name))
+        .map(|name| Problem::UnknownInput {
+            name: name.to_string(),
+        })
+        .collect();
+    for name in input_names {
+        let Some(day) = sample_day(name) else {
+            continue;
+        };
+        let Some(input) = read_sample(name) else {
+            continue;
+        };
+        for part in Part::BOTH {
+            let Some(expected) = answers.get_for_input(name, part) else {
+                continue;
+            };
+            let found = match days::solve(day, &input, &[part]) {
+                Ok(answer) if answer == [expected.clone()] => continue,
+                Ok(answer) => answer[0].to_string(),
+                Err(error) => format!("an error ({error})"),
+            };
+            problems.push(Problem::WrongSampleAnswer {
+                name: name.clone(),
+                part,
+                expected: expected.to_string(),
+                found,
+            });
+        }
+    }
+    problems
+}
+
+/// The parts that still return the `Unsolved` placeholder, found by
+/// running each day on its first sample input. Days without a sample
+/// that parses can't be checked, and are skipped.
+#[must_use]
+pub fn check_solved(input_names: &[String]) -> Vec<Problem> {
+    (1..=25)
+        .filter_map(|day| {
+            let name = input_names
+                .iter()
+                .find(|name| sample_day(name) == Some(day))?;
+            let input = read_sample(name)?;
+            days::run(day, &input, &Part::BOTH).ok()
+        })
+        .flat_map(|day_run| {
+            day_run
+                .parts
+                .into_iter()
+                .filter(PartRun::is_unsolved)
+                .map(move |part_run| Problem::Unsolved {
+                    day: day_run.day,
+                    part: part_run.part,
+                })
+        })
+        .collect()
+}
+
+/// Run every check. Only `check_session()` needs the network, and
+/// that's skipped if we're `offline`.
+#[must_use]
+pub fn check_everything(offline: bool) -> Vec<Problem> {
+    let input_names = input_names();
+    let solved_problems = check_solved(&input_names);
+    let unsolved_days: Vec<u8> = (1..=25)
+        .filter(|&day| {
+            solved_problems
+                .iter()
+                .filter(|problem| matches!(problem, Problem::Unsolved { day: d, .. } if *d == day))
+                .count()
+                == Part::BOTH.len()
+        })
+        .collect();
+
+    let mut problems = check_inputs(&unsolved_days);
+    problems.extend(check_session(offline));
+    match Answers::load() {
+        Ok(answers) => problems.extend(check_answers(&answers, &input_names)),
+        Err(error) => problems.push(Problem::UnreadableAnswers {
+            message: error.to_string(),
+        }),
+    }
+    problems.extend(solved_problems);
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_for_missing_inputs_are_a_problem() {
+        let answers: Answers = "[day_07]\npart_1 = 6440\n[day_99_test]\npart_1 = 1"
+            .parse()
+            .unwrap();
+        let problems = check_answers(&answers, &["day_07".to_string()]);
+        assert!(matches!(
+            &problems[..],
+            [Problem::UnknownInput { name }] if name == "day_99_test"
+        ));
+    }
+
+    #[test]
+    fn wrong_sample_answers_are_a_problem() {
+        let answers: Answers = "[day_09_test]\npart_1 = 113\npart_2 = 2".parse().unwrap();
+        let problems = check_answers(&answers, &["day_09_test".to_string()]);
+        assert!(matches!(
+            &problems[..],
+            [Problem::WrongSampleAnswer { part: Part::One, found, .. }] if found == "114"
+        ));
+    }
+
+    #[test]
+    fn unsolved_parts_are_only_warnings() {
+        let problems = check_solved(&["day_19_test".to_string()]);
+        assert!(matches!(
+            &problems[..],
+            [Problem::Unsolved {
+                day: 19,
+                part: Part::One
+            }]
+        ));
+        assert!(!problems[0].is_error());
+        assert!(Problem::InvalidSession.is_error());
+    }
+}
@@ -0,0 +1,265 @@
+//! Synthetic puzzle inputs, much bigger than the real ones, for seeing
+//! how the solutions scale.
+//!
+//! Each generator builds an input that has the same structure as the
+//! real thing, so it parses and has an answer, but `size` (the number
+//! of lines, or the side of a square grid) can be as big as we like.
+//! The same seed always gives the same input, so timings can be
+//! compared between runs.
+
+use std::fmt::Write;
+
+use rand::{rngs::StdRng, seq::IndexedRandom, Rng, SeedableRng};
+
+/// The days that `generate()` can make inputs for.
+pub const DAYS: [u8; 9] = [4, 7, 9, 11, 12, 13, 14, 15, 16];
+
+/// Make a synthetic input for `day` whose size is set by `size`: the
+/// number of lines for days 4, 7, 9, and 12, the number of steps for day 15,
+/// and the side of the square grid for the others.
+///
+/// # Errors
+///
+/// Returns an error if we don't have a generator for `day` or if
+/// `size` is zero.
+pub fn generate(day: u8, size: usize, seed: u64) -> miette::Result<String> {
+    if size == 0 {
+        return Err(miette::miette!("The size has to be at least 1"));
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+    Ok(match day {
+        4 => scratch_cards(&mut rng, size),
+        7 => camel_cards(&mut rng, size),
+        9 => sequences(&mut rng, size),
+        11 => galaxies(&mut rng, size),
+        12 => condition_records(&mut rng, size),
+        13 => mirror_grid(&mut rng, size),
+        14 => platform(&mut rng, size),
+        15 => initialization_sequence(&mut rng, size),
+        16 => contraption(&mut rng, size),
+        _ => {
+            return Err(miette::miette!(
+                help = format!("Inputs can be generated for days {DAYS:?}"),
+                "Can't generate an input for day {day}"
+            ))
+        }
+    })
+}
+
+/// A square grid where each cell is drawn independently from `cells`,
+/// each paired with its relative weight.
+fn square_grid(rng: &mut impl Rng, size: usize, cells: &[(char, u32)]) -> String {
+    let total: u32 = cells.iter().map(|(_, weight)| weight).sum();
+    let mut grid = String::with_capacity(size * (size + 1));
+    for _ in 0..size {
+        for _ in 0..size {
+            let mut pick = rng.random_range(0..total);
+            for &(cell, weight) in cells {
+                if pick < weight {
+                    grid.push(cell);
+                    break;
+                }
+                pick -= weight;
+            }
+        }
+        grid.push('\n');
+    }
+    grid
+}
+
+/// Day 4: each card has 10 winning numbers and 25 of our own, all
+/// between 1 and 99, so a card wins a couple of copies on average.
+fn scratch_cards(rng: &mut impl Rng, num_cards: usize) -> String {
+    let mut cards = String::new();
+    for number in 1..=num_cards {
+        let mut numbers = |amount| {
+            rand::seq::index::sample(rng, 99, amount)
+                .into_iter()
+                .map(|n| format!("{:>2}", n + 1))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let winning_numbers = numbers(10);
+        let our_numbers = numbers(25);
+        writeln!(cards, "Card {number}: {winning_numbers} | {our_numbers}").unwrap();
+    }
+    cards
+}
+
+/// Day 7: rounds of five cards drawn from the whole deck, with bids up
+/// to 1000, like the real ones.
+fn camel_cards(rng: &mut impl Rng, num_rounds: usize) -> String {
+    const CARDS: &[u8] = b"23456789TJQKA";
+
+    let mut rounds = String::with_capacity(num_rounds * 10);
+    for _ in 0..num_rounds {
+        rounds.extend((0..5).map(|_| char::from(CARDS[rng.random_range(0..CARDS.len())])));
+        writeln!(rounds, " {}", rng.random_range(1..=1000)).unwrap();
+    }
+    rounds
+}
+
+/// Day 9: each line is 21 terms of a polynomial of degree at most 5,
+/// so the differences always bottom out in zeros.
+fn sequences(rng: &mut impl Rng, num_lines: usize) -> String {
+    let mut lines = String::new();
+    for _ in 0..num_lines {
+        let degree = rng.random_range(0..=5);
+        let coefficients: Vec<i64> = (0..=degree).map(|_| rng.random_range(-9..=9)).collect();
+        let terms: Vec<String> = (0..21_i64)
+            .map(|x| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |value, coefficient| value * x + coefficient)
+                    .to_string()
+            })
+            .collect();
+        lines.push_str(&terms.join(" "));
+        lines.push('\n');
+    }
+    lines
+}
+
+/// Day 11: sparse galaxies, so there are plenty of empty rows and
+/// columns to expand.
+fn galaxies(rng: &mut impl Rng, size: usize) -> String {
+    square_grid(rng, size, &[('#', 1), ('.', 49)])
+}
+
+/// Day 12: each record comes from a real arrangement of broken springs
+/// with some of them hidden behind `?`, so it has at least one
+/// arrangement.
+fn condition_records(rng: &mut impl Rng, num_records: usize) -> String {
+    let mut records = String::new();
+    for _ in 0..num_records {
+        let length = rng.random_range(8..=20);
+        let mut springs: Vec<char> = (0..length)
+            .map(|_| if rng.random_bool(0.4) { '#' } else { '.' })
+            .collect();
+        if !springs.contains(&'#') {
+            springs[rng.random_range(0..length)] = '#';
+        }
+        let counts: Vec<String> = springs
+            .split(|&spring| spring == '.')
+            .filter(|group| !group.is_empty())
+            .map(|group| group.len().to_string())
+            .collect();
+        for spring in &mut springs {
+            if rng.random_bool(0.4) {
+                *spring = '?';
+            }
+        }
+        records.extend(springs);
+        records.push(' ');
+        records.push_str(&counts.join(","));
+        records.push('\n');
+    }
+    records
+}
+
+/// Day 13: a single pattern that's reflected across one randomly chosen
+/// row or column. Only part 1 is sure to have an answer, since there
+/// may not be a smudge that makes a second reflection.
+fn mirror_grid(rng: &mut impl Rng, size: usize) -> String {
+    let mut cells: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.random_bool(0.5) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    if size > 1 {
+        // The reflection is between `line - 1` and `line`.
+        let line = rng.random_range(1..size);
+        let vertical = rng.random_bool(0.5);
+        for offset in 0..line.min(size - line) {
+            let (before, after) = (line - 1 - offset, line + offset);
+            if vertical {
+                for row in &mut cells {
+                    row[after] = row[before];
+                }
+            } else {
+                let (above, below) = cells.split_at_mut(after);
+                below[0].clone_from(&above[before]);
+            }
+        }
+    }
+    cells
+        .into_iter()
+        .map(|row| row.into_iter().chain(['\n']).collect::<String>())
+        .collect()
+}
+
+/// Day 14: a platform with round rocks to roll and cube rocks to stop
+/// them.
+fn platform(rng: &mut impl Rng, size: usize) -> String {
+    square_grid(rng, size, &[('O', 2), ('#', 1), ('.', 7)])
+}
+
+/// Day 15: steps that add and remove lenses with a limited set of
+/// labels, so removals and replacements actually find their lens.
+fn initialization_sequence(rng: &mut impl Rng, num_steps: usize) -> String {
+    let labels: Vec<String> = (0..num_steps.min(1000))
+        .map(|_| {
+            let length = rng.random_range(1..=6);
+            (0..length)
+                .map(|_| char::from(rng.random_range(b'a'..=b'z')))
+                .collect()
+        })
+        .collect();
+    let steps: Vec<String> = (0..num_steps)
+        .map(|_| {
+            let label = labels.choose(rng).map_or("a", String::as_str);
+            if rng.random_bool(0.25) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.random_range(1..=9))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+/// Day 16: mostly empty space with mirrors and splitters scattered
+/// through it.
+fn contraption(rng: &mut impl Rng, size: usize) -> String {
+    square_grid(
+        rng,
+        size,
+        &[('.', 36), ('/', 1), ('\\', 1), ('|', 1), ('-', 1)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{days, solver::Part};
+    use test_case::test_case;
+
+    #[test_case(4 ; "day 4")]
+    #[test_case(7 ; "day 7")]
+    #[test_case(9 ; "day 9")]
+    #[test_case(11 ; "day 11")]
+    #[test_case(12 ; "day 12")]
+    #[test_case(13 ; "day 13")]
+    #[test_case(14 ; "day 14")]
+    #[test_case(15 ; "day 15")]
+    #[test_case(16 ; "day 16")]
+    fn generated_inputs_have_a_part_1_answer(day: u8) {
+        let input = generate(day, 30, 2023).unwrap();
+        days::solve(day, &input, &[Part::One]).unwrap();
+    }
+
+    #[test]
+    fn the_same_seed_gives_the_same_input() {
+        assert_eq!(generate(12, 100, 7).unwrap(), generate(12, 100, 7).unwrap());
+        assert_ne!(generate(12, 100, 7).unwrap(), generate(12, 100, 8).unwrap());
+    }
+
+    #[test]
+    fn rejects_days_without_a_generator() {
+        assert!(generate(1, 10, 0).is_err());
+        assert!(generate(14, 0, 0).is_err());
+    }
+}
@@ -0,0 +1,37 @@
+//! The runner that ties the days together: dispatching to each day's
+//! crate, the known answers, the puzzle client, and the bindings for
+//! the browser and Python.
+//!
+//! The shared code lives in `aoc-core` and each day's solution in its
+//! own `aoc-dayNN` crate; they're re-exported here so paths like
+//! `advent_of_code_2023::grid::Grid` and
+//! `advent_of_code_2023::days::day_14::Day14` keep working.
+
+pub use aoc_core::{
+    animation, cycle, direction, errors, grid, ipos, logging, math, memo, parsing, polygon,
+    progress, ranges, solver, viz,
+};
+
+pub mod answers;
+#[cfg(feature = "cargo-aoc")]
+pub mod cargo_aoc;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod client;
+/// Each day's solution, as an implementation of `solver::Solver`.
+pub mod days;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod doctor;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod generate;
+#[cfg(feature = "python")]
+mod python;
+pub mod scaffold;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+
+pub use direction::CardinalDirection;
+pub use grid::{CellParse, Grid, Position};
+
+// This has to come after every `#[aoc]` function.
+#[cfg(feature = "cargo-aoc")]
+aoc_runner_derive::aoc_lib! { year = 2023 }
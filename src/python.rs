@@ -0,0 +1,75 @@
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    days::{self, day_05::Day05, day_10::Day10},
+    errors::render_plain,
+    solver::{Part, Solver},
+};
+
+fn to_py_err(report: &miette::Report) -> PyErr {
+    PyValueError::new_err(render_plain(report))
+}
+
+/// Solve one part of a day for `input`. The answer comes back as a
+/// string, like the browser playground's, so every day's answer has
+/// the same type.
+///
+/// # Errors
+///
+/// Raises a `ValueError` describing what went wrong (e.g., where the
+/// input failed to parse) if there's no answer.
+#[pyfunction]
+fn solve(day: u8, part: u8, input: &str) -> PyResult<String> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(PyValueError::new_err(format!("There's no part {part}"))),
+    };
+    days::solve(day, input, &[part])
+        .map(|answers| answers[0].to_string())
+        .map_err(|report| to_py_err(&report))
+}
+
+/// Day 5's seeds-to-locations mapping, with all the maps composed into
+/// one, as `(start, end, offset)` triples: each seed in `start..end`
+/// ends up at that seed plus `offset`, and seeds outside all of them
+/// stay where they are.
+///
+/// # Errors
+///
+/// Raises a `ValueError` if `input` isn't a valid almanac.
+#[pyfunction]
+fn day_05_mapping(input: &str) -> PyResult<Vec<(u64, u64, i64)>> {
+    let almanac = Day05::parse(input).map_err(|report| to_py_err(&report))?;
+    Ok(almanac
+        .seed_to_location()
+        .map(|mapping| {
+            mapping
+                .entries()
+                .map(|(range, offset)| (range.start, range.end, *offset))
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// The `(row, column)` positions around day 10's loop, in order.
+///
+/// # Errors
+///
+/// Raises a `ValueError` if `input` isn't a valid pipe map with a loop
+/// through the start.
+#[pyfunction]
+fn day_10_loop(input: &str) -> PyResult<Vec<(usize, usize)>> {
+    Day10::parse(input)
+        .and_then(|pipe_map| pipe_map.loop_positions())
+        .map_err(|report| to_py_err(&report))
+}
+
+/// The `advent_of_code_2023` Python module, built with `maturin`.
+#[pymodule]
+fn advent_of_code_2023(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(solve, module)?)?;
+    module.add_function(wrap_pyfunction!(day_05_mapping, module)?)?;
+    module.add_function(wrap_pyfunction!(day_10_loop, module)?)?;
+    Ok(())
+}
@@ -0,0 +1,224 @@
+//! Generating the boilerplate for a new day.
+//!
+//! That's the day's crate (its `Cargo.toml`, and a `lib.rs` with the
+//! parse error, `FromStr` stub, `Solver` impl, and tests), empty input
+//! files, the dependencies on it in the top-level `Cargo.toml`, and the
+//! re-export and dispatch arm in `days/mod.rs`.
+
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+
+use crate::parsing::input_path;
+
+const LIB_TEMPLATE: &str = include_str!("scaffold/lib.rs.tmpl");
+const CARGO_TEMPLATE: &str = include_str!("scaffold/Cargo.toml.tmpl");
+
+/// `register()` inserts the new dispatch arm just before this one.
+const FALLBACK_ARM: &str = "        _ => Err(miette::miette!(";
+
+#[derive(Debug, Diagnostic, thiserror::Error)]
+pub enum ScaffoldError {
+    #[error("{} already exists", path.display())]
+    #[diagnostic(help("Delete it first if you really want to start that day over"))]
+    AlreadyExists { path: PathBuf },
+
+    #[error("Day {day} is already registered in days/mod.rs")]
+    AlreadyRegistered { day: u8 },
+
+    #[error("Couldn't find where to add the new day in days/mod.rs")]
+    UnexpectedModFile,
+
+    #[error("Couldn't find where to add the new day's crate in Cargo.toml")]
+    UnexpectedManifest,
+
+    #[error("Couldn't read or write {}", path.display())]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+fn root_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{dd}}", &format!("{day:02}"))
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Add `pub use aoc_dayNN as day_NN;` after the last day's re-export
+/// and a match arm for the new day before the fallback arm in `run()`.
+fn register(mod_rs: &str, day: u8) -> Result<String, ScaffoldError> {
+    let module = format!("pub use aoc_day{day:02} as day_{day:02};");
+    if mod_rs.lines().any(|line| line == module) {
+        return Err(ScaffoldError::AlreadyRegistered { day });
+    }
+    let mut lines: Vec<String> = mod_rs.lines().map(ToString::to_string).collect();
+    let last_module = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub use aoc_day"))
+        .ok_or(ScaffoldError::UnexpectedModFile)?;
+    lines.insert(last_module + 1, module);
+    let fallback = lines
+        .iter()
+        .position(|line| line.starts_with(FALLBACK_ARM))
+        .ok_or(ScaffoldError::UnexpectedModFile)?;
+    lines.insert(
+        fallback,
+        format!("        {day} => day_{day:02}::Day{day:02}::run(input, parts),"),
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the new day's crate to `[workspace.dependencies]` and to the
+/// runner's `[dependencies]`, each time after the last day already there.
+fn register_dependency(cargo_toml: &str, day: u8) -> Result<String, ScaffoldError> {
+    let mut lines: Vec<String> = cargo_toml.lines().map(ToString::to_string).collect();
+    for (kind, line) in [
+        (
+            " = { path = ",
+            format!("aoc-day{day:02} = {{ path = \"crates/aoc-day{day:02}\" }}"),
+        ),
+        (
+            ".workspace = true",
+            format!("aoc-day{day:02}.workspace = true"),
+        ),
+    ] {
+        let last = lines
+            .iter()
+            .rposition(|existing| existing.starts_with("aoc-day") && existing.contains(kind))
+            .ok_or(ScaffoldError::UnexpectedManifest)?;
+        lines.insert(last + 1, line);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, ScaffoldError> {
+    std::fs::read_to_string(path).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<(), ScaffoldError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|source| ScaffoldError::Io {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+    std::fs::write(path, contents).map_err(|source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Create the crate and empty input files for `day`, and register it
+/// in `Cargo.toml` and `days/mod.rs`. Returns the paths of the files
+/// it created.
+///
+/// # Errors
+///
+/// Returns an error without changing anything if any of the files
+/// already exist or the day is already registered, or if a file can't
+/// be read or written.
+pub fn new_day(day: u8) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let root = root_dir();
+    let crate_dir = root.join("crates").join(format!("aoc-day{day:02}"));
+    let manifest = crate_dir.join("Cargo.toml");
+    let solver = crate_dir.join("src").join("lib.rs");
+    // The sample has to be in `src/inputs/` for the tests to build it
+    // in, but the full input goes wherever the others are.
+    let input = input_path(day);
+    let test_input = root
+        .join("src")
+        .join("inputs")
+        .join(format!("day_{day:02}_test.txt"));
+    let new_files = [manifest, solver, input, test_input];
+    if let Some(path) = new_files.iter().find(|path| path.exists()) {
+        return Err(ScaffoldError::AlreadyExists { path: path.clone() });
+    }
+
+    let mod_path = root.join("src").join("days").join("mod.rs");
+    let mod_rs = register(&read(&mod_path)?, day)?;
+    let cargo_path = root.join("Cargo.toml");
+    let cargo_toml = register_dependency(&read(&cargo_path)?, day)?;
+
+    let [manifest, solver, input, test_input] = new_files;
+    write_new(&manifest, &render(CARGO_TEMPLATE, day))?;
+    write_new(&solver, &render(LIB_TEMPLATE, day))?;
+    write_new(&input, "")?;
+    write_new(&test_input, "")?;
+    write_new(&mod_path, &mod_rs)?;
+    write_new(&cargo_path, &cargo_toml)?;
+    Ok(vec![manifest, solver, input, test_input])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "\
+pub use aoc_day01 as day_01;
+pub use aoc_day02 as day_02;
+
+pub fn solve(day: u8, input: &str, parts: &[Part]) -> miette::Result<Vec<Answer>> {
+    match day {
+        1 => day_01::Day01::run(input, parts),
+        2 => day_02::Day02::run(input, parts),
+        _ => Err(miette::miette!(\"There's no day {day}\")),
+    }
+}
+";
+
+    #[test]
+    fn render_fills_in_the_day() {
+        let module = render(LIB_TEMPLATE, 7);
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(module.contains("\"../../../src/inputs/day_07_test.txt\""));
+        assert!(!module.contains("{{"));
+        assert!(render(CARGO_TEMPLATE, 7).contains("name = \"aoc-day07\""));
+    }
+
+    #[test]
+    fn register_adds_module_and_arm() {
+        let updated = register(MOD_RS, 3).unwrap();
+        assert!(updated.contains("pub use aoc_day02 as day_02;\npub use aoc_day03 as day_03;\n"));
+        assert!(updated.contains(
+            "2 => day_02::Day02::run(input, parts),\n        3 => day_03::Day03::run(input, parts),\n        _ =>"
+        ));
+    }
+
+    #[test]
+    fn register_rejects_existing_day() {
+        assert!(matches!(
+            register(MOD_RS, 2),
+            Err(ScaffoldError::AlreadyRegistered { day: 2 })
+        ));
+    }
+
+    #[test]
+    fn register_dependency_adds_both_lines() {
+        let cargo_toml = "\
+[workspace.dependencies]
+aoc-core = { path = \"crates/aoc-core\" }
+aoc-day01 = { path = \"crates/aoc-day01\" }
+pest = \"2.7.6\"
+
+[dependencies]
+aoc-core.workspace = true
+aoc-day01.workspace = true
+nom.workspace = true
+";
+        let updated = register_dependency(cargo_toml, 2).unwrap();
+        assert!(updated.contains(
+            "aoc-day01 = { path = \"crates/aoc-day01\" }\naoc-day02 = { path = \"crates/aoc-day02\" }\npest"
+        ));
+        assert!(updated.contains("aoc-day01.workspace = true\naoc-day02.workspace = true\nnom"));
+    }
+}
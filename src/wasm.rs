@@ -0,0 +1,23 @@
+use wasm_bindgen::prelude::*;
+
+use crate::{days, errors::render_plain, solver::Part};
+
+/// Solve one part of a day for the pasted `input`, for the playground in
+/// `web/`. The answer comes back as a string since some answers are too
+/// big to be safely stored in a JavaScript number.
+///
+/// # Errors
+///
+/// Throws a JavaScript `Error` describing what went wrong (e.g., where
+/// the input failed to parse) if there's no answer.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, JsError> {
+    let part = match part {
+        1 => Part::One,
+        2 => Part::Two,
+        _ => return Err(JsError::new(&format!("There's no part {part}"))),
+    };
+    days::solve(day, input, &[part])
+        .map(|answers| answers[0].to_string())
+        .map_err(|report| JsError::new(&render_plain(&report)))
+}
@@ -0,0 +1,27 @@
+// Build the WebAssembly package into `web/pkg/` first; see the README.
+import init, { solve } from "./pkg/advent_of_code_2023.js";
+
+const form = document.getElementById("solve");
+const day = document.getElementById("day");
+const part = document.getElementById("part");
+const input = document.getElementById("input");
+const output = document.getElementById("output");
+
+for (let n = 1; n <= 25; n++) {
+  day.add(new Option(n, n));
+}
+
+await init();
+form.querySelector("button").disabled = false;
+
+form.addEventListener("submit", (event) => {
+  event.preventDefault();
+  const start = performance.now();
+  try {
+    const answer = solve(Number(day.value), Number(part.value), input.value);
+    const ms = (performance.now() - start).toFixed(1);
+    output.textContent = `${answer}\n\n(solved in ${ms} ms)`;
+  } catch (error) {
+    output.textContent = error.message;
+  }
+});